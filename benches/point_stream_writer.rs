@@ -0,0 +1,53 @@
+use std::io::Cursor;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use shapefile::{Point, ShapeWriter};
+
+const NUM_POINTS: usize = 100_000;
+
+fn coordinates() -> Vec<(f64, f64)> {
+    (0..NUM_POINTS)
+        .map(|i| (i as f64, 2.0 * i as f64))
+        .collect()
+}
+
+fn write_points(c: &mut Criterion) {
+    let coordinates = coordinates();
+    let points = coordinates
+        .iter()
+        .map(|&(x, y)| Point::new(x, y))
+        .collect::<Vec<_>>();
+
+    let mut group = c.benchmark_group("write_points");
+    group.throughput(Throughput::Elements(NUM_POINTS as u64));
+
+    group.bench_function("write_shape", |b| {
+        b.iter(|| {
+            let mut shp = Cursor::new(Vec::<u8>::with_capacity(NUM_POINTS * 28 + 100));
+            let mut shx = Cursor::new(Vec::<u8>::with_capacity(NUM_POINTS * 8 + 100));
+            let mut writer = ShapeWriter::with_shx(&mut shp, &mut shx);
+            for point in &points {
+                writer.write_shape(black_box(point)).unwrap();
+            }
+            drop(writer);
+        })
+    });
+
+    group.bench_function("point_stream", |b| {
+        b.iter(|| {
+            let mut shp = Cursor::new(Vec::<u8>::with_capacity(NUM_POINTS * 28 + 100));
+            let mut shx = Cursor::new(Vec::<u8>::with_capacity(NUM_POINTS * 8 + 100));
+            let mut writer = ShapeWriter::with_shx(&mut shp, &mut shx);
+            let mut point_writer = writer.point_stream().unwrap();
+            for &(x, y) in &coordinates {
+                point_writer.push(black_box(x), black_box(y)).unwrap();
+            }
+            drop(writer);
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, write_points);
+criterion_main!(benches);
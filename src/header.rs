@@ -1,8 +1,9 @@
 use super::{Error, ShapeType};
 
-use crate::record::BBoxZ;
+use crate::record::{BBoxZ, RecordHeader};
 use byteorder::{BigEndian, LittleEndian, ReadBytesExt, WriteBytesExt};
 use std::io::{Read, Write};
+use std::mem::size_of;
 
 pub(crate) const HEADER_SIZE: i32 = 100;
 const FILE_CODE: i32 = 9994;
@@ -72,6 +73,87 @@ impl Header {
         Ok(hdr)
     }
 
+    /// Returns the total length of the file (header + shapes) in bytes
+    ///
+    /// The `file_length` field is in 16-bit words, as in the specification.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), shapefile::Error> {
+    /// let reader = shapefile::ShapeReader::from_path("tests/data/point.shp")?;
+    /// assert_eq!(reader.header().length_in_bytes(), 128);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn length_in_bytes(&self) -> u64 {
+        self.file_length as u64 * 2
+    }
+
+    /// Returns the `[min, max]` range of the measures of the file
+    ///
+    /// Files of shapes without measures have a range of `[0.0, 0.0]`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), shapefile::Error> {
+    /// let reader = shapefile::ShapeReader::from_path("tests/data/line.shp")?;
+    /// assert_eq!(reader.header().m_range(), [0.0, 0.0]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn m_range(&self) -> [f64; 2] {
+        self.bbox.m_range()
+    }
+
+    /// Returns the number of shapes in the file, computed from the `file_length`
+    ///
+    /// This only works for files of point shapes (`Point`, `PointM`, `PointZ`)
+    /// as their records all have the same size. For the other shape types,
+    /// `None` is returned and the _.shx_ file is needed to know the shape count.
+    ///
+    /// `None` is also returned if the `file_length` does not correspond
+    /// to a whole number of point records. As the `m` of `PointZ` records is optional,
+    /// this includes `PointZ` files whose `file_length` corresponds both to a whole number
+    /// of records with `m` and to a whole number of records without it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), shapefile::Error> {
+    /// let reader = shapefile::ShapeReader::from_path("tests/data/pointm.shp")?;
+    /// assert_eq!(reader.header().point_shape_count(), Some(2));
+    ///
+    /// let reader = shapefile::ShapeReader::from_path("tests/data/line.shp")?;
+    /// assert_eq!(reader.header().point_shape_count(), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::manual_is_multiple_of)] // usize::is_multiple_of needs Rust 1.87
+    pub fn point_shape_count(&self) -> Option<usize> {
+        let point_sizes: &[usize] = match self.shape_type {
+            ShapeType::Point => &[2 * size_of::<f64>()],
+            ShapeType::PointM => &[3 * size_of::<f64>()],
+            // The m value of PointZ is optional
+            ShapeType::PointZ => &[4 * size_of::<f64>(), 3 * size_of::<f64>()],
+            _ => return None,
+        };
+
+        let content_length =
+            (i64::from(self.file_length) * 2 - i64::from(HEADER_SIZE)).max(0) as usize;
+        let mut counts = point_sizes
+            .iter()
+            .map(|point_size| RecordHeader::SIZE + size_of::<i32>() + point_size)
+            .filter(|record_size| content_length % record_size == 0)
+            .map(|record_size| content_length / record_size);
+        match (counts.next(), counts.next()) {
+            (Some(count), None) => Some(count),
+            // No record size matches, or both PointZ record sizes do
+            _ => None,
+        }
+    }
+
     pub(crate) fn write_to<T: Write>(&self, dest: &mut T) -> Result<(), std::io::Error> {
         dest.write_i32::<BigEndian>(FILE_CODE)?;
 
@@ -111,4 +193,44 @@ mod tests {
         src.seek(SeekFrom::Start(0)).unwrap();
         assert!(Header::read_from(&mut src).is_err());
     }
+
+    #[test]
+    fn point_shape_count() {
+        let mut src = std::fs::File::open("tests/data/point.shp").unwrap();
+        let header = Header::read_from(&mut src).unwrap();
+        assert_eq!(header.point_shape_count(), Some(1));
+
+        let mut src = std::fs::File::open("tests/data/pointm.shp").unwrap();
+        let header = Header::read_from(&mut src).unwrap();
+        assert_eq!(header.point_shape_count(), Some(2));
+
+        let mut src = std::fs::File::open("tests/data/pointz.shp").unwrap();
+        let header = Header::read_from(&mut src).unwrap();
+        assert_eq!(header.point_shape_count(), Some(2));
+
+        let mut src = std::fs::File::open("tests/data/polygon.shp").unwrap();
+        let header = Header::read_from(&mut src).unwrap();
+        assert_eq!(header.point_shape_count(), None);
+
+        // 80 000 000 records of 28 bytes, the file length in bytes does not fit in an i32
+        let mut src = std::fs::File::open("tests/data/point.shp").unwrap();
+        let mut header = Header::read_from(&mut src).unwrap();
+        header.file_length = ((100 + 28 * 80_000_000_i64) / 2) as i32;
+        assert_eq!(header.point_shape_count(), Some(80_000_000));
+    }
+
+    #[test]
+    fn point_z_shape_count_with_and_without_m() {
+        let mut src = std::fs::File::open("tests/data/pointz.shp").unwrap();
+        let mut header = Header::read_from(&mut src).unwrap();
+
+        // Records with m are 44 bytes long, records without m 36 bytes long
+        header.file_length = (100 + 44 * 2) / 2;
+        assert_eq!(header.point_shape_count(), Some(2));
+        header.file_length = (100 + 36 * 2) / 2;
+        assert_eq!(header.point_shape_count(), Some(2));
+        // 396 bytes are either 9 records with m or 11 records without
+        header.file_length = (100 + 396) / 2;
+        assert_eq!(header.point_shape_count(), None);
+    }
 }
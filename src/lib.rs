@@ -37,6 +37,9 @@
 //! implementations allowing to convert (or try to) back and forth between shapefile's type and
 //! the one in `geo_types`
 //!
+//! The `wkb` feature gives access to `Shape::to_ewkb`, to write shapes
+//! in the Extended Well-Known Binary format used by PostGIS.
+//!
 //! [`Point`]: record/point/struct.Point.html
 //! [`PointM`]: record/point/struct.PointM.html
 //! [`PointZ`]: record/point/struct.PointZ.html
@@ -50,24 +53,33 @@ pub extern crate dbase;
 pub mod header;
 pub mod reader;
 pub mod record;
+mod spatial_index;
+pub mod validation;
 pub mod writer;
 
 #[cfg(feature = "geo-traits")]
 mod geo_traits_impl;
+#[cfg(feature = "wkb")]
+mod wkb;
 
 use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
 use std::fmt;
 use std::io::{Read, Write};
 
+#[cfg(feature = "geo-types")]
+pub use reader::read_as_geometry_collection;
 pub use reader::{read, read_as, read_shapes, read_shapes_as, Reader, ShapeReader};
+pub use record::bounding_box_of;
 pub use record::Multipatch;
 pub use record::{convert_shapes_to_vec_of, HasShapeType, ReadableShape};
+pub use record::{HashablePoint, Point, PointF32, PointM, PointZ};
 pub use record::{Multipoint, MultipointM, MultipointZ};
-pub use record::{Patch, Shape, NO_DATA};
-pub use record::{Point, PointM, PointZ};
+pub use record::{MultipointBuilder, MultipointMBuilder, MultipointZBuilder};
+pub use record::{Patch, PatchType, Shape, NO_DATA};
 pub use record::{Polygon, PolygonM, PolygonRing, PolygonZ};
 pub use record::{Polyline, PolylineM, PolylineZ};
-pub use writer::{ShapeWriter, Writer};
+pub use validation::{validate_file, ValidationReport};
+pub use writer::{copy_transform, ShapeWriter, SpatialIndexKind, Writer, WriterBuilder};
 
 extern crate core;
 #[cfg(feature = "geo-types")]
@@ -97,6 +109,36 @@ pub enum Error {
     DbaseError(dbase::Error),
     MissingDbf,
     MissingIndexFile,
+    /// Error returned when a shape could not be converted into another type of shape
+    GeometryConversion(&'static str),
+    /// Error returned when the header of the .shx file does not
+    /// match the header of the .shp file
+    InconsistentIndex {
+        /// The shape type declared in the .shp header
+        shp_type: ShapeType,
+        /// The shape type declared in the .shx header
+        shx_type: ShapeType,
+    },
+    /// Error returned when a name given for a dbase field is not valid
+    InvalidFieldName(String),
+    /// Error returned when the data given to create a shape is not valid
+    InvalidShape(&'static str),
+    /// Error returned when the .shp and the .dbf do not have
+    /// the same number of entries
+    RecordCountMismatch {
+        /// The number of shapes in the .shp
+        shp: usize,
+        /// The number of records in the .dbf
+        dbf: usize,
+    },
+    /// Error returned when a record declares a size bigger than the
+    /// maximum allowed by the reader, see [ShapeReader::set_max_record_size]
+    RecordTooLarge {
+        /// The size in bytes declared by the record header
+        size: usize,
+        /// The maximum size in bytes allowed
+        max: usize,
+    },
 }
 
 impl From<std::io::Error> for Error {
@@ -130,6 +172,28 @@ impl fmt::Display for Error {
                 "The requested type: '{}' does not correspond to the actual shape type: '{}'",
                 requested, actual
             ),
+            Error::GeometryConversion(msg) => write!(f, "{}", msg),
+            Error::InvalidShape(msg) => write!(f, "{}", msg),
+            Error::InconsistentIndex { shp_type, shx_type } => write!(
+                f,
+                "The .shx header (shape type: '{}') is not consistent with the .shp header (shape type: '{}')",
+                shx_type, shp_type
+            ),
+            Error::InvalidFieldName(name) => write!(
+                f,
+                "'{}' is not a valid dbase field name (it cannot exceed 11 bytes)",
+                name
+            ),
+            Error::RecordCountMismatch { shp, dbf } => write!(
+                f,
+                "The .shp has {} shapes but the .dbf has {} records",
+                shp, dbf
+            ),
+            Error::RecordTooLarge { size, max } => write!(
+                f,
+                "The record has a size of {} bytes, the maximum allowed is {} bytes",
+                size, max
+            ),
             e => write!(f, "{:?}", e),
         }
     }
@@ -139,7 +203,7 @@ impl std::error::Error for Error {}
 
 /// The enum for the ShapeType as defined in the
 /// specification
-#[derive(Debug, PartialEq, Copy, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
 pub enum ShapeType {
     NullShape = 0,
     Point = 1,
@@ -226,6 +290,29 @@ impl ShapeType {
         )
     }
 
+    /// Returns the 2D shape type corresponding to this one,
+    /// e.g. `PolylineZ` and `PolylineM` give `Polyline`
+    ///
+    /// 2D types and `NullShape` are returned unchanged,
+    /// as is `Multipatch`, which has no 2D equivalent.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use shapefile::ShapeType;
+    /// assert_eq!(ShapeType::PolygonZ.to_2d(), ShapeType::Polygon);
+    /// assert_eq!(ShapeType::MultipointM.to_2d(), ShapeType::Multipoint);
+    /// ```
+    pub fn to_2d(self) -> ShapeType {
+        match self {
+            ShapeType::PointM | ShapeType::PointZ => ShapeType::Point,
+            ShapeType::PolylineM | ShapeType::PolylineZ => ShapeType::Polyline,
+            ShapeType::PolygonM | ShapeType::PolygonZ => ShapeType::Polygon,
+            ShapeType::MultipointM | ShapeType::MultipointZ => ShapeType::Multipoint,
+            shape_type => shape_type,
+        }
+    }
+
     /// Returns true if the shape may have multiple parts
     pub fn is_multipart(self) -> bool {
         !matches!(
@@ -55,22 +55,28 @@
 //! - [read_shapes]
 //! - [read_shapes_as]
 
+use std::collections::HashMap;
 use std::fs::File;
-use std::io::{BufReader, Read, Seek, SeekFrom, Write};
+use std::io::{BufReader, Cursor, Read, Seek, SeekFrom, Write};
 use std::path::Path;
 
 use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
 
 use crate::header;
 use crate::record;
-use crate::record::ReadableShape;
-use crate::{Error, Shape};
+use crate::record::{BBoxZ, ConcreteReadableShape, EsriShape, ReadableShape};
+use crate::{Error, Shape, ShapeType};
 
 const INDEX_RECORD_SIZE: usize = 2 * std::mem::size_of::<i32>();
 
-#[derive(Copy, Clone)]
-pub(crate) struct ShapeIndex {
+/// An entry of the _.shx_ file
+///
+/// Both values are expressed in 16-bit words, as in the file.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ShapeIndex {
+    /// Position of the record (including its header) in the _.shp_
     pub offset: i32,
+    /// Size of the record content (so excluding its header)
     pub record_size: i32,
 }
 
@@ -83,7 +89,14 @@ impl ShapeIndex {
 }
 
 /// Read the content of a .shx file
-fn read_index_file<T: Read>(mut source: T) -> Result<Vec<ShapeIndex>, Error> {
+fn read_index_file<T: Read>(source: T) -> Result<Vec<ShapeIndex>, Error> {
+    read_index_file_with_header(source).map(|(_, shapes_index)| shapes_index)
+}
+
+/// Read the content of a .shx file, also returning its header
+fn read_index_file_with_header<T: Read>(
+    mut source: T,
+) -> Result<(header::Header, Vec<ShapeIndex>), Error> {
     let header = header::Header::read_from(&mut source)?;
 
     let num_shapes = ((header.file_length * 2) - header::HEADER_SIZE) / INDEX_RECORD_SIZE as i32;
@@ -96,16 +109,69 @@ fn read_index_file<T: Read>(mut source: T) -> Result<Vec<ShapeIndex>, Error> {
             record_size,
         });
     }
-    Ok(shapes_index)
+    Ok((header, shapes_index))
+}
+
+/// Capacity of the buffers used by [ShapeReader::from_path], 8 KiB
+/// (the same as the default capacity of [BufReader])
+const DEFAULT_BUF_SIZE: usize = 8 * 1024;
+
+/// Default value of the maximum record size of a [ShapeReader], 256 MiB
+const DEFAULT_MAX_RECORD_SIZE: usize = 256 * 1024 * 1024;
+
+/// Returns an error if the record size (in 16-bit words, as in the file)
+/// is bigger than `max_record_size` bytes
+fn check_record_size(record_size: i32, max_record_size: usize) -> Result<(), Error> {
+    let size = record_size.max(0) as usize * 2;
+    if size > max_record_size {
+        Err(Error::RecordTooLarge {
+            size,
+            max: max_record_size,
+        })
+    } else {
+        Ok(())
+    }
 }
 
 /// Reads and returns one shape and its header from the source
+///
+/// The record size is checked against `max_record_size`
+/// before the shape is read using the `options`.
 fn read_one_shape_as<T: Read, S: ReadableShape>(
     mut source: &mut T,
+    max_record_size: usize,
+    options: ReadOptions,
 ) -> Result<(record::RecordHeader, S), Error> {
     let hdr = record::RecordHeader::read_from(&mut source)?;
+    check_record_size(hdr.record_size, max_record_size)?;
     let record_size = hdr.record_size * 2;
-    let shape = S::read_from(&mut source, record_size)?;
+    let shape = S::read_from_with_options(&mut source, record_size, options)?;
+    Ok((hdr, shape))
+}
+
+/// Reads the shape at `current_pos` in the source and moves `current_pos` after it
+///
+/// When the _.shx_ entry of the shape is given, the source is first moved to its offset.
+/// Its 'safer' to seek to the shape offset when we have the `shx` file
+/// as some shapes may not be stored sequentially and may contain 'garbage'
+/// bytes between them
+fn read_shape_at_as<T: Read + Seek, S: ReadableShape>(
+    source: &mut T,
+    current_pos: &mut usize,
+    index_entry: Option<&ShapeIndex>,
+    max_record_size: usize,
+    options: ReadOptions,
+) -> Result<(record::RecordHeader, S), Error> {
+    if let Some(index_entry) = index_entry {
+        let start_pos = index_entry.offset * 2;
+        if start_pos != *current_pos as i32 {
+            source.seek(SeekFrom::Start(start_pos as u64))?;
+            *current_pos = start_pos as usize;
+        }
+    }
+    let (hdr, shape) = read_one_shape_as::<T, S>(source, max_record_size, options)?;
+    *current_pos += record::RecordHeader::SIZE;
+    *current_pos += hdr.record_size as usize * 2;
     Ok((hdr, shape))
 }
 
@@ -122,35 +188,151 @@ pub struct ShapeIterator<'a, T: Read, S: ReadableShape> {
     // Iterator over the shape indices, used to seek
     // to the start of a shape when reading
     shapes_indices: Option<std::slice::Iter<'a, ShapeIndex>>,
+    // Records bigger than this are not read
+    max_record_size: usize,
+    options: ReadOptions,
+}
+
+impl<T: Read + Seek, S: ReadableShape> ShapeIterator<'_, T, S> {
+    /// Reads the next shape along with its record header
+    fn next_with_header(&mut self) -> Option<Result<(record::RecordHeader, S), Error>> {
+        if self.current_pos >= self.file_length {
+            return None;
+        }
+        let index_entry = match self.shapes_indices {
+            Some(ref mut shapes_indices) => Some(shapes_indices.next()?),
+            None => None,
+        };
+        Some(read_shape_at_as(
+            self.source,
+            &mut self.current_pos,
+            index_entry,
+            self.max_record_size,
+            self.options,
+        ))
+    }
 }
 
 impl<'a, T: Read + Seek, S: ReadableShape> Iterator for ShapeIterator<'a, T, S> {
     type Item = Result<S, crate::Error>;
 
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_with_header()
+            .map(|result| result.map(|(_, shape)| shape))
+    }
+}
+
+/// Struct that handle iteration over the shapes of a .shp file,
+/// while owning the [ShapeReader]
+///
+/// Created by the `into_iter` method of [ShapeReader].
+pub struct ShapeIntoIterator<T> {
+    reader: ShapeReader<T>,
+    // Current position in bytes in the source.
+    current_pos: usize,
+    // How many bytes the header said there are in
+    // the file.
+    file_length: usize,
+    // Index of the next shape, used to seek
+    // to the start of a shape when reading
+    next_index: usize,
+}
+
+impl<T: Read + Seek> Iterator for ShapeIntoIterator<T> {
+    type Item = Result<Shape, Error>;
+
     fn next(&mut self) -> Option<Self::Item> {
         if self.current_pos >= self.file_length {
-            None
-        } else {
-            if let Some(ref mut shapes_indices) = self.shapes_indices {
-                // Its 'safer' to seek to the shape offset when we have the `shx` file
-                // as some shapes may not be stored sequentially and may contain 'garbage'
-                // bytes between them
-                let start_pos = shapes_indices.next()?.offset * 2;
-                if start_pos != self.current_pos as i32 {
-                    if let Err(err) = self.source.seek(SeekFrom::Start(start_pos as u64)) {
-                        return Some(Err(err.into()));
-                    }
-                    self.current_pos = start_pos as usize;
-                }
+            return None;
+        }
+        let index_entry = match self.reader.shapes_index {
+            Some(ref shapes_index) => Some(shapes_index.get(self.next_index)?),
+            None => None,
+        };
+        let result = read_shape_at_as::<T, Shape>(
+            &mut self.reader.source,
+            &mut self.current_pos,
+            index_entry,
+            self.reader.max_record_size,
+            self.reader.options,
+        );
+        self.next_index += 1;
+        Some(result.map(|(_, shape)| shape))
+    }
+}
+
+/// Struct that handle iteration over the shapes of a .shp file
+/// until the end of the source, ignoring the file length of the header
+///
+/// Created by [ShapeReader::iter_until_eof].
+pub struct ShapeUntilEofIterator<'a, T: Read, S: ReadableShape> {
+    _shape: std::marker::PhantomData<S>,
+    source: &'a mut T,
+    // Whether the source was moved to the first record
+    started: bool,
+    // Set when the end of the source or an error was reached
+    done: bool,
+    // Records bigger than this are not read
+    max_record_size: usize,
+    options: ReadOptions,
+}
+
+impl<T: Read + Seek, S: ReadableShape> ShapeUntilEofIterator<'_, T, S> {
+    /// Reads the next record header, returns `None` on a clean EOF
+    fn read_record_header(&mut self) -> Result<Option<record::RecordHeader>, Error> {
+        let mut buffer = [0u8; record::RecordHeader::SIZE];
+        let mut num_read = 0;
+        while num_read < buffer.len() {
+            match self.source.read(&mut buffer[num_read..]) {
+                Ok(0) => break,
+                Ok(n) => num_read += n,
+                Err(err) if err.kind() == std::io::ErrorKind::Interrupted => {}
+                Err(err) => return Err(err.into()),
             }
-            let (hdr, shape) = match read_one_shape_as::<T, S>(self.source) {
-                Err(e) => return Some(Err(e)),
-                Ok(hdr_and_shape) => hdr_and_shape,
-            };
-            self.current_pos += record::RecordHeader::SIZE;
-            self.current_pos += hdr.record_size as usize * 2;
-            Some(Ok(shape))
         }
+        match num_read {
+            0 => Ok(None),
+            n if n == buffer.len() => {
+                record::RecordHeader::read_from(&mut Cursor::new(&buffer)).map(Some)
+            }
+            _ => Err(std::io::Error::from(std::io::ErrorKind::UnexpectedEof).into()),
+        }
+    }
+}
+
+impl<T: Read + Seek, S: ReadableShape> Iterator for ShapeUntilEofIterator<'_, T, S> {
+    type Item = Result<S, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        if !self.started {
+            self.started = true;
+            if let Err(err) = self
+                .source
+                .seek(SeekFrom::Start(header::HEADER_SIZE as u64))
+            {
+                self.done = true;
+                return Some(Err(err.into()));
+            }
+        }
+        let result = match self.read_record_header() {
+            Ok(None) => {
+                self.done = true;
+                return None;
+            }
+            Ok(Some(hdr)) => {
+                check_record_size(hdr.record_size, self.max_record_size).and_then(|_| {
+                    S::read_from_with_options(self.source, hdr.record_size * 2, self.options)
+                })
+            }
+            Err(err) => Err(err),
+        };
+        if result.is_err() {
+            self.done = true;
+        }
+        Some(result)
     }
 }
 
@@ -185,12 +367,240 @@ impl<'a, T: Read + Seek, D: Read + Seek, S: ReadableShape, R: dbase::ReadableRec
     }
 }
 
+/// Options used by [ShapeReader::read_with_options] and [ShapeReader::set_read_options]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ReadOptions {
+    /// Measures less or equal to this value are read as [NO_DATA](crate::NO_DATA)
+    ///
+    /// Defaults to [NO_DATA](crate::NO_DATA), as in the specification,
+    /// but some producers use other values (e.g `0.0`) to mean _no measure_.
+    pub no_data_threshold: f64,
+}
+
+impl Default for ReadOptions {
+    fn default() -> Self {
+        Self {
+            no_data_threshold: crate::NO_DATA,
+        }
+    }
+}
+
+/// Returns true if the x and y ranges of the bboxes are equal,
+/// as well as their z and m ranges if the shape type has them
+pub(crate) fn bboxes_match(
+    header_bbox: &BBoxZ,
+    computed_bbox: &BBoxZ,
+    shape_type: ShapeType,
+) -> bool {
+    let mut matches = header_bbox.x_range() == computed_bbox.x_range()
+        && header_bbox.y_range() == computed_bbox.y_range();
+    if shape_type.has_z() {
+        matches &= header_bbox.z_range() == computed_bbox.z_range();
+    }
+    if shape_type.has_m() {
+        matches &= header_bbox.m_range() == computed_bbox.m_range();
+    }
+    matches
+}
+
+/// Result of [ShapeReader::verify_bbox]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct BboxVerification {
+    /// The bounding box declared in the header of the file
+    pub header_bbox: BBoxZ,
+    /// The bounding box computed from the shapes of the file
+    pub computed_bbox: BBoxZ,
+    /// Whether both bounding boxes are the same
+    ///
+    /// The `z` and `m` ranges are only compared when the shape type
+    /// of the file has them.
+    pub matches: bool,
+}
+
+/// Shapes of a file grouped by type, returned by [ShapeReader::read_grouped]
+#[derive(Debug, Default, PartialEq)]
+pub struct GroupedShapes {
+    /// Number of null shapes
+    pub null_shapes: usize,
+    pub points: Vec<record::Point>,
+    pub points_m: Vec<record::PointM>,
+    pub points_z: Vec<record::PointZ>,
+    pub polylines: Vec<record::Polyline>,
+    pub polylines_m: Vec<record::PolylineM>,
+    pub polylines_z: Vec<record::PolylineZ>,
+    pub polygons: Vec<record::Polygon>,
+    pub polygons_m: Vec<record::PolygonM>,
+    pub polygons_z: Vec<record::PolygonZ>,
+    pub multipoints: Vec<record::Multipoint>,
+    pub multipoints_m: Vec<record::MultipointM>,
+    pub multipoints_z: Vec<record::MultipointZ>,
+    pub multipatches: Vec<record::Multipatch>,
+}
+
+impl GroupedShapes {
+    fn push(&mut self, shape: Shape) {
+        match shape {
+            Shape::NullShape => self.null_shapes += 1,
+            Shape::Point(shape) => self.points.push(shape),
+            Shape::PointM(shape) => self.points_m.push(shape),
+            Shape::PointZ(shape) => self.points_z.push(shape),
+            Shape::Polyline(shape) => self.polylines.push(shape),
+            Shape::PolylineM(shape) => self.polylines_m.push(shape),
+            Shape::PolylineZ(shape) => self.polylines_z.push(shape),
+            Shape::Polygon(shape) => self.polygons.push(shape),
+            Shape::PolygonM(shape) => self.polygons_m.push(shape),
+            Shape::PolygonZ(shape) => self.polygons_z.push(shape),
+            Shape::Multipoint(shape) => self.multipoints.push(shape),
+            Shape::MultipointM(shape) => self.multipoints_m.push(shape),
+            Shape::MultipointZ(shape) => self.multipoints_z.push(shape),
+            Shape::Multipatch(shape) => self.multipatches.push(shape),
+        }
+    }
+}
+
+/// Kind of fix applied by [ShapeReader::read_as_lenient_polygons]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PolygonFixupKind {
+    /// The first point of the ring was appended to close it
+    ClosedRing,
+    /// The ring had less than 3 distinct points and was removed
+    DroppedRing,
+}
+
+/// A fix applied to a ring by [ShapeReader::read_as_lenient_polygons]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct PolygonFixup {
+    /// The record number (starting at 1) of the polygon
+    pub record_number: usize,
+    /// The index of the ring, as it is in the file
+    pub ring: usize,
+    pub kind: PolygonFixupKind,
+}
+
+/// Polygons and fixes returned by [ShapeReader::read_as_lenient_polygons]
+type LenientPolygons<PointType> = (
+    Vec<Option<record::polygon::GenericPolygon<PointType>>>,
+    Vec<PolygonFixup>,
+);
+
+/// Returns true if the points have at least 3 distinct points
+fn has_three_distinct_points<PointType: PartialEq>(points: &[PointType]) -> bool {
+    let mut distinct = Vec::<&PointType>::with_capacity(3);
+    for point in points {
+        if !distinct.contains(&point) {
+            distinct.push(point);
+            if distinct.len() == 3 {
+                return true;
+            }
+        }
+    }
+    false
+}
+
+/// Closes the open rings of the polygon and drops the degenerated ones,
+/// returns `None` if no ring is left
+fn fix_polygon<PointType>(
+    polygon: record::polygon::GenericPolygon<PointType>,
+    record_number: usize,
+    fixups: &mut Vec<PolygonFixup>,
+) -> Option<record::polygon::GenericPolygon<PointType>>
+where
+    PointType: record::traits::ShrinkablePoint
+        + record::traits::GrowablePoint
+        + record::traits::HasXY
+        + PartialEq
+        + Copy,
+{
+    let mut rings = Vec::with_capacity(polygon.rings().len());
+    // The numbers of the rings are the ones of the file
+    let added_rings = polygon.rings().len() - polygon.file_rings().len();
+    for (ring, mut points) in polygon
+        .into_inner()
+        .into_iter()
+        .skip(added_rings)
+        .map(record::PolygonRing::into_inner)
+        .enumerate()
+    {
+        let fix = |kind| PolygonFixup {
+            record_number,
+            ring,
+            kind,
+        };
+        if !has_three_distinct_points(&points) {
+            fixups.push(fix(PolygonFixupKind::DroppedRing));
+            continue;
+        }
+        if !record::is_part_closed(&points) {
+            record::close_points_if_not_already(&mut points);
+            fixups.push(fix(PolygonFixupKind::ClosedRing));
+        }
+        // The ring type is inferred again, as the winding of an open ring may be wrong
+        rings.push(record::PolygonRing::from(points));
+    }
+
+    if rings.is_empty() {
+        None
+    } else {
+        Some(
+            record::polygon::GenericPolygon::with_rings_unchecked(rings)
+                .attach_orphan_inner_rings(),
+        )
+    }
+}
+
+/// Coordinates of points stored as one array per dimension,
+/// returned by [ShapeReader::read_all_into_soa]
+///
+/// The arrays of the dimensions the points do not have are empty.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct StructOfArrays {
+    pub x: Vec<f64>,
+    pub y: Vec<f64>,
+    pub z: Vec<f64>,
+    pub m: Vec<f64>,
+}
+
+/// Point types that can be read by [ShapeReader::read_all_into_soa]
+///
+/// This trait is implemented for [Point](record::Point),
+/// [PointM](record::PointM) and [PointZ](record::PointZ).
+pub trait SoaPoint: ReadableShape {
+    /// Pushes the coordinates of the point to the arrays
+    fn push_to(self, soa: &mut StructOfArrays);
+}
+
+impl SoaPoint for record::Point {
+    fn push_to(self, soa: &mut StructOfArrays) {
+        soa.x.push(self.x);
+        soa.y.push(self.y);
+    }
+}
+
+impl SoaPoint for record::PointM {
+    fn push_to(self, soa: &mut StructOfArrays) {
+        soa.x.push(self.x);
+        soa.y.push(self.y);
+        soa.m.push(self.m);
+    }
+}
+
+impl SoaPoint for record::PointZ {
+    fn push_to(self, soa: &mut StructOfArrays) {
+        soa.x.push(self.x);
+        soa.y.push(self.y);
+        soa.z.push(self.z);
+        soa.m.push(self.m);
+    }
+}
+
 /// This reader only reads the `.shp` and optionally the (`.shx`) files
 /// of a shapefile.
 pub struct ShapeReader<T> {
     source: T,
     header: header::Header,
     shapes_index: Option<Vec<ShapeIndex>>,
+    max_record_size: usize,
+    options: ReadOptions,
 }
 
 impl<T: Read> ShapeReader<T> {
@@ -223,6 +633,8 @@ impl<T: Read> ShapeReader<T> {
             source,
             header,
             shapes_index: None,
+            max_record_size: DEFAULT_MAX_RECORD_SIZE,
+            options: ReadOptions::default(),
         })
     }
 
@@ -253,9 +665,120 @@ impl<T: Read> ShapeReader<T> {
             source,
             header,
             shapes_index,
+            max_record_size: DEFAULT_MAX_RECORD_SIZE,
+            options: ReadOptions::default(),
+        })
+    }
+
+    /// Creates a new ShapeReader using 2 sources, one for the _.shp_
+    /// the other for the _.shx_, and checks that their headers are consistent
+    ///
+    /// Unlike [ShapeReader::with_shx], the header of the _.shx_ is compared to
+    /// the header of the _.shp_.
+    ///
+    /// # Errors
+    ///
+    /// Returns [Error::InconsistentIndex] if the shape type or the bounding box
+    /// of the two headers differ.
+    ///
+    /// # Example
+    /// ```
+    /// # fn main() -> Result<(), shapefile::Error> {
+    /// use std::fs::File;
+    /// let shp_file = File::open("tests/data/line.shp")?;
+    /// let shx_file = File::open("tests/data/line.shx")?;
+    /// let reader = shapefile::ShapeReader::with_shx_validated(shp_file, shx_file)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_shx_validated<ShxSource>(
+        mut source: T,
+        shx_source: ShxSource,
+    ) -> Result<Self, Error>
+    where
+        ShxSource: Read,
+    {
+        let (shx_header, shapes_index) = read_index_file_with_header(shx_source)?;
+        let header = header::Header::read_from(&mut source)?;
+
+        if header.shape_type != shx_header.shape_type || header.bbox != shx_header.bbox {
+            return Err(Error::InconsistentIndex {
+                shp_type: header.shape_type,
+                shx_type: shx_header.shape_type,
+            });
+        }
+
+        Ok(Self {
+            source,
+            header,
+            shapes_index: Some(shapes_index),
+            max_record_size: DEFAULT_MAX_RECORD_SIZE,
+            options: ReadOptions::default(),
         })
     }
 
+    /// Returns the entries of the _.shx_ file, if it was read
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), shapefile::Error> {
+    /// let reader = shapefile::ShapeReader::from_path("tests/data/line.shp")?;
+    /// let entries = reader.index_entries().unwrap();
+    /// assert_eq!(entries.len(), 1);
+    /// // The first record starts right after the 100 bytes header
+    /// assert_eq!(entries[0].offset * 2, 100);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn index_entries(&self) -> Option<&[ShapeIndex]> {
+        self.shapes_index.as_deref()
+    }
+
+    /// Sets the maximum size in bytes of the records that can be read (256 MiB by default)
+    ///
+    /// Reading a record whose header declares a bigger size returns an
+    /// [Error::RecordTooLarge] before anything is allocated, which protects
+    /// against corrupted or malicious files.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), shapefile::Error> {
+    /// let mut reader = shapefile::ShapeReader::from_path("tests/data/line.shp")?;
+    /// reader.set_max_record_size(16);
+    /// let result = reader.iter_shapes().next().unwrap();
+    /// assert!(matches!(result, Err(shapefile::Error::RecordTooLarge { max: 16, .. })));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_max_record_size(&mut self, bytes: usize) {
+        self.max_record_size = bytes;
+    }
+
+    /// Sets the options used when reading the shapes
+    ///
+    /// The options apply to all the reading methods and iterators,
+    /// whatever the type the shapes are read as.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), shapefile::Error> {
+    /// use shapefile::reader::ReadOptions;
+    /// let mut reader = shapefile::ShapeReader::from_path("tests/data/pointm.shp")?;
+    /// reader.set_read_options(ReadOptions { no_data_threshold: 0.0 });
+    /// for point in reader.iter_shapes_as::<shapefile::PointM>() {
+    ///     let point = point?;
+    ///     assert!(point.m > 0.0 || point.m == shapefile::NO_DATA);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_read_options(&mut self, options: ReadOptions) {
+        self.options = options;
+    }
+
     /// Returns a non-mutable reference to the header read
     ///
     /// # Examples
@@ -271,6 +794,21 @@ impl<T: Read> ShapeReader<T> {
     pub fn header(&self) -> &header::Header {
         &self.header
     }
+
+    /// Returns the length of the .shp file in bytes, as declared in its header
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> Result<(), shapefile::Error> {
+    /// let reader = shapefile::ShapeReader::from_path("tests/data/point.shp")?;
+    /// assert_eq!(reader.shp_byte_length(), 128);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn shp_byte_length(&self) -> u64 {
+        self.header.length_in_bytes()
+    }
 }
 
 impl<T: Read + Seek> ShapeReader<T> {
@@ -328,75 +866,559 @@ impl<T: Read + Seek> ShapeReader<T> {
         self.iter_shapes_as::<Shape>().collect()
     }
 
-    /// Returns an iterator that tries to read the shapes as the specified type
-    /// Will return an error of the type `S` does not match the actual type in the file
+    /// Reads all the shapes and returns them, using the given options
     ///
     /// # Examples
     ///
     /// ```
     /// # fn main() -> Result<(), shapefile::Error> {
-    /// let mut reader = shapefile::ShapeReader::from_path("tests/data/multipoint.shp")?;
-    /// for multipoints in reader.iter_shapes_as::<shapefile::Multipoint>() {
-    ///     let points = multipoints?;
-    ///     println!("{}", points);
-    /// }
+    /// use shapefile::reader::ReadOptions;
+    /// let reader = shapefile::ShapeReader::from_path("tests/data/pointm.shp")?;
+    /// // Consider measures that are not positive as no data
+    /// let options = ReadOptions { no_data_threshold: 0.0 };
+    /// let shapes = reader.read_with_options(options)?;
     /// # Ok(())
     /// # }
     /// ```
-    pub fn iter_shapes_as<S: ReadableShape>(&mut self) -> ShapeIterator<'_, T, S> {
-        ShapeIterator {
-            _shape: std::marker::PhantomData,
-            source: &mut self.source,
-            current_pos: header::HEADER_SIZE as usize,
-            file_length: (self.header.file_length as usize) * 2,
-            shapes_indices: self.shapes_index.as_ref().map(|s| s.iter()),
-        }
+    pub fn read_with_options(mut self, options: ReadOptions) -> Result<Vec<Shape>, Error> {
+        self.set_read_options(options);
+        self.read()
     }
 
-    /// Returns an iterator that to reads the shapes wraps them in the enum [Shape](enum.Shape.html)
-    /// You do not need to call this method and can iterate over the `Reader` directly
+    /// Reads all the shapes as the specified type into `out`
+    ///
+    /// `out` is cleared before reading, which allows to reuse the same `Vec`
+    /// (and its allocation) across multiple reads.
+    /// When the _.shx_ is available, the exact number of shapes is reserved.
     ///
     /// # Examples
     ///
     /// ```
     /// # fn main() -> Result<(), shapefile::Error> {
-    /// let mut reader = shapefile::ShapeReader::from_path("tests/data/multipoint.shp")?;
-    /// for shape in reader.iter_shapes() {
-    ///     match shape? {
-    ///         shapefile::Shape::Multipatch(shp) => println!("Multipoint!"),
-    ///         _ => println!("Other type of shape"),
-    ///     }
+    /// let mut points = Vec::<shapefile::Point>::new();
+    /// for path in &["tests/data/point.shp", "tests/data/point.shp"] {
+    ///     let mut reader = shapefile::ShapeReader::from_path(path)?;
+    ///     reader.read_into(&mut points)?;
+    ///     assert_eq!(points.len(), 1);
     /// }
     /// # Ok(())
     /// # }
     /// ```
+    pub fn read_into<S: ReadableShape>(&mut self, out: &mut Vec<S>) -> Result<(), Error> {
+        out.clear();
+        if let Some(ref shapes_index) = self.shapes_index {
+            out.reserve(shapes_index.len());
+        }
+        self.source
+            .seek(SeekFrom::Start(header::HEADER_SIZE as u64))?;
+        for shape in self.iter_shapes_as::<S>() {
+            out.push(shape?);
+        }
+        Ok(())
+    }
+
+    /// Reads all the shapes to compute their bounding box and compares it
+    /// with the bounding box declared in the header
+    ///
+    /// # Examples
     ///
     /// ```
     /// # fn main() -> Result<(), shapefile::Error> {
-    /// let mut reader = shapefile::ShapeReader::from_path("tests/data/multipoint.shp")?;
-    /// for shape in reader.iter_shapes() {
-    ///     match shape? {
-    ///         shapefile::Shape::Multipatch(shp) => println!("Multipoint!"),
-    ///         _ => println!("Other type of shape"),
-    ///     }
-    /// }
+    /// let mut reader = shapefile::ShapeReader::from_path("tests/data/line.shp")?;
+    /// let verification = reader.verify_bbox::<shapefile::Polyline>()?;
+    /// assert!(verification.matches);
+    ///
+    /// // Reading as Shape also verifies files that have null shapes
+    /// let verification = reader.verify_bbox::<shapefile::Shape>()?;
+    /// assert!(verification.matches);
     /// # Ok(())
     /// # }
     /// ```
-    pub fn iter_shapes(&mut self) -> ShapeIterator<'_, T, Shape> {
-        self.iter_shapes_as::<Shape>()
+    pub fn verify_bbox<S: ReadableShape + Into<Shape>>(
+        &mut self,
+    ) -> Result<BboxVerification, Error> {
+        let header_bbox = self.header.bbox;
+        let shape_type = self.header.shape_type;
+
+        self.source
+            .seek(SeekFrom::Start(header::HEADER_SIZE as u64))?;
+        let mut computed_bbox = BBoxZ::inverted();
+        let mut is_empty = true;
+        for shape in self.iter_shapes_as::<S>() {
+            let shape = shape?.into();
+            if let Shape::NullShape = shape {
+                continue;
+            }
+            computed_bbox.grow_from_any_shape(&shape);
+            is_empty = false;
+        }
+        if is_empty {
+            computed_bbox = BBoxZ::default();
+        }
+        computed_bbox.zero_ungrown_z_and_m();
+
+        Ok(BboxVerification {
+            header_bbox,
+            computed_bbox,
+            matches: bboxes_match(&header_bbox, &computed_bbox, shape_type),
+        })
     }
 
-    /// Reads the `n`th shape of the shapefile
-    ///
-    /// # Important
+    /// Reads all the shapes and counts how many there are of each shape type
     ///
-    /// Even though in shapefiles, shapes are indexed starting from '1'.
-    /// this method expects indexes starting from 0.
+    /// A valid shapefile only has one shape type, but `NullShape` records can be
+    /// interspersed with them.
     ///
-    /// # Returns
+    /// # Examples
     ///
-    /// `None` if the index is out of range
+    /// ```
+    /// # fn main() -> Result<(), shapefile::Error> {
+    /// use shapefile::ShapeType;
+    /// let mut reader = shapefile::ShapeReader::from_path("tests/data/pointm.shp")?;
+    /// let histogram = reader.shape_type_histogram()?;
+    /// assert_eq!(histogram[&ShapeType::PointM], 2);
+    /// assert_eq!(histogram.get(&ShapeType::NullShape), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn shape_type_histogram(&mut self) -> Result<HashMap<ShapeType, usize>, Error> {
+        self.source
+            .seek(SeekFrom::Start(header::HEADER_SIZE as u64))?;
+        let mut histogram = HashMap::new();
+        for shape in self.iter_shapes() {
+            *histogram.entry(shape?.shapetype()).or_insert(0) += 1;
+        }
+        Ok(histogram)
+    }
+
+    /// Reads all the shapes, grouping them by type
+    ///
+    /// Null shapes are only counted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> Result<(), shapefile::Error> {
+    /// let mut reader = shapefile::ShapeReader::from_path("tests/data/line.shp")?;
+    /// let grouped = reader.read_grouped()?;
+    /// assert_eq!(grouped.polylines.len(), 1);
+    /// assert!(grouped.points.is_empty());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_grouped(&mut self) -> Result<GroupedShapes, Error> {
+        self.source
+            .seek(SeekFrom::Start(header::HEADER_SIZE as u64))?;
+        let mut grouped = GroupedShapes::default();
+        for shape in self.iter_shapes() {
+            grouped.push(shape?);
+        }
+        Ok(grouped)
+    }
+
+    /// Reads all the polygons, fixing the rings that do not respect the specification
+    ///
+    /// Rings that are not closed are closed, and rings with less than 3 distinct points
+    /// are dropped. The fixes that were applied are returned alongside the polygons.
+    ///
+    /// `NullShape` records, and polygons for which no ring is left, are read as `None`,
+    /// so that the polygons stay aligned with the records of the _.dbf_.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), shapefile::Error> {
+    /// use shapefile::reader::PolygonFixupKind;
+    /// let mut reader = shapefile::ShapeReader::from_path("tests/data/polygon.shp")?;
+    /// let (polygons, fixups) = reader.read_as_lenient_polygons::<shapefile::Point>()?;
+    /// assert_eq!(polygons.len(), 1);
+    /// // The 3 rings of this file are not closed
+    /// assert_eq!(fixups.len(), 3);
+    /// assert!(fixups.iter().all(|fixup| fixup.kind == PolygonFixupKind::ClosedRing));
+    /// for ring in polygons[0].as_ref().unwrap().rings() {
+    ///     assert_eq!(ring.points().first(), ring.points().last());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_as_lenient_polygons<PointType>(
+        &mut self,
+    ) -> Result<LenientPolygons<PointType>, Error>
+    where
+        PointType: record::traits::ShrinkablePoint
+            + record::traits::GrowablePoint
+            + record::traits::HasXY
+            + PartialEq
+            + Copy,
+        record::polygon::GenericPolygon<PointType>: ConcreteReadableShape,
+    {
+        self.source
+            .seek(SeekFrom::Start(header::HEADER_SIZE as u64))?;
+        let mut polygons = Vec::new();
+        let mut fixups = Vec::new();
+        for (i, polygon) in self
+            .iter_shapes_as::<Option<record::polygon::GenericPolygon<PointType>>>()
+            .enumerate()
+        {
+            let polygon = polygon?.and_then(|polygon| fix_polygon(polygon, i + 1, &mut fixups));
+            polygons.push(polygon);
+        }
+        Ok((polygons, fixups))
+    }
+
+    /// Reads all the points of the file in one array per dimension
+    ///
+    /// Compared to reading a `Vec` of points, this gives contiguous arrays
+    /// of coordinates, which suits bulk numeric processing.
+    ///
+    /// Only implemented for the point shape types, see [SoaPoint].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), shapefile::Error> {
+    /// let mut reader = shapefile::ShapeReader::from_path("tests/data/pointm.shp")?;
+    /// let soa = reader.read_all_into_soa::<shapefile::PointM>()?;
+    /// assert_eq!(soa.x.len(), 2);
+    /// assert_eq!(soa.m.len(), 2);
+    /// assert!(soa.z.is_empty());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_all_into_soa<S: SoaPoint>(&mut self) -> Result<StructOfArrays, Error> {
+        self.source
+            .seek(SeekFrom::Start(header::HEADER_SIZE as u64))?;
+        let capacity = self.header.point_shape_count().unwrap_or(0);
+        let mut soa = StructOfArrays::default();
+        soa.x.reserve(capacity);
+        soa.y.reserve(capacity);
+        for point in self.iter_shapes_as::<S>() {
+            point?.push_to(&mut soa);
+        }
+        Ok(soa)
+    }
+
+    /// Returns an iterator over the shape type of each record
+    ///
+    /// Only the record header and the shape type are read, the shapes themselves
+    /// are skipped, which makes it cheap to find records that do not
+    /// have the type of the file.
+    ///
+    /// The records are located using the _.shx_ if there is one,
+    /// otherwise using the record sizes of the _.shp_.
+    /// A record with an invalid shape type yields an [Error::InvalidShapeType]
+    /// and the iteration continues, an I/O error ends it.
+    /// A record with a negative size yields an [Error::InvalidShapeRecordSize],
+    /// which also ends the iteration when there is no _.shx_.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), shapefile::Error> {
+    /// let mut reader = shapefile::ShapeReader::from_path("tests/data/line.shp")?;
+    /// for shape_type in reader.iter_record_types() {
+    ///     assert_eq!(shape_type?, shapefile::ShapeType::Polyline);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn iter_record_types(&mut self) -> impl Iterator<Item = Result<ShapeType, Error>> + '_ {
+        let file_length = (self.header.file_length as u64) * 2;
+        let mut offsets = self.shapes_index.as_ref().map(|index| index.iter());
+        let source = &mut self.source;
+        let mut next_pos = header::HEADER_SIZE as u64;
+        let mut done = false;
+        std::iter::from_fn(move || {
+            if done {
+                return None;
+            }
+            if let Some(ref mut offsets) = offsets {
+                next_pos = (offsets.next()?.offset as u64) * 2;
+            } else if next_pos >= file_length {
+                return None;
+            }
+
+            let hdr = source
+                .seek(SeekFrom::Start(next_pos))
+                .map_err(Error::from)
+                .and_then(|_| record::RecordHeader::read_from(source));
+            let hdr = match hdr {
+                Ok(hdr) => hdr,
+                Err(err) => {
+                    done = true;
+                    return Some(Err(err));
+                }
+            };
+            if hdr.record_size < 0 {
+                // Without index, the position of the next record is unknown
+                done = offsets.is_none();
+                return Some(Err(Error::InvalidShapeRecordSize));
+            }
+            let record_end = (hdr.record_size as u64 * 2)
+                .checked_add(record::RecordHeader::SIZE as u64)
+                .and_then(|record_length| next_pos.checked_add(record_length));
+            next_pos = match record_end {
+                Some(pos) => pos,
+                None => {
+                    done = true;
+                    return Some(Err(Error::InvalidShapeRecordSize));
+                }
+            };
+            let shape_type = ShapeType::read_from(source);
+            if let Err(Error::IoError(_)) = shape_type {
+                done = true;
+            }
+            Some(shape_type)
+        })
+    }
+
+    /// Returns an iterator that tries to read the shapes as the specified type
+    /// Will return an error of the type `S` does not match the actual type in the file
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> Result<(), shapefile::Error> {
+    /// let mut reader = shapefile::ShapeReader::from_path("tests/data/multipoint.shp")?;
+    /// for multipoints in reader.iter_shapes_as::<shapefile::Multipoint>() {
+    ///     let points = multipoints?;
+    ///     println!("{}", points);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn iter_shapes_as<S: ReadableShape>(&mut self) -> ShapeIterator<'_, T, S> {
+        ShapeIterator {
+            _shape: std::marker::PhantomData,
+            source: &mut self.source,
+            current_pos: header::HEADER_SIZE as usize,
+            file_length: (self.header.file_length as usize) * 2,
+            shapes_indices: self.shapes_index.as_ref().map(|s| s.iter()),
+            max_record_size: self.max_record_size,
+            options: self.options,
+        }
+    }
+
+    /// Returns an iterator over the shapes along with their bounding box
+    ///
+    /// For multi-part shapes and multipoints, this is the bounding box
+    /// stored in the record, for points (which have none in the file)
+    /// it is computed from the point.
+    /// The z and m ranges of shapes that do not have them are `[0.0, 0.0]`.
+    ///
+    /// This lets spatial filters use the bounding boxes of the file.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), shapefile::Error> {
+    /// let mut reader = shapefile::ShapeReader::from_path("tests/data/line.shp")?;
+    /// for shape_bbox in reader.iter_shapes_with_bbox::<shapefile::Polyline>() {
+    ///     let (polyline, bbox) = shape_bbox?;
+    ///     assert_eq!(bbox.x_range(), polyline.bbox().x_range());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn iter_shapes_with_bbox<'a, S: ReadableShape + EsriShape + 'a>(
+        &'a mut self,
+    ) -> impl Iterator<Item = Result<(S, BBoxZ), Error>> + 'a {
+        self.iter_shapes_as::<S>().map(|result| {
+            result.map(|shape| {
+                let bbox = BBoxZ::from_ranges(
+                    shape.x_range(),
+                    shape.y_range(),
+                    shape.z_range(),
+                    shape.m_range(),
+                );
+                (shape, bbox)
+            })
+        })
+    }
+
+    /// Returns an iterator that reads the shapes as the specified type
+    /// until the end of the file is reached
+    ///
+    /// Unlike [ShapeReader::iter_shapes_as], the file length written in the
+    /// header and the _.shx_ are ignored, the records are read one after the other,
+    /// which is useful for files written by tools that got the file length wrong.
+    ///
+    /// Reaching the end of the file right after a record ends the iteration,
+    /// reaching it in the middle of a record returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), shapefile::Error> {
+    /// let mut reader = shapefile::ShapeReader::from_path("tests/data/line.shp")?;
+    /// let polylines = reader
+    ///     .iter_until_eof::<shapefile::Polyline>()
+    ///     .collect::<Result<Vec<_>, _>>()?;
+    /// assert_eq!(polylines.len(), 1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn iter_until_eof<S: ReadableShape>(&mut self) -> ShapeUntilEofIterator<'_, T, S> {
+        ShapeUntilEofIterator {
+            _shape: std::marker::PhantomData,
+            source: &mut self.source,
+            started: false,
+            done: false,
+            max_record_size: self.max_record_size,
+            options: self.options,
+        }
+    }
+
+    /// Returns an iterator that tries to read the shapes as the specified type,
+    /// `NullShape` records are returned as `None`
+    ///
+    /// The spec allows `NullShape` records anywhere in a file, which makes
+    /// [ShapeReader::iter_shapes_as] fail with a `MismatchShapeType` error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> Result<(), shapefile::Error> {
+    /// let mut reader = shapefile::ShapeReader::from_path("tests/data/line.shp")?;
+    /// for polyline in reader.iter_shapes_as_optional::<shapefile::Polyline>() {
+    ///     match polyline? {
+    ///         Some(polyline) => println!("{}", polyline),
+    ///         None => println!("Null record"),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn iter_shapes_as_optional<S: ConcreteReadableShape>(
+        &mut self,
+    ) -> ShapeIterator<'_, T, Option<S>> {
+        self.iter_shapes_as::<Option<S>>()
+    }
+
+    /// Returns an iterator that to reads the shapes wraps them in the enum [Shape](enum.Shape.html)
+    /// You do not need to call this method and can iterate over the `Reader` directly
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> Result<(), shapefile::Error> {
+    /// let mut reader = shapefile::ShapeReader::from_path("tests/data/multipoint.shp")?;
+    /// for shape in reader.iter_shapes() {
+    ///     match shape? {
+    ///         shapefile::Shape::Multipatch(shp) => println!("Multipoint!"),
+    ///         _ => println!("Other type of shape"),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// ```
+    /// # fn main() -> Result<(), shapefile::Error> {
+    /// let mut reader = shapefile::ShapeReader::from_path("tests/data/multipoint.shp")?;
+    /// for shape in reader.iter_shapes() {
+    ///     match shape? {
+    ///         shapefile::Shape::Multipatch(shp) => println!("Multipoint!"),
+    ///         _ => println!("Other type of shape"),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn iter_shapes(&mut self) -> ShapeIterator<'_, T, Shape> {
+        self.iter_shapes_as::<Shape>()
+    }
+
+    /// Returns an iterator that reads the shapes and converts them
+    /// to [geo_types::Geometry]
+    ///
+    /// Null shapes are skipped, shapes that cannot be converted
+    /// yield an [Error::GeometryConversion].
+    ///
+    /// Requires the `geo-types` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "geo-types")]
+    /// # fn main() -> Result<(), shapefile::Error> {
+    /// let mut reader = shapefile::ShapeReader::from_path("tests/data/line.shp")?;
+    /// for geometry in reader.iter_geometries() {
+    ///     let geometry = geometry?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// # #[cfg(not(feature = "geo-types"))]
+    /// # fn main() {}
+    /// ```
+    #[cfg(feature = "geo-types")]
+    pub fn iter_geometries(
+        &mut self,
+    ) -> impl Iterator<Item = Result<geo_types::Geometry<f64>, Error>> + '_ {
+        self.iter_shapes().filter_map(shape_to_geometry)
+    }
+
+    /// Reads the raw bytes of the `n`th record, (0 based index)
+    ///
+    /// The bytes are the content of the record as stored in the _.shp_,
+    /// that is, the shape type followed by the shape, but not the record header.
+    /// The number of bytes read is the record size given by the _.shx_.
+    ///
+    /// # Returns
+    ///
+    /// `None` if the index is out of range
+    ///
+    /// # Errors
+    ///
+    /// `Error::MissingIndexFile` if no *.shx* was found when opening the shapefile.
+    ///
+    /// `Error::InvalidShapeRecordSize` if the record size of the *.shx* entry is negative.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), shapefile::Error> {
+    /// let mut reader = shapefile::ShapeReader::from_path("tests/data/point.shp")?;
+    /// let bytes = reader.read_nth_record_bytes(0).unwrap()?;
+    /// // shape type (1 for Point), then x and y
+    /// assert_eq!(bytes.len(), 4 + 2 * 8);
+    /// assert_eq!(&bytes[..4], &[1, 0, 0, 0]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_nth_record_bytes(&mut self, index: usize) -> Option<Result<Vec<u8>, Error>> {
+        let record_size = match self.shapes_index {
+            Some(ref shapes_index) => shapes_index.get(index)?.record_size,
+            None => return Some(Err(Error::MissingIndexFile)),
+        };
+
+        if record_size < 0 {
+            return Some(Err(Error::InvalidShapeRecordSize));
+        }
+
+        let result = self.seek(index).and_then(|_| {
+            check_record_size(record_size, self.max_record_size)?;
+            record::RecordHeader::read_from(&mut self.source)?;
+            let mut bytes = vec![0u8; record_size as usize * 2];
+            self.source.read_exact(&mut bytes)?;
+            self.source
+                .seek(SeekFrom::Start(header::HEADER_SIZE as u64))?;
+            Ok(bytes)
+        });
+        Some(result)
+    }
+
+    /// Reads the `n`th shape of the shapefile
+    ///
+    /// # Important
+    ///
+    /// Even though in shapefiles, shapes are indexed starting from '1'.
+    /// this method expects indexes starting from 0.
+    /// See [ShapeReader::read_shape_number] to use the record numbers of the shapefile.
+    ///
+    /// # Returns
+    ///
+    /// `None` if the index is out of range
     ///
     /// # Errors
     ///
@@ -415,7 +1437,11 @@ impl<T: Read + Seek> ShapeReader<T> {
                 return Some(Err(e));
             }
 
-            let (_, shape) = match read_one_shape_as::<T, S>(&mut self.source) {
+            let (_, shape) = match read_one_shape_as::<T, S>(
+                &mut self.source,
+                self.max_record_size,
+                self.options,
+            ) {
                 Err(e) => return Some(Err(e)),
                 Ok(hdr_and_shape) => hdr_and_shape,
             };
@@ -437,6 +1463,72 @@ impl<T: Read + Seek> ShapeReader<T> {
         self.read_nth_shape_as::<Shape>(index)
     }
 
+    /// Reads the shape with the given record number
+    ///
+    /// Record numbers start at 1, as in the shapefile specification,
+    /// this is the same as `read_nth_shape(record_number - 1)`.
+    ///
+    /// # Returns
+    ///
+    /// `None` if the record number is 0 or out of range
+    ///
+    /// # Errors
+    ///
+    /// This method will return an `Error::MissingIndexFile` if you use it
+    /// but no *.shx* was found when opening the shapefile.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), shapefile::Error> {
+    /// let mut reader = shapefile::ShapeReader::from_path("tests/data/line.shp")?;
+    /// assert!(reader.read_shape_number(1).is_some());
+    /// assert!(reader.read_shape_number(0).is_none());
+    /// assert!(reader.read_shape_number(2).is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_shape_number(&mut self, record_number: usize) -> Option<Result<Shape, Error>> {
+        let index = record_number.checked_sub(1)?;
+        self.read_nth_shape(index)
+    }
+
+    /// Reads the shapes whose indices are in the `range` (`[start, end)`)
+    ///
+    /// The end of the range is clamped to the number of shapes in the file.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an `Error::MissingIndexFile` if you use it
+    /// but no *.shx* was found when opening the shapefile.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), shapefile::Error> {
+    /// let mut reader = shapefile::ShapeReader::from_path("tests/data/line.shp")?;
+    /// let polylines = reader.read_range_as::<shapefile::Polyline>(0..10)?;
+    /// assert_eq!(polylines.len(), 1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_range_as<S: ReadableShape>(
+        &mut self,
+        range: std::ops::Range<usize>,
+    ) -> Result<Vec<S>, Error> {
+        let end = range.end.min(self.shape_count()?);
+        let mut shapes = Vec::with_capacity(end.saturating_sub(range.start));
+        for index in range.start..end {
+            self.seek(index)?;
+            let (_, shape) =
+                read_one_shape_as::<T, S>(&mut self.source, self.max_record_size, self.options)?;
+            shapes.push(shape);
+        }
+        self.source
+            .seek(SeekFrom::Start(header::HEADER_SIZE as u64))?;
+        Ok(shapes)
+    }
+
     /// Seek to the start of the shape at `index`
     ///
     /// # Error
@@ -460,13 +1552,27 @@ impl<T: Read + Seek> ShapeReader<T> {
         }
     }
 
+    /// Returns true if the file has no shapes, that is,
+    /// if it is only made of its header
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let reader = shapefile::ShapeReader::from_path("tests/data/point.shp").unwrap();
+    /// assert!(!reader.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        i64::from(self.header.file_length) * 2 <= i64::from(header::HEADER_SIZE)
+    }
+
     /// Returns the number of shapes in the shapefile
     ///
     /// # Error
     ///
     /// Returns [Error::MissingIndexFile] if the _shx_ file
     /// was not found by [ShapeReader::from_path] or the reader
-    /// was not constructed with [ShapeReader::with_shx]
+    /// was not constructed with [ShapeReader::with_shx],
+    /// unless the file is empty (see [ShapeReader::is_empty])
     ///
     /// # Example
     ///
@@ -482,25 +1588,247 @@ impl<T: Read + Seek> ShapeReader<T> {
     pub fn shape_count(&self) -> Result<usize, Error> {
         if let Some(ref shapes_index) = self.shapes_index {
             Ok(shapes_index.len())
+        } else if self.is_empty() {
+            Ok(0)
         } else {
             Err(Error::MissingIndexFile)
         }
     }
+
+    /// Estimates the number of bytes of memory that reading all the shapes
+    /// with [ShapeReader::read] would use
+    ///
+    /// The records are not read, the estimate is computed from the shape type
+    /// and the record sizes: all the bytes of a record that may hold points
+    /// are counted as points (16, 24 or 32 bytes each in memory depending on the shape type),
+    /// and each shape is counted with one part.
+    ///
+    /// When the _.shx_ is available, the size of each record is known.
+    /// Otherwise, the file is considered to hold as many records as it could,
+    /// which gives a conservative upper bound.
+    ///
+    /// # Errors
+    ///
+    /// Returns [Error::InvalidShapeRecordSize] if an entry of the _.shx_ has a negative size.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), shapefile::Error> {
+    /// let reader = shapefile::ShapeReader::from_path("tests/data/polygonz.shp")?;
+    /// let estimate = reader.estimate_memory()?;
+    /// assert!(estimate > 0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn estimate_memory(&self) -> Result<usize, Error> {
+        use std::mem::size_of;
+        use ShapeType::*;
+
+        let f64_size = size_of::<f64>();
+        let i32_size = size_of::<i32>();
+        // Shape type and bbox, followed by the number of points for multipoints,
+        // or by the number of parts, number of points and one part index for multi part shapes
+        let multipoint_size = i32_size + 4 * f64_size + i32_size;
+        let multi_part_size = i32_size + 4 * f64_size + 3 * i32_size;
+        // z or m range
+        let range_size = 2 * f64_size;
+
+        // Bytes of a record that are not points (shape type, bbox, ranges, counts, one part)
+        // the bytes per point in the file, the bytes per point in memory
+        // and the heap memory a part uses besides its points
+        let (fixed_size, file_point_size, point_size, part_size) = match self.header.shape_type {
+            // These shapes are stored inline in the Shape
+            NullShape | Point | PointM | PointZ => (i32_size, 1, 0, 0),
+            Multipoint => (multipoint_size, 2 * f64_size, size_of::<record::Point>(), 0),
+            MultipointM => (
+                multipoint_size + range_size,
+                3 * f64_size,
+                size_of::<record::PointM>(),
+                0,
+            ),
+            // The m values are optional
+            MultipointZ => (
+                multipoint_size + range_size,
+                3 * f64_size,
+                size_of::<record::PointZ>(),
+                0,
+            ),
+            Polyline => (
+                multi_part_size,
+                2 * f64_size,
+                size_of::<record::Point>(),
+                size_of::<Vec<record::Point>>(),
+            ),
+            PolylineM => (
+                multi_part_size + range_size,
+                3 * f64_size,
+                size_of::<record::PointM>(),
+                size_of::<Vec<record::PointM>>(),
+            ),
+            PolylineZ => (
+                multi_part_size + range_size,
+                3 * f64_size,
+                size_of::<record::PointZ>(),
+                size_of::<Vec<record::PointZ>>(),
+            ),
+            Polygon => (
+                multi_part_size,
+                2 * f64_size,
+                size_of::<record::Point>(),
+                size_of::<record::PolygonRing<record::Point>>(),
+            ),
+            PolygonM => (
+                multi_part_size + range_size,
+                3 * f64_size,
+                size_of::<record::PointM>(),
+                size_of::<record::PolygonRing<record::PointM>>(),
+            ),
+            PolygonZ => (
+                multi_part_size + range_size,
+                3 * f64_size,
+                size_of::<record::PointZ>(),
+                size_of::<record::PolygonRing<record::PointZ>>(),
+            ),
+            // The patch type follows the part index
+            Multipatch => (
+                multi_part_size + i32_size + range_size,
+                3 * f64_size,
+                size_of::<record::PointZ>(),
+                size_of::<record::Patch>(),
+            ),
+        };
+        let record_estimate = |content_size: usize| {
+            let num_points = content_size.saturating_sub(fixed_size) / file_point_size;
+            size_of::<Shape>() + part_size + num_points * point_size
+        };
+
+        match self.shapes_index {
+            Some(ref shapes_index) => shapes_index.iter().try_fold(0usize, |total, index| {
+                if index.record_size < 0 {
+                    return Err(Error::InvalidShapeRecordSize);
+                }
+                Ok(total + record_estimate(index.record_size as usize * 2))
+            }),
+            None => {
+                let file_length = i64::from(self.header.file_length) * 2;
+                let content_length = (file_length - i64::from(header::HEADER_SIZE)).max(0) as usize;
+                // Smallest possible records are null shapes
+                let max_num_records =
+                    content_length / (record::RecordHeader::SIZE + size_of::<i32>());
+                // All points have to fit in the file, regardless of the number of records
+                let num_points = content_length / file_point_size;
+                Ok(max_num_records * (size_of::<Shape>() + part_size) + num_points * point_size)
+            }
+        }
+    }
+}
+
+/// Iterates over the shapes while owning the reader
+///
+/// # Example
+///
+/// ```
+/// # fn main() -> Result<(), shapefile::Error> {
+/// fn shapes_of(path: &str) -> Result<impl Iterator<Item = Result<shapefile::Shape, shapefile::Error>>, shapefile::Error> {
+///     let reader = shapefile::ShapeReader::from_path(path)?;
+///     Ok(reader.into_iter())
+/// }
+///
+/// for shape in shapes_of("tests/data/multipoint.shp")? {
+///     println!("{}", shape?);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+impl<T: Read + Seek> IntoIterator for ShapeReader<T> {
+    type Item = Result<Shape, Error>;
+    type IntoIter = ShapeIntoIterator<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        ShapeIntoIterator {
+            current_pos: header::HEADER_SIZE as usize,
+            file_length: (self.header.file_length as usize) * 2,
+            next_index: 0,
+            reader: self,
+        }
+    }
 }
 
 impl ShapeReader<BufReader<File>> {
     pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        Self::from_path_with_capacity(path, DEFAULT_BUF_SIZE)
+    }
+
+    /// Same as [ShapeReader::from_path], but the buffers used to read
+    /// the _.shp_ and _.shx_ files have the given `capacity` (in bytes)
+    /// instead of the default 8 KiB
+    ///
+    /// A bigger buffer reduces the number of system calls when reading large files.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), shapefile::Error> {
+    /// let reader = shapefile::ShapeReader::from_path_with_capacity("tests/data/line.shp", 1 << 20)?;
+    /// let shapes = reader.read()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_path_with_capacity<P: AsRef<Path>>(
+        path: P,
+        capacity: usize,
+    ) -> Result<Self, Error> {
         let shape_path = path.as_ref().to_path_buf();
         let shx_path = shape_path.with_extension("shx");
 
-        let source = BufReader::new(File::open(shape_path)?);
+        let source = BufReader::with_capacity(capacity, File::open(shape_path)?);
+
+        if shx_path.exists() {
+            let index_source = BufReader::with_capacity(capacity, File::open(shx_path)?);
+            Self::with_shx(source, index_source)
+        } else {
+            Self::new(source)
+        }
+    }
+}
+
+impl<'a> ShapeReader<Cursor<&'a [u8]>> {
+    /// Creates a ShapeReader that reads the _.shp_ content from a slice of bytes
+    ///
+    /// `Cursor<&[u8]>` implements `Read + Seek`, so the data is read
+    /// directly from the slice, without any copy or extra buffering.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), shapefile::Error> {
+    /// let shp_bytes = std::fs::read("tests/data/line.shp")?;
+    /// let reader = shapefile::ShapeReader::from_bytes(&shp_bytes)?;
+    /// let shapes = reader.read()?;
+    /// assert_eq!(shapes.len(), 1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_bytes(shp: &'a [u8]) -> Result<Self, Error> {
+        Self::new(Cursor::new(shp))
+    }
 
-        if shx_path.exists() {
-            let index_source = BufReader::new(File::open(shx_path)?);
-            Self::with_shx(source, index_source)
-        } else {
-            Self::new(source)
-        }
+    /// Creates a ShapeReader that reads the _.shp_ and _.shx_ content from slices of bytes
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), shapefile::Error> {
+    /// let shp_bytes = std::fs::read("tests/data/line.shp")?;
+    /// let shx_bytes = std::fs::read("tests/data/line.shx")?;
+    /// let reader = shapefile::ShapeReader::with_shx_bytes(&shp_bytes, &shx_bytes)?;
+    /// assert_eq!(reader.shape_count()?, 1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_shx_bytes(shp: &'a [u8], shx: &[u8]) -> Result<Self, Error> {
+        Self::with_shx(Cursor::new(shp), shx)
     }
 }
 
@@ -534,6 +1862,51 @@ impl<T: Read + Seek, D: Read + Seek> Reader<T, D> {
         self.shape_reader.header()
     }
 
+    /// Returns the fields of the .dbf file
+    ///
+    /// Unlike [Reader::into_table_info], this does not consume the reader.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), shapefile::Error> {
+    /// let reader = shapefile::Reader::from_path("tests/data/multipatch.shp")?;
+    /// for field in reader.fields() {
+    ///     println!("{}", field.name());
+    /// }
+    /// assert_eq!(reader.fields()[0].name(), "name");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn fields(&self) -> &[dbase::FieldInfo] {
+        self.dbase_reader.fields()
+    }
+
+    /// Returns an iterator that only reads the records of the _.dbf_ as `R`,
+    /// the shapes are not read
+    ///
+    /// Records flagged as deleted in the _.dbf_ are skipped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), shapefile::Error> {
+    /// let mut reader = shapefile::Reader::from_path("tests/data/multipatch.shp")?;
+    /// for record in reader.iter_records_as::<dbase::Record>() {
+    ///     let record = record?;
+    ///     assert!(record.get("name").is_some());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn iter_records_as<'a, R: dbase::ReadableRecord + 'a>(
+        &'a mut self,
+    ) -> impl Iterator<Item = Result<R, Error>> + 'a {
+        self.dbase_reader
+            .iter_records_as::<R>()
+            .map(|record| record.map_err(Error::DbaseError))
+    }
+
     pub fn iter_shapes_and_records_as<S: ReadableShape, R: dbase::ReadableRecord>(
         &mut self,
     ) -> ShapeRecordIterator<'_, T, D, S, R> {
@@ -543,6 +1916,47 @@ impl<T: Read + Seek, D: Read + Seek> Reader<T, D> {
         }
     }
 
+    /// Returns an iterator that returns the record number
+    /// (as written in the record header of the _.shp_) along with the shape and the record
+    ///
+    /// Useful to join the shapes with external data keyed by record number.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), shapefile::Error> {
+    /// let mut reader = shapefile::Reader::from_path("tests/data/multipatch.shp")?;
+    /// for shape_record in
+    ///     reader.iter_shapes_and_records_with_number::<shapefile::Multipatch, dbase::Record>()
+    /// {
+    ///     let (record_number, shape, record) = shape_record?;
+    ///     assert_eq!(record_number, 1);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn iter_shapes_and_records_with_number<
+        'a,
+        S: ReadableShape + 'a,
+        R: dbase::ReadableRecord + 'a,
+    >(
+        &'a mut self,
+    ) -> impl Iterator<Item = Result<(i32, S, R), Error>> + 'a {
+        let mut shape_iter = self.shape_reader.iter_shapes_as::<S>();
+        let mut record_iter = self.dbase_reader.iter_records_as::<R>();
+        std::iter::from_fn(move || {
+            let (hdr, shape) = match shape_iter.next_with_header()? {
+                Err(e) => return Some(Err(e)),
+                Ok(hdr_and_shape) => hdr_and_shape,
+            };
+            let record = match record_iter.next()? {
+                Err(e) => return Some(Err(Error::DbaseError(e))),
+                Ok(rcd) => rcd,
+            };
+            Some(Ok((hdr.record_number, shape, record)))
+        })
+    }
+
     /// Returns an iterator that returns both the shape and the record
     ///
     /// # Example
@@ -563,12 +1977,194 @@ impl<T: Read + Seek, D: Read + Seek> Reader<T, D> {
         self.iter_shapes_and_records_as::<Shape, dbase::Record>()
     }
 
+    /// Returns an iterator that returns the shapes and records
+    /// in chunks of `chunk_size` pairs
+    ///
+    /// The last chunk may have less than `chunk_size` pairs.
+    /// If reading one of the pairs fails, the error is returned
+    /// instead of the chunk being filled, the pairs of the chunk that were
+    /// read before are discarded.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), shapefile::Error> {
+    /// let mut reader = shapefile::Reader::from_path("tests/data/multipatch.shp")?;
+    /// for chunk in reader.iter_shapes_and_records_chunked(100) {
+    ///     let shapes_and_records = chunk?;
+    ///     assert_eq!(shapes_and_records.len(), 1);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn iter_shapes_and_records_chunked(
+        &mut self,
+        chunk_size: usize,
+    ) -> impl Iterator<Item = Result<Vec<(Shape, dbase::Record)>, Error>> + '_ {
+        assert!(chunk_size != 0, "chunk size must be non-zero");
+        let mut iter = self.iter_shapes_and_records();
+        std::iter::from_fn(move || {
+            let mut chunk = Vec::with_capacity(chunk_size);
+            for shape_record in iter.by_ref().take(chunk_size) {
+                match shape_record {
+                    Ok(shape_record) => chunk.push(shape_record),
+                    Err(e) => return Some(Err(e)),
+                }
+            }
+            if chunk.is_empty() {
+                None
+            } else {
+                Some(Ok(chunk))
+            }
+        })
+    }
+
+    /// Returns an iterator that reads the shapes (only) and converts them
+    /// to [geo_types::Geometry]
+    ///
+    /// See [ShapeReader::iter_geometries]
+    #[cfg(feature = "geo-types")]
+    pub fn iter_geometries(
+        &mut self,
+    ) -> impl Iterator<Item = Result<geo_types::Geometry<f64>, Error>> + '_ {
+        self.shape_reader.iter_geometries()
+    }
+
+    /// Reads all the shapes as `S` and records as `R`
+    ///
+    /// `R` can be [dbase::Record] or any type implementing [dbase::ReadableRecord],
+    /// see [Reader::read_typed] for an example.
     pub fn read_as<S: ReadableShape, R: dbase::ReadableRecord>(
         &mut self,
     ) -> Result<Vec<(S, R)>, Error> {
         self.iter_shapes_and_records_as::<S, R>().collect()
     }
 
+    /// Reads all the shapes as `S` and records as `R`, and returns
+    /// them in two separate vectors
+    pub fn read_unzipped_as<S: ReadableShape, R: dbase::ReadableRecord>(
+        &mut self,
+    ) -> Result<(Vec<S>, Vec<R>), Error> {
+        let capacity = self.shape_count().unwrap_or(0);
+        let mut shapes = Vec::with_capacity(capacity);
+        let mut records = Vec::with_capacity(capacity);
+        for shape_record in self.iter_shapes_and_records_as::<S, R>() {
+            let (shape, record) = shape_record?;
+            shapes.push(shape);
+            records.push(record);
+        }
+        Ok((shapes, records))
+    }
+
+    /// Reads all the shapes and records, and returns them in two separate vectors
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), shapefile::Error> {
+    /// let mut reader = shapefile::Reader::from_path("tests/data/multipatch.shp")?;
+    /// let (shapes, records) = reader.read_unzipped()?;
+    /// assert_eq!(shapes.len(), records.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_unzipped(&mut self) -> Result<(Vec<Shape>, Vec<dbase::Record>), Error> {
+        self.read_unzipped_as::<Shape, dbase::Record>()
+    }
+
+    /// Returns an iterator over the shapes and records for which
+    /// `pred(&shape)` returns true
+    ///
+    /// Shapes and records are still all read to keep the .shp and the .dbf in lockstep,
+    /// so this does not skip any IO, but only the pairs kept are yielded.
+    ///
+    /// Errors are always yielded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), shapefile::Error> {
+    /// let mut reader = shapefile::Reader::from_path("tests/data/multipatch.shp")?;
+    /// let kept = reader
+    ///     .filter_shapes_and_records::<shapefile::Multipatch, shapefile::dbase::Record, _>(|patch| {
+    ///         patch.bbox().max.z > 0.0
+    ///     })
+    ///     .collect::<Result<Vec<_>, _>>()?;
+    /// assert_eq!(kept.len(), 1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn filter_shapes_and_records<'a, S, R, F>(
+        &'a mut self,
+        mut pred: F,
+    ) -> impl Iterator<Item = Result<(S, R), Error>> + 'a
+    where
+        S: ReadableShape + 'a,
+        R: dbase::ReadableRecord + 'a,
+        F: FnMut(&S) -> bool + 'a,
+    {
+        self.iter_shapes_and_records_as::<S, R>()
+            .filter(move |result| match result {
+                Ok((shape, _)) => pred(shape),
+                Err(_) => true,
+            })
+    }
+
+    /// Reads all the shapes as `S` and records as the user defined type `R`
+    ///
+    /// This is the same as [Reader::read_as], records can be read as a user defined
+    /// struct by implementing [dbase::ReadableRecord], for example with
+    /// the [dbase::dbase_record] macro.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), shapefile::Error> {
+    /// use shapefile::dbase;
+    /// use std::convert::TryInto;
+    ///
+    /// dbase::dbase_record!(
+    ///     #[derive(Debug, Clone, PartialEq)]
+    ///     struct City {
+    ///         name: String,
+    ///         population: f64,
+    ///     }
+    /// );
+    ///
+    /// let cities = vec![
+    ///     City { name: "Paris".to_string(), population: 2_161_000.0 },
+    ///     City { name: "Lyon".to_string(), population: 513_275.0 },
+    /// ];
+    ///
+    /// let table_builder = dbase::TableWriterBuilder::new()
+    ///     .add_character_field("name".try_into().unwrap(), 50)
+    ///     .add_numeric_field("population".try_into().unwrap(), 12, 0);
+    /// let mut writer = shapefile::Writer::from_path("typed_cities.shp", table_builder)?;
+    /// writer.write_shape_and_record(&shapefile::Point::new(2.35, 48.85), &cities[0])?;
+    /// writer.write_shape_and_record(&shapefile::Point::new(4.83, 45.76), &cities[1])?;
+    /// drop(writer);
+    ///
+    /// let mut reader = shapefile::Reader::from_path("typed_cities.shp")?;
+    /// let points_and_cities = reader.read_typed::<shapefile::Point, City>()?;
+    /// assert_eq!(points_and_cities.len(), 2);
+    /// assert_eq!(points_and_cities[1].0, shapefile::Point::new(4.83, 45.76));
+    /// assert_eq!(points_and_cities[1].1, cities[1]);
+    /// # std::fs::remove_file("typed_cities.shp")?;
+    /// # std::fs::remove_file("typed_cities.shx")?;
+    /// # std::fs::remove_file("typed_cities.dbf")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_typed<S: ReadableShape, R: dbase::ReadableRecord>(
+        &mut self,
+    ) -> Result<Vec<(S, R)>, Error> {
+        self.read_as::<S, R>()
+    }
+
     /// Read all the shape and record and returns them in a [Vec]
     ///
     /// # Example
@@ -598,6 +2194,35 @@ impl<T: Read + Seek, D: Read + Seek> Reader<T, D> {
         self.shape_reader.shape_count()
     }
 
+    /// Checks that the number of shapes in the _.shp_ is the same as the
+    /// number of records in the _.dbf_
+    ///
+    /// Requires the _.shx_ to know the number of shapes, returns
+    /// [Error::MissingIndexFile] otherwise.
+    ///
+    /// When the counts differ, [Error::RecordCountMismatch] is returned,
+    /// as iterating over shapes and records would silently stop at the shortest.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), shapefile::Error> {
+    /// let reader = shapefile::Reader::from_path("tests/data/multipatch.shp")?;
+    /// // There is no multipatch.shx
+    /// assert!(reader.verify_record_counts().is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn verify_record_counts(&self) -> Result<(), Error> {
+        let shp = self.shape_count()?;
+        let dbf = self.dbase_reader.header().num_records as usize;
+        if shp == dbf {
+            Ok(())
+        } else {
+            Err(Error::RecordCountMismatch { shp, dbf })
+        }
+    }
+
     /// Consumes the self and returns the dbase table info
     /// which can be given to [TableWriterBuild](dbase::TableWriterBuilder) or
     /// [crate::Writer::from_path_with_info] to create a shapefile where the .dbf file has the
@@ -640,13 +2265,57 @@ impl Reader<BufReader<File>, BufReader<File>> {
     /// # }
     /// ```
     pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        Self::from_path_with_dbase_config(path, |_| {})
+    }
+
+    /// Creates a reader from a path the .shp file,
+    /// reading the `.dbf` with the given `options` and `encoding`
+    ///
+    /// The `encoding` replaces the one given by the code page of the `.dbf`
+    /// (the reading options of dbase do not include the encoding).
+    /// Besides that, this behaves like [Reader::from_path].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), shapefile::Error> {
+    /// use shapefile::dbase::{ReadingOptions, TrimOption, UnicodeLossy};
+    /// let options = ReadingOptions::default().character_trim(TrimOption::End);
+    /// let mut reader = shapefile::Reader::from_path_with_dbase_options(
+    ///     "tests/data/multipatch.shp",
+    ///     options,
+    ///     UnicodeLossy,
+    /// )?;
+    /// assert_eq!(reader.read()?.len(), 1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_path_with_dbase_options<P: AsRef<Path>, E: dbase::Encoding + 'static>(
+        path: P,
+        options: dbase::ReadingOptions,
+        encoding: E,
+    ) -> Result<Self, Error> {
+        Self::from_path_with_dbase_config(path, |dbf_reader| {
+            dbf_reader.set_options(options);
+            dbf_reader.set_encoding(encoding);
+        })
+    }
+
+    /// Opens the files like [Reader::from_path] does,
+    /// `configure` is called on the dbase reader before any record is read
+    fn from_path_with_dbase_config<P, F>(path: P, configure: F) -> Result<Self, Error>
+    where
+        P: AsRef<Path>,
+        F: FnOnce(&mut dbase::Reader<BufReader<File>>),
+    {
         let shape_path = path.as_ref().to_path_buf();
         let dbf_path = shape_path.with_extension("dbf");
 
         if dbf_path.exists() {
             let shape_reader = ShapeReader::from_path(path)?;
             let dbf_source = BufReader::new(File::open(dbf_path)?);
-            let dbf_reader = dbase::Reader::new(dbf_source)?;
+            let mut dbf_reader = dbase::Reader::new(dbf_source)?;
+            configure(&mut dbf_reader);
             Ok(Self {
                 shape_reader,
                 dbase_reader: dbf_reader,
@@ -655,6 +2324,197 @@ impl Reader<BufReader<File>, BufReader<File>> {
             Err(Error::MissingDbf)
         }
     }
+
+    /// Reads only the records of the `.dbf` associated to the path
+    ///
+    /// Neither the `.shp` nor the `.shx` are opened, so they do not have to exist.
+    ///
+    /// If the `.dbf` is not found [Error::MissingDbf] will be return as the error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), shapefile::Error> {
+    /// let records = shapefile::Reader::read_records_only("tests/data/multipatch.shp")?;
+    /// assert_eq!(records.len(), 1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_records_only<P: AsRef<Path>>(path: P) -> Result<Vec<dbase::Record>, Error> {
+        let dbf_path = path.as_ref().with_extension("dbf");
+        if dbf_path.exists() {
+            let dbf_source = BufReader::new(File::open(dbf_path)?);
+            let mut dbf_reader = dbase::Reader::new(dbf_source)?;
+            Ok(dbf_reader.read()?)
+        } else {
+            Err(Error::MissingDbf)
+        }
+    }
+}
+
+#[cfg(feature = "zip")]
+impl Reader<Cursor<Vec<u8>>, Cursor<Vec<u8>>> {
+    /// Creates a reader for a shapefile stored inside a zip archive
+    ///
+    /// `member` is the path of the _.shp_ inside the archive,
+    /// the _.shx_ and _.dbf_ are looked up next to it.
+    /// The members are decompressed in memory.
+    ///
+    /// Like [Reader::from_path], a missing _.shx_ is not an error,
+    /// but a missing _.dbf_ returns [Error::MissingDbf].
+    ///
+    /// Requires the `zip` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "zip")]
+    /// # fn main() -> Result<(), shapefile::Error> {
+    /// let mut reader = shapefile::Reader::from_zip("tests/data/bundle.zip", "point.shp")?;
+    /// let shapes_and_records = reader.read()?;
+    /// assert_eq!(shapes_and_records.len(), 1);
+    /// # Ok(())
+    /// # }
+    /// # #[cfg(not(feature = "zip"))]
+    /// # fn main() {}
+    /// ```
+    pub fn from_zip<P: AsRef<Path>>(archive_path: P, member: &str) -> Result<Self, Error> {
+        let source = BufReader::new(File::open(archive_path)?);
+        let mut archive = zip::ZipArchive::new(source).map_err(std::io::Error::from)?;
+
+        let shape_path = Path::new(member);
+        let shp = read_zip_member(&mut archive, shape_path)?.ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                format!("'{}' not found in the archive", member),
+            )
+        })?;
+        let shx = read_zip_member(&mut archive, &shape_path.with_extension("shx"))?;
+        let dbf = read_zip_member(&mut archive, &shape_path.with_extension("dbf"))?;
+        Self::from_buffers(shp, shx, dbf)
+    }
+
+    /// Opens a shapefile from a path specification
+    ///
+    /// The specification is either a plain path to a _.shp_,
+    /// or a path that goes through a zip archive, in which case
+    /// the part after the archive is the path of the _.shp_ inside of it:
+    ///
+    /// - `data/roads.shp`
+    /// - `data/archive.zip/roads.shp`
+    /// - `data/archive.zip/some/dir/roads.shp`
+    ///
+    /// GDAL's `/vsizip/` prefix is also accepted (`/vsizip/data/archive.zip/roads.shp`).
+    ///
+    /// Unlike [Reader::from_path], the files are loaded in memory
+    /// even when they are not in an archive.
+    ///
+    /// Requires the `zip` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "zip")]
+    /// # fn main() -> Result<(), shapefile::Error> {
+    /// let mut reader = shapefile::Reader::open("tests/data/bundle.zip/point.shp")?;
+    /// assert_eq!(reader.read()?.len(), 1);
+    ///
+    /// let mut reader = shapefile::Reader::open("tests/data/multipatch.shp")?;
+    /// assert_eq!(reader.read()?.len(), 1);
+    /// # Ok(())
+    /// # }
+    /// # #[cfg(not(feature = "zip"))]
+    /// # fn main() {}
+    /// ```
+    pub fn open(spec: &str) -> Result<Self, Error> {
+        let spec = spec.strip_prefix("/vsizip/").unwrap_or(spec);
+        if let Some((archive_path, member)) = split_zip_spec(Path::new(spec)) {
+            return Self::from_zip(archive_path, &member);
+        }
+
+        let shape_path = Path::new(spec);
+        let shp = std::fs::read(shape_path)?;
+        let shx_path = shape_path.with_extension("shx");
+        let shx = if shx_path.exists() {
+            Some(std::fs::read(shx_path)?)
+        } else {
+            None
+        };
+        let dbf_path = shape_path.with_extension("dbf");
+        let dbf = if dbf_path.exists() {
+            Some(std::fs::read(dbf_path)?)
+        } else {
+            None
+        };
+        Self::from_buffers(shp, shx, dbf)
+    }
+
+    fn from_buffers(
+        shp: Vec<u8>,
+        shx: Option<Vec<u8>>,
+        dbf: Option<Vec<u8>>,
+    ) -> Result<Self, Error> {
+        let dbf = dbf.ok_or(Error::MissingDbf)?;
+        let shape_reader = match shx {
+            Some(shx) => ShapeReader::with_shx(Cursor::new(shp), Cursor::new(shx))?,
+            None => ShapeReader::new(Cursor::new(shp))?,
+        };
+        let dbase_reader = dbase::Reader::new(Cursor::new(dbf))?;
+        Ok(Self {
+            shape_reader,
+            dbase_reader,
+        })
+    }
+}
+
+/// Reads the whole content of a member of the archive,
+/// returns `None` if there is no such member
+#[cfg(feature = "zip")]
+fn read_zip_member<R: Read + Seek>(
+    archive: &mut zip::ZipArchive<R>,
+    member: &Path,
+) -> Result<Option<Vec<u8>>, Error> {
+    let name = member
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/");
+    match archive.by_name(&name) {
+        Ok(mut file) => {
+            // The size comes from the archive, which may be corrupted,
+            // so it is only trusted up to the default max record size
+            let capacity = file.size().min(DEFAULT_MAX_RECORD_SIZE as u64) as usize;
+            let mut content = Vec::with_capacity(capacity);
+            file.read_to_end(&mut content)?;
+            Ok(Some(content))
+        }
+        Err(zip::result::ZipError::FileNotFound) => Ok(None),
+        Err(err) => Err(std::io::Error::from(err).into()),
+    }
+}
+
+/// Splits `path/to/archive.zip/path/in/archive.shp` into the path
+/// of the archive and the path of the member,
+/// returns `None` if the path does not go through a zip file
+#[cfg(feature = "zip")]
+fn split_zip_spec(spec: &Path) -> Option<(std::path::PathBuf, String)> {
+    let mut archive_path = std::path::PathBuf::new();
+    let mut components = spec.components();
+    while let Some(component) = components.next() {
+        archive_path.push(component);
+        let is_zip = component
+            .as_os_str()
+            .to_str()
+            .is_some_and(|name| name.to_ascii_lowercase().ends_with(".zip"));
+        if is_zip && archive_path.is_file() {
+            let member = components
+                .map(|component| component.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+            return (!member.is_empty()).then_some((archive_path, member));
+        }
+    }
+    None
 }
 
 pub fn read<T: AsRef<Path>>(path: T) -> Result<Vec<(Shape, dbase::Record)>, Error> {
@@ -710,5 +2570,51 @@ pub fn read_shapes<T: AsRef<Path>>(path: T) -> Result<Vec<Shape>, Error> {
     read_shapes_as::<T, Shape>(path)
 }
 
+/// Function to read all the Shapes in a file as a `geo_types::GeometryCollection`
+///
+/// `NullShape` records are skipped, as they have no equivalent geo_types Geometry.
+///
+/// # Errors
+///
+/// Returns `Error::GeometryConversion` if a shape could not be converted.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "geo-types")]
+/// # fn main() -> Result<(), shapefile::Error> {
+/// let collection = shapefile::read_as_geometry_collection("tests/data/polygon.shp")?;
+/// assert_eq!(collection.len(), 1);
+/// # Ok(())
+/// # }
+/// # #[cfg(not(feature = "geo-types"))]
+/// # fn main() {}
+/// ```
+#[cfg(feature = "geo-types")]
+pub fn read_as_geometry_collection<T: AsRef<Path>>(
+    path: T,
+) -> Result<geo_types::GeometryCollection<f64>, Error> {
+    let geometries = ShapeReader::from_path(path)?
+        .into_iter()
+        .filter_map(shape_to_geometry)
+        .collect::<Result<Vec<_>, Error>>()?;
+    Ok(geo_types::GeometryCollection(geometries))
+}
+
+/// Converts a read shape to a geometry, returns `None` for null shapes
+#[cfg(feature = "geo-types")]
+fn shape_to_geometry(
+    shape: Result<Shape, Error>,
+) -> Option<Result<geo_types::Geometry<f64>, Error>> {
+    use std::convert::TryFrom;
+    match shape {
+        Ok(Shape::NullShape) => None,
+        Ok(shape) => {
+            Some(geo_types::Geometry::<f64>::try_from(shape).map_err(Error::GeometryConversion))
+        }
+        Err(err) => Some(Err(err)),
+    }
+}
+
 #[cfg(test)]
 mod tests {}
@@ -1,8 +1,9 @@
 //! Bounding Boxes
 use super::traits::{GrowablePoint, HasM, HasXY, HasZ, ShrinkablePoint};
 use super::EsriShape;
-use super::PointZ;
+use super::{Point, PointM, PointZ, Shape, NO_DATA};
 use crate::writer::{f64_max, f64_min};
+use std::fmt;
 
 /// The Bounding Box type used in this crate.
 ///
@@ -67,6 +68,25 @@ impl<PointType> GenericBBox<PointType> {
         }
         bbox
     }
+
+    /// Returns the bbox of the points, or `None` if there are no points
+    pub(crate) fn from_points_iter<'a, I>(points: I) -> Option<Self>
+    where
+        PointType: Copy + ShrinkablePoint + GrowablePoint + 'a,
+        I: IntoIterator<Item = &'a PointType>,
+    {
+        let mut points = points.into_iter();
+        let first = *points.next()?;
+        let mut bbox = Self {
+            max: first,
+            min: first,
+        };
+        for point in points {
+            bbox.min.shrink(point);
+            bbox.max.grow(point);
+        }
+        Some(bbox)
+    }
 }
 
 impl<PointType: HasXY> GenericBBox<PointType> {
@@ -91,6 +111,99 @@ impl<PointType: HasM> GenericBBox<PointType> {
     }
 }
 
+impl GenericBBox<Point> {
+    /// Creates a bbox from its x and y ranges (`[min, max]`)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use shapefile::record::GenericBBox;
+    /// use shapefile::Point;
+    /// let bbox = GenericBBox::<Point>::from_ranges([0.0, 10.0], [-5.0, 5.0]);
+    /// assert_eq!(bbox.min, Point::new(0.0, -5.0));
+    /// assert_eq!(bbox.max, Point::new(10.0, 5.0));
+    /// ```
+    pub fn from_ranges(x_range: [f64; 2], y_range: [f64; 2]) -> Self {
+        Self {
+            min: Point::new(x_range[0], y_range[0]),
+            max: Point::new(x_range[1], y_range[1]),
+        }
+    }
+}
+
+impl GenericBBox<PointM> {
+    /// Creates a bbox from its x, y and m ranges (`[min, max]`)
+    pub fn from_ranges(x_range: [f64; 2], y_range: [f64; 2], m_range: [f64; 2]) -> Self {
+        Self {
+            min: PointM::new(x_range[0], y_range[0], m_range[0]),
+            max: PointM::new(x_range[1], y_range[1], m_range[1]),
+        }
+    }
+}
+
+impl GenericBBox<PointZ> {
+    /// Creates a bbox from its x, y, z and m ranges (`[min, max]`)
+    pub fn from_ranges(
+        x_range: [f64; 2],
+        y_range: [f64; 2],
+        z_range: [f64; 2],
+        m_range: [f64; 2],
+    ) -> Self {
+        Self {
+            min: PointZ::new(x_range[0], y_range[0], z_range[0], m_range[0]),
+            max: PointZ::new(x_range[1], y_range[1], z_range[1], m_range[1]),
+        }
+    }
+}
+
+/// Displays the bbox as `[xmin, ymin] - [xmax, ymax]`
+///
+/// # Example
+///
+/// ```
+/// use shapefile::{Point, Polyline};
+/// let polyline = Polyline::new(vec![Point::new(1.0, 4.0), Point::new(3.0, 2.0)]);
+/// assert_eq!(format!("{}", polyline.bbox()), "[1, 2] - [3, 4]");
+/// ```
+impl fmt::Display for GenericBBox<Point> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "[{}, {}] - [{}, {}]",
+            self.min.x, self.min.y, self.max.x, self.max.y
+        )
+    }
+}
+
+/// Displays the bbox as `[xmin, ymin, mmin] - [xmax, ymax, mmax]`
+impl fmt::Display for GenericBBox<PointM> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "[{}, {}, {}] - [{}, {}, {}]",
+            self.min.x, self.min.y, self.min.m, self.max.x, self.max.y, self.max.m
+        )
+    }
+}
+
+/// Displays the bbox as `[xmin, ymin, zmin, mmin] - [xmax, ymax, zmax, mmax]`
+impl fmt::Display for GenericBBox<PointZ> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "[{}, {}, {}, {}] - [{}, {}, {}, {}]",
+            self.min.x,
+            self.min.y,
+            self.min.z,
+            self.min.m,
+            self.max.x,
+            self.max.y,
+            self.max.z,
+            self.max.m
+        )
+    }
+}
+
 impl<PointType: Default> Default for GenericBBox<PointType> {
     fn default() -> Self {
         Self {
@@ -103,6 +216,49 @@ impl<PointType: Default> Default for GenericBBox<PointType> {
 pub type BBoxZ = GenericBBox<PointZ>;
 
 impl BBoxZ {
+    /// Returns a bbox with min set to `f64::MAX` and max set to `f64::MIN`,
+    /// meant to be grown
+    pub(crate) fn inverted() -> Self {
+        Self {
+            max: PointZ::new(f64::MIN, f64::MIN, f64::MIN, f64::MIN),
+            min: PointZ::new(f64::MAX, f64::MAX, f64::MAX, f64::MAX),
+        }
+    }
+
+    /// Sets the z and m ranges that were never grown to `[0.0, 0.0]`
+    pub(crate) fn zero_ungrown_z_and_m(&mut self) {
+        if self.max.m == f64::MIN && self.min.m == f64::MAX {
+            self.max.m = 0.0;
+            self.min.m = 0.0;
+        }
+
+        if self.max.z == f64::MIN && self.min.z == f64::MAX {
+            self.max.z = 0.0;
+            self.min.z = 0.0;
+        }
+    }
+
+    /// Grows the bbox with the concrete shape wrapped in the [Shape],
+    /// null shapes leave it unchanged
+    pub(crate) fn grow_from_any_shape(&mut self, shape: &Shape) {
+        match shape {
+            Shape::NullShape => {}
+            Shape::Point(shp) => self.grow_from_shape(shp),
+            Shape::PointM(shp) => self.grow_from_shape(shp),
+            Shape::PointZ(shp) => self.grow_from_shape(shp),
+            Shape::Polyline(shp) => self.grow_from_shape(shp),
+            Shape::PolylineM(shp) => self.grow_from_shape(shp),
+            Shape::PolylineZ(shp) => self.grow_from_shape(shp),
+            Shape::Polygon(shp) => self.grow_from_shape(shp),
+            Shape::PolygonM(shp) => self.grow_from_shape(shp),
+            Shape::PolygonZ(shp) => self.grow_from_shape(shp),
+            Shape::Multipoint(shp) => self.grow_from_shape(shp),
+            Shape::MultipointM(shp) => self.grow_from_shape(shp),
+            Shape::MultipointZ(shp) => self.grow_from_shape(shp),
+            Shape::Multipatch(shp) => self.grow_from_shape(shp),
+        }
+    }
+
     pub(crate) fn grow_from_shape<S: EsriShape>(&mut self, shape: &S) {
         let x_range = shape.x_range();
         let y_range = shape.y_range();
@@ -125,3 +281,140 @@ impl BBoxZ {
         }
     }
 }
+
+/// Returns the bounding box enclosing all the shapes
+///
+/// Shapes without z are considered to have z = 0.0,
+/// shapes without m do not change the m range, and if none of
+/// the shapes has m, the m range is `[NO_DATA, NO_DATA]`.
+///
+/// Returns `None` if the slice is empty or only contains null shapes.
+///
+/// # Example
+///
+/// ```
+/// use shapefile::{Point, PointZ, Shape};
+/// let shapes = vec![
+///     Shape::Point(Point::new(1.0, 5.0)),
+///     Shape::NullShape,
+///     Shape::PointZ(PointZ::new(-2.0, 3.0, 7.0, shapefile::NO_DATA)),
+/// ];
+/// let bbox = shapefile::bounding_box_of(&shapes).unwrap();
+/// assert_eq!(bbox.x_range(), [-2.0, 1.0]);
+/// assert_eq!(bbox.y_range(), [3.0, 5.0]);
+/// assert_eq!(bbox.z_range(), [0.0, 7.0]);
+///
+/// assert!(shapefile::bounding_box_of(&[Shape::NullShape]).is_none());
+/// ```
+pub fn bounding_box_of(shapes: &[Shape]) -> Option<BBoxZ> {
+    fn grow<S: EsriShape>(bbox: &mut BBoxZ, shape: &S) {
+        bbox.grow_from_shape(shape);
+        if !S::shapetype().has_z() {
+            bbox.min.z = f64_min(0.0, bbox.min.z);
+            bbox.max.z = f64_max(0.0, bbox.max.z);
+        }
+    }
+
+    let mut bbox = BBoxZ::inverted();
+    let mut is_empty = true;
+    for shape in shapes {
+        match shape {
+            Shape::NullShape => continue,
+            Shape::Point(shp) => grow(&mut bbox, shp),
+            Shape::PointM(shp) => grow(&mut bbox, shp),
+            Shape::PointZ(shp) => grow(&mut bbox, shp),
+            Shape::Polyline(shp) => grow(&mut bbox, shp),
+            Shape::PolylineM(shp) => grow(&mut bbox, shp),
+            Shape::PolylineZ(shp) => grow(&mut bbox, shp),
+            Shape::Polygon(shp) => grow(&mut bbox, shp),
+            Shape::PolygonM(shp) => grow(&mut bbox, shp),
+            Shape::PolygonZ(shp) => grow(&mut bbox, shp),
+            Shape::Multipoint(shp) => grow(&mut bbox, shp),
+            Shape::MultipointM(shp) => grow(&mut bbox, shp),
+            Shape::MultipointZ(shp) => grow(&mut bbox, shp),
+            Shape::Multipatch(shp) => grow(&mut bbox, shp),
+        }
+        is_empty = false;
+    }
+
+    if is_empty {
+        return None;
+    }
+    if bbox.max.m == f64::MIN && bbox.min.m == f64::MAX {
+        bbox.min.m = NO_DATA;
+        bbox.max.m = NO_DATA;
+    }
+    Some(bbox)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bbox_from_ranges() {
+        let bbox = GenericBBox::<Point>::from_ranges([1.0, 2.0], [3.0, 4.0]);
+        assert_eq!(bbox.x_range(), [1.0, 2.0]);
+        assert_eq!(bbox.y_range(), [3.0, 4.0]);
+
+        let bbox = GenericBBox::<PointM>::from_ranges([1.0, 2.0], [3.0, 4.0], [5.0, 6.0]);
+        assert_eq!(bbox.x_range(), [1.0, 2.0]);
+        assert_eq!(bbox.y_range(), [3.0, 4.0]);
+        assert_eq!(bbox.m_range(), [5.0, 6.0]);
+
+        let bbox = BBoxZ::from_ranges([1.0, 2.0], [3.0, 4.0], [5.0, 6.0], [7.0, 8.0]);
+        assert_eq!(bbox.x_range(), [1.0, 2.0]);
+        assert_eq!(bbox.y_range(), [3.0, 4.0]);
+        assert_eq!(bbox.z_range(), [5.0, 6.0]);
+        assert_eq!(bbox.m_range(), [7.0, 8.0]);
+    }
+
+    #[test]
+    fn bbox_ignores_nan() {
+        let points = [
+            Point::new(f64::NAN, 2.0),
+            Point::new(1.0, f64::NAN),
+            Point::new(3.0, -1.0),
+        ];
+        let bbox = GenericBBox::<Point>::from_points(&points);
+        assert_eq!(bbox.min, Point::new(1.0, -1.0));
+        assert_eq!(bbox.max, Point::new(3.0, 2.0));
+    }
+
+    #[test]
+    fn bbox_display() {
+        let bbox = GenericBBox::<PointM>::from_ranges([1.0, 2.0], [3.0, 4.0], [5.0, 6.0]);
+        assert_eq!(format!("{}", bbox), "[1, 3, 5] - [2, 4, 6]");
+
+        let bbox = BBoxZ::from_ranges([1.0, 2.0], [3.0, 4.0], [5.0, 6.0], [7.0, 8.5]);
+        assert_eq!(format!("{}", bbox), "[1, 3, 5, 7] - [2, 4, 6, 8.5]");
+    }
+
+    #[test]
+    fn bounding_box_of_points_and_polygons() {
+        use crate::record::{Polygon, PolygonRing};
+
+        let shapes = vec![
+            Shape::Point(Point::new(-5.0, 2.0)),
+            Shape::NullShape,
+            Shape::Polygon(Polygon::new(PolygonRing::Outer(vec![
+                Point::new(0.0, 0.0),
+                Point::new(0.0, 10.0),
+                Point::new(4.0, 10.0),
+                Point::new(0.0, 0.0),
+            ]))),
+            Shape::PointM(PointM::new(6.0, -1.0, 3.5)),
+        ];
+        let bbox = bounding_box_of(&shapes).unwrap();
+        assert_eq!(bbox.x_range(), [-5.0, 6.0]);
+        assert_eq!(bbox.y_range(), [-1.0, 10.0]);
+        assert_eq!(bbox.z_range(), [0.0, 0.0]);
+        assert_eq!(bbox.m_range(), [3.5, 3.5]);
+
+        let bbox = bounding_box_of(&shapes[..2]).unwrap();
+        assert_eq!(bbox.m_range(), [NO_DATA, NO_DATA]);
+
+        assert!(bounding_box_of(&[]).is_none());
+        assert!(bounding_box_of(&[Shape::NullShape, Shape::NullShape]).is_none());
+    }
+}
@@ -3,7 +3,7 @@ use std::io::{Read, Write};
 use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
 
 use super::traits::{HasM, HasMutM, HasMutXY, HasMutZ, HasXY, HasZ};
-use super::{GenericBBox, PointZ, NO_DATA};
+use super::{Error, GenericBBox, PointZ, NO_DATA};
 use super::{Point, PointM};
 
 pub(crate) fn bbox_read_xy_from<PointType: HasMutXY, R: Read>(
@@ -64,6 +64,29 @@ pub(crate) fn bbox_write_z_range_to<PointType: HasZ, W: Write>(
     Ok(())
 }
 
+/// Returns an error if a record of `record_size` bytes (shape type excluded)
+/// is too small to hold `fixed_size` bytes, `num_parts` part indices and `num_points` points
+///
+/// The counts are read from the file, so they are checked before being used to allocate.
+pub(crate) fn check_counts_fit_record(
+    record_size: i32,
+    fixed_size: usize,
+    num_parts: i32,
+    num_points: i32,
+) -> Result<(), Error> {
+    if num_parts < 0 || num_points < 0 {
+        return Err(Error::InvalidShapeRecordSize);
+    }
+    let min_size = fixed_size as i64
+        + std::mem::size_of::<i32>() as i64 * num_parts as i64
+        + std::mem::size_of::<Point>() as i64 * num_points as i64;
+    if min_size > record_size as i64 {
+        Err(Error::InvalidShapeRecordSize)
+    } else {
+        Ok(())
+    }
+}
+
 pub(crate) fn read_xy_in_vec_of<PointType, T>(
     source: &mut T,
     num_points: i32,
@@ -82,12 +105,22 @@ where
     Ok(points)
 }
 
+/// Returns [NO_DATA] if `m` is less or equal to the `no_data_threshold`, `m` otherwise
+pub(crate) fn m_or_no_data(m: f64, no_data_threshold: f64) -> f64 {
+    if m <= no_data_threshold {
+        NO_DATA
+    } else {
+        m
+    }
+}
+
 pub(crate) fn read_ms_into<T: Read, D: HasMutM>(
     source: &mut T,
     points: &mut [D],
+    no_data_threshold: f64,
 ) -> Result<(), std::io::Error> {
     for point in points {
-        *point.m_mut() = f64::max(source.read_f64::<LittleEndian>()?, NO_DATA);
+        *point.m_mut() = m_or_no_data(source.read_f64::<LittleEndian>()?, no_data_threshold);
     }
     Ok(())
 }
@@ -187,12 +220,24 @@ pub(crate) struct MultiPartShapeReader<'a, PointType, R: Read> {
 }
 
 impl<'a, PointType: Default + HasMutXY, R: Read> MultiPartShapeReader<'a, PointType, R> {
-    pub(crate) fn new(source: &'a mut R) -> std::io::Result<Self> {
+    /// Reads the bbox, the counts and the part indices of the record
+    ///
+    /// Returns [Error::InvalidShapeRecordSize] if the counts cannot fit in `record_size` bytes
+    pub(crate) fn new(source: &'a mut R, record_size: i32) -> Result<Self, Error> {
         let mut bbox = GenericBBox::<PointType>::default();
         bbox_read_xy_from(&mut bbox, source)?;
         let num_parts = source.read_i32::<LittleEndian>()?;
         let num_points = source.read_i32::<LittleEndian>()?;
+        // bbox + num_parts + num_points
+        let fixed_size = 4 * std::mem::size_of::<f64>() + 2 * std::mem::size_of::<i32>();
+        check_counts_fit_record(record_size, fixed_size, num_parts, num_points)?;
         let parts_array = read_parts(source, num_parts)?;
+        if parts_array
+            .iter()
+            .any(|&part_start| part_start < 0 || part_start > num_points)
+        {
+            return Err(Error::InvalidShapeRecordSize);
+        }
         let parts = Vec::<Vec<PointType>>::with_capacity(num_parts as usize);
         Ok(Self {
             num_points,
@@ -215,17 +260,21 @@ impl<'a, PointType: Default + HasMutXY, R: Read> MultiPartShapeReader<'a, PointT
 }
 
 impl<'a, PointType: HasMutM, R: Read> MultiPartShapeReader<'a, PointType, R> {
-    pub(crate) fn read_ms(mut self) -> std::io::Result<Self> {
+    pub(crate) fn read_ms(mut self, no_data_threshold: f64) -> std::io::Result<Self> {
         bbox_read_m_range_from(&mut self.bbox, &mut self.source)?;
         for part_points in self.parts.iter_mut() {
-            read_ms_into(self.source, part_points)?;
+            read_ms_into(self.source, part_points, no_data_threshold)?;
         }
         Ok(self)
     }
 
-    pub(crate) fn read_ms_if(self, condition: bool) -> std::io::Result<Self> {
+    pub(crate) fn read_ms_if(
+        self,
+        condition: bool,
+        no_data_threshold: f64,
+    ) -> std::io::Result<Self> {
         if condition {
-            self.read_ms()
+            self.read_ms(no_data_threshold)
         } else {
             Ok(self)
         }
@@ -304,6 +353,15 @@ where
         }
         Ok(self)
     }
+
+    /// Writes the shape as a 2D (x and y only) multi part shape
+    pub(crate) fn write_point_shape(self) -> std::io::Result<Self> {
+        self.write_bbox_xy()
+            .and_then(|wrt| wrt.write_num_parts())
+            .and_then(|wrt| wrt.write_num_points())
+            .and_then(|wrt| wrt.write_parts_array())
+            .and_then(|wrt| wrt.write_xy())
+    }
 }
 
 impl<'a, PointType, T, W> MultiPartShapeWriter<'a, PointType, T, W>
@@ -343,33 +401,24 @@ where
     }
 }
 
-impl<'a, T, W> MultiPartShapeWriter<'a, Point, T, W>
-where
-    T: Iterator<Item = &'a [Point]> + Clone,
-    W: Write,
-{
-    pub(crate) fn write_point_shape(self) -> std::io::Result<Self> {
-        self.write_bbox_xy()
-            .and_then(|wrt| wrt.write_num_parts())
-            .and_then(|wrt| wrt.write_num_points())
-            .and_then(|wrt| wrt.write_parts_array())
-            .and_then(|wrt| wrt.write_xy())
-    }
-}
-
 impl<'a, T, W> MultiPartShapeWriter<'a, PointM, T, W>
 where
     T: Iterator<Item = &'a [PointM]> + Clone,
     W: Write,
 {
     pub(crate) fn write_point_m_shape(self) -> std::io::Result<Self> {
+        self.write_point_m_shape_without_ms()
+            .and_then(|wrt| wrt.write_bbox_m_range())
+            .and_then(|wrt| wrt.write_ms())
+    }
+
+    /// Writes the shape without the optional M block
+    pub(crate) fn write_point_m_shape_without_ms(self) -> std::io::Result<Self> {
         self.write_bbox_xy()
             .and_then(|wrt| wrt.write_num_parts())
             .and_then(|wrt| wrt.write_num_points())
             .and_then(|wrt| wrt.write_parts_array())
             .and_then(|wrt| wrt.write_xy())
-            .and_then(|wrt| wrt.write_bbox_m_range())
-            .and_then(|wrt| wrt.write_ms())
     }
 }
 
@@ -14,13 +14,19 @@ pub mod polyline;
 pub mod traits;
 
 use super::{Error, ShapeType};
-pub use bbox::{BBoxZ, GenericBBox};
-pub use multipatch::{Multipatch, Patch};
+pub use bbox::{bounding_box_of, BBoxZ, GenericBBox};
+pub use multipatch::{Multipatch, Patch, PatchType};
+use multipoint::GenericMultipoint;
 pub use multipoint::{Multipoint, MultipointM, MultipointZ};
-pub use point::{Point, PointM, PointZ};
+pub use multipoint::{MultipointBuilder, MultipointMBuilder, MultipointZBuilder};
+pub use point::{HashablePoint, Point, PointF32, PointM, PointZ};
+use polygon::GenericPolygon;
 pub use polygon::{Polygon, PolygonM, PolygonRing, PolygonZ};
+use polyline::GenericPolyline;
 pub use polyline::{Polyline, PolylineM, PolylineZ};
-use traits::HasXY;
+use traits::{GrowablePoint, HasXY, ShrinkablePoint};
+
+use crate::reader::ReadOptions;
 
 #[cfg(feature = "geo-types")]
 use geo_types;
@@ -45,19 +51,50 @@ pub trait ConcreteReadableShape: ConcreteShape {
     /// Function that actually reads the `ActualShape` from the source
     /// and returns it
     fn read_shape_content<T: Read>(source: &mut T, record_size: i32) -> Result<Self, Error>;
+
+    /// Same as [ConcreteReadableShape::read_shape_content], but using the `options`
+    ///
+    /// The default implementation ignores the options, shapes with measures
+    /// override it to apply the [ReadOptions::no_data_threshold].
+    fn read_shape_content_with_options<T: Read>(
+        source: &mut T,
+        record_size: i32,
+        _options: ReadOptions,
+    ) -> Result<Self, Error> {
+        Self::read_shape_content(source, record_size)
+    }
 }
 
 /// Trait implemented by all the Shapes that can be read
 pub trait ReadableShape: Sized {
     fn read_from<T: Read>(source: &mut T, record_size: i32) -> Result<Self, Error>;
+
+    /// Same as [ReadableShape::read_from], but using the `options`
+    ///
+    /// The default implementation ignores the options.
+    fn read_from_with_options<T: Read>(
+        source: &mut T,
+        record_size: i32,
+        _options: ReadOptions,
+    ) -> Result<Self, Error> {
+        Self::read_from(source, record_size)
+    }
 }
 
 impl<S: ConcreteReadableShape> ReadableShape for S {
-    fn read_from<T: Read>(mut source: &mut T, mut record_size: i32) -> Result<S, Error> {
+    fn read_from<T: Read>(source: &mut T, record_size: i32) -> Result<S, Error> {
+        Self::read_from_with_options(source, record_size, ReadOptions::default())
+    }
+
+    fn read_from_with_options<T: Read>(
+        mut source: &mut T,
+        mut record_size: i32,
+        options: ReadOptions,
+    ) -> Result<S, Error> {
         let shapetype = ShapeType::read_from(&mut source)?;
         record_size -= std::mem::size_of::<i32>() as i32;
         if shapetype == Self::shapetype() {
-            S::read_shape_content(&mut source, record_size)
+            S::read_shape_content_with_options(&mut source, record_size, options)
         } else {
             Err(Error::MismatchShapeType {
                 requested: Self::shapetype(),
@@ -67,6 +104,38 @@ impl<S: ConcreteReadableShape> ReadableShape for S {
     }
 }
 
+/// Reading a concrete shape as an `Option` allows `NullShape` records
+/// which are allowed anywhere in a file, they are read as `None`.
+impl<S: ConcreteReadableShape> ReadableShape for Option<S> {
+    fn read_from<T: Read>(source: &mut T, record_size: i32) -> Result<Self, Error> {
+        Self::read_from_with_options(source, record_size, ReadOptions::default())
+    }
+
+    fn read_from_with_options<T: Read>(
+        mut source: &mut T,
+        mut record_size: i32,
+        options: ReadOptions,
+    ) -> Result<Self, Error> {
+        let shapetype = ShapeType::read_from(&mut source)?;
+        record_size -= std::mem::size_of::<i32>() as i32;
+        if shapetype == S::shapetype() {
+            S::read_shape_content_with_options(&mut source, record_size, options).map(Some)
+        } else if shapetype == ShapeType::NullShape {
+            // A NullShape record is only made of its shape type,
+            // but skip whatever may have been declared after it
+            if record_size > 0 {
+                std::io::copy(&mut source.take(record_size as u64), &mut std::io::sink())?;
+            }
+            Ok(None)
+        } else {
+            Err(Error::MismatchShapeType {
+                requested: S::shapetype(),
+                actual: shapetype,
+            })
+        }
+    }
+}
+
 /// Trait implemented by all Shapes that can be written
 pub trait WritableShape {
     /// Returns the size in bytes that the Shapes will take once written.
@@ -88,6 +157,74 @@ pub trait EsriShape: HasShapeType + WritableShape {
     fn m_range(&self) -> [f64; 2] {
         [0.0, 0.0]
     }
+    /// Returns the shape type of the shape
+    ///
+    /// This is the same as [HasShapeType::shapetype] but
+    /// can be called on a value, which is handy in generic code.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use shapefile::record::EsriShape;
+    /// use shapefile::{PointM, PolylineM, ShapeType};
+    /// let polyline = PolylineM::new(vec![
+    ///     PointM::new(1.0, 1.0, 5.0),
+    ///     PointM::new(2.0, 2.0, 6.0),
+    /// ]);
+    /// assert_eq!(polyline.runtime_shape_type(), ShapeType::PolylineM);
+    /// ```
+    fn runtime_shape_type(&self) -> ShapeType {
+        Self::shapetype()
+    }
+    /// Returns the number of bytes the shape takes in the _.shp_ once written,
+    /// including the record header and the shape type
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use shapefile::record::EsriShape;
+    /// use shapefile::Point;
+    /// // 8 bytes of record header, 4 for the shape type, 16 for x and y
+    /// assert_eq!(Point::new(1.0, 2.0).record_size_in_file(), 28);
+    /// ```
+    fn record_size_in_file(&self) -> usize {
+        self.size_in_bytes() + std::mem::size_of::<i32>() + RecordHeader::SIZE
+    }
+    /// Should return true if none of the `x` and `y` coordinates
+    /// of the shape are NaN or infinite
+    ///
+    /// The default implementation only looks at the x and y ranges.
+    fn has_finite_coords(&self) -> bool {
+        self.x_range()
+            .iter()
+            .chain(self.y_range().iter())
+            .all(|v| v.is_finite())
+    }
+    /// Returns the size in bytes that the shape takes once written
+    /// without its z and m, as done by [write_2d_to](Self::write_2d_to)
+    ///
+    /// The default implementation is the [WritableShape::size_in_bytes]
+    /// of the shape, which is right for 2D shapes.
+    fn size_in_bytes_2d(&self) -> usize {
+        self.size_in_bytes()
+    }
+    /// Writes the shape without its z and m, that is
+    /// the way its 2D shape type (see [ShapeType::to_2d]) is written
+    ///
+    /// The default implementation is the [WritableShape::write_to]
+    /// of the shape, which is right for 2D shapes.
+    fn write_2d_to<T: Write>(&self, dest: &mut T) -> Result<(), Error> {
+        self.write_to(dest)
+    }
+}
+
+/// Returns true if the `x` and `y` of all the points are finite
+pub(crate) fn are_xy_finite<'a, PointType: HasXY + 'a, I: IntoIterator<Item = &'a PointType>>(
+    points: I,
+) -> bool {
+    points
+        .into_iter()
+        .all(|point| point.x().is_finite() && point.y().is_finite())
 }
 
 pub(crate) fn is_part_closed<PointType: PartialEq>(points: &[PointType]) -> bool {
@@ -98,6 +235,47 @@ pub(crate) fn is_part_closed<PointType: PartialEq>(points: &[PointType]) -> bool
     }
 }
 
+/// Returns true if the `x` and `y` of the first and last points are within `epsilon`
+pub fn is_part_closed_eps<PointType: HasXY>(points: &[PointType], epsilon: f64) -> bool {
+    if let (Some(first), Some(last)) = (points.first(), points.last()) {
+        Point::new(first.x(), first.y()).approx_eq(&Point::new(last.x(), last.y()), epsilon)
+    } else {
+        false
+    }
+}
+
+/// Closes the points by adding a copy of the first point at the end,
+/// unless the `x` and `y` of the first and last points are already within `epsilon`
+///
+/// Unlike the constructors of polygons, which use exact comparisons,
+/// this tolerates points that do not exactly repeat the first vertex
+/// (e.g. after a round trip through text).
+///
+/// # Example
+///
+/// ```
+/// use shapefile::Point;
+/// use shapefile::record::close_points_if_not_already_eps;
+/// let mut points = vec![
+///     Point::new(0.0, 0.0),
+///     Point::new(0.0, 1.0),
+///     Point::new(1.0, 1.0),
+///     Point::new(1e-9, 0.0),
+/// ];
+/// close_points_if_not_already_eps(&mut points, 1e-6);
+/// assert_eq!(points.len(), 4);
+/// ```
+pub fn close_points_if_not_already_eps<PointType: HasXY + Copy>(
+    points: &mut Vec<PointType>,
+    epsilon: f64,
+) {
+    if !is_part_closed_eps(points, epsilon) {
+        if let Some(point) = points.first().copied() {
+            points.push(point)
+        }
+    }
+}
+
 pub(crate) fn close_points_if_not_already<PointType: PartialEq + Copy>(
     points: &mut Vec<PointType>,
 ) {
@@ -168,6 +346,7 @@ pub(crate) fn ring_type_from_points_ordering<PointType: HasXY>(points: &[PointTy
 /// # fn main() {}
 /// ```
 ///
+#[derive(Debug, PartialEq)]
 pub enum Shape {
     NullShape,
     Point(Point),
@@ -192,53 +371,179 @@ impl HasShapeType for Shape {
 }
 
 impl ReadableShape for Shape {
-    fn read_from<T: Read>(mut source: &mut T, mut record_size: i32) -> Result<Self, Error> {
+    fn read_from<T: Read>(source: &mut T, record_size: i32) -> Result<Self, Error> {
+        Self::read_from_with_options(source, record_size, ReadOptions::default())
+    }
+
+    fn read_from_with_options<T: Read>(
+        mut source: &mut T,
+        mut record_size: i32,
+        options: ReadOptions,
+    ) -> Result<Self, Error> {
         let shapetype = ShapeType::read_from(&mut source)?;
         record_size -= std::mem::size_of::<i32>() as i32;
         let shape = match shapetype {
-            ShapeType::Polyline => {
-                Shape::Polyline(Polyline::read_shape_content(&mut source, record_size)?)
-            }
-            ShapeType::PolylineM => {
-                Shape::PolylineM(PolylineM::read_shape_content(&mut source, record_size)?)
-            }
-            ShapeType::PolylineZ => {
-                Shape::PolylineZ(PolylineZ::read_shape_content(&mut source, record_size)?)
-            }
-            ShapeType::Point => Shape::Point(Point::read_shape_content(&mut source, record_size)?),
-            ShapeType::PointM => {
-                Shape::PointM(PointM::read_shape_content(&mut source, record_size)?)
-            }
-            ShapeType::PointZ => {
-                Shape::PointZ(PointZ::read_shape_content(&mut source, record_size)?)
-            }
-            ShapeType::Polygon => {
-                Shape::Polygon(Polygon::read_shape_content(&mut source, record_size)?)
-            }
-            ShapeType::PolygonM => {
-                Shape::PolygonM(PolygonM::read_shape_content(&mut source, record_size)?)
-            }
-            ShapeType::PolygonZ => {
-                Shape::PolygonZ(PolygonZ::read_shape_content(&mut source, record_size)?)
-            }
-            ShapeType::Multipoint => {
-                Shape::Multipoint(Multipoint::read_shape_content(&mut source, record_size)?)
-            }
-            ShapeType::MultipointM => {
-                Shape::MultipointM(MultipointM::read_shape_content(&mut source, record_size)?)
-            }
-            ShapeType::MultipointZ => {
-                Shape::MultipointZ(MultipointZ::read_shape_content(&mut source, record_size)?)
-            }
-            ShapeType::Multipatch => {
-                Shape::Multipatch(Multipatch::read_shape_content(&mut source, record_size)?)
-            }
+            ShapeType::Polyline => Shape::Polyline(Polyline::read_shape_content_with_options(
+                &mut source,
+                record_size,
+                options,
+            )?),
+            ShapeType::PolylineM => Shape::PolylineM(PolylineM::read_shape_content_with_options(
+                &mut source,
+                record_size,
+                options,
+            )?),
+            ShapeType::PolylineZ => Shape::PolylineZ(PolylineZ::read_shape_content_with_options(
+                &mut source,
+                record_size,
+                options,
+            )?),
+            ShapeType::Point => Shape::Point(Point::read_shape_content_with_options(
+                &mut source,
+                record_size,
+                options,
+            )?),
+            ShapeType::PointM => Shape::PointM(PointM::read_shape_content_with_options(
+                &mut source,
+                record_size,
+                options,
+            )?),
+            ShapeType::PointZ => Shape::PointZ(PointZ::read_shape_content_with_options(
+                &mut source,
+                record_size,
+                options,
+            )?),
+            ShapeType::Polygon => Shape::Polygon(Polygon::read_shape_content_with_options(
+                &mut source,
+                record_size,
+                options,
+            )?),
+            ShapeType::PolygonM => Shape::PolygonM(PolygonM::read_shape_content_with_options(
+                &mut source,
+                record_size,
+                options,
+            )?),
+            ShapeType::PolygonZ => Shape::PolygonZ(PolygonZ::read_shape_content_with_options(
+                &mut source,
+                record_size,
+                options,
+            )?),
+            ShapeType::Multipoint => Shape::Multipoint(
+                Multipoint::read_shape_content_with_options(&mut source, record_size, options)?,
+            ),
+            ShapeType::MultipointM => Shape::MultipointM(
+                MultipointM::read_shape_content_with_options(&mut source, record_size, options)?,
+            ),
+            ShapeType::MultipointZ => Shape::MultipointZ(
+                MultipointZ::read_shape_content_with_options(&mut source, record_size, options)?,
+            ),
+            ShapeType::Multipatch => Shape::Multipatch(
+                Multipatch::read_shape_content_with_options(&mut source, record_size, options)?,
+            ),
             ShapeType::NullShape => Shape::NullShape,
         };
         Ok(shape)
     }
 }
 
+/// macro that generates a method of the Shape enum
+/// returning a reference to the inner concrete shape
+macro_rules! impl_shape_as {
+    ($fn_name:ident, Shape::$ShapeEnumVariant:ident=>$ConcreteShape:ident) => {
+        #[doc = concat!(
+            "Returns a reference to the inner [`", stringify!($ConcreteShape),
+            "`] or `None` if the shape is not a `Shape::", stringify!($ShapeEnumVariant), "`"
+        )]
+        pub fn $fn_name(&self) -> Option<&$ConcreteShape> {
+            match self {
+                Shape::$ShapeEnumVariant(shp) => Some(shp),
+                _ => None,
+            }
+        }
+    };
+}
+
+/// Borrowing counterparts of the `TryFrom<Shape>` conversions
+///
+/// # Example
+///
+/// ```
+/// # fn main() -> Result<(), shapefile::Error> {
+/// let shapes = shapefile::read_shapes("tests/data/line.shp")?;
+/// for shape in &shapes {
+///     match shape.as_polyline() {
+///         Some(polyline) => println!("Polyline with {} parts", polyline.parts().len()),
+///         None => println!("Not a polyline"),
+///     }
+/// }
+/// assert!(shapes[0].as_polyline().is_some());
+/// assert!(shapes[0].as_polygon().is_none());
+/// # Ok(())
+/// # }
+/// ```
+impl Shape {
+    impl_shape_as!(as_point, Shape::Point => Point);
+    impl_shape_as!(as_point_m, Shape::PointM => PointM);
+    impl_shape_as!(as_point_z, Shape::PointZ => PointZ);
+    impl_shape_as!(as_polyline, Shape::Polyline => Polyline);
+    impl_shape_as!(as_polyline_m, Shape::PolylineM => PolylineM);
+    impl_shape_as!(as_polyline_z, Shape::PolylineZ => PolylineZ);
+    impl_shape_as!(as_polygon, Shape::Polygon => Polygon);
+    impl_shape_as!(as_polygon_m, Shape::PolygonM => PolygonM);
+    impl_shape_as!(as_polygon_z, Shape::PolygonZ => PolygonZ);
+    impl_shape_as!(as_multipoint, Shape::Multipoint => Multipoint);
+    impl_shape_as!(as_multipoint_m, Shape::MultipointM => MultipointM);
+    impl_shape_as!(as_multipoint_z, Shape::MultipointZ => MultipointZ);
+    impl_shape_as!(as_multipatch, Shape::Multipatch => Multipatch);
+}
+
+/// macro that generates a method of the Shape enum
+/// converting it to the inner concrete shape, giving back the shape on failure
+macro_rules! impl_shape_try_into {
+    ($fn_name:ident, Shape::$ShapeEnumVariant:ident=>$ConcreteShape:ident) => {
+        #[doc = concat!(
+            "Converts the shape to the inner [`", stringify!($ConcreteShape),
+            "`], or gives the shape back if it is not a `Shape::", stringify!($ShapeEnumVariant), "`"
+        )]
+        pub fn $fn_name(self) -> Result<$ConcreteShape, Shape> {
+            match self {
+                Shape::$ShapeEnumVariant(shp) => Ok(shp),
+                shape => Err(shape),
+            }
+        }
+    };
+}
+
+/// Counterparts of the `TryFrom<Shape>` conversions that return
+/// the original shape on failure, so that another conversion can be tried
+///
+/// # Example
+///
+/// ```
+/// use shapefile::{Point, Shape};
+/// let shape = Shape::Point(Point::new(1.0, 2.0));
+/// let point = match shape.try_into_polyline() {
+///     Ok(_polyline) => unreachable!(),
+///     Err(shape) => shape.try_into_point().unwrap(),
+/// };
+/// assert_eq!(point, Point::new(1.0, 2.0));
+/// ```
+impl Shape {
+    impl_shape_try_into!(try_into_point, Shape::Point => Point);
+    impl_shape_try_into!(try_into_point_m, Shape::PointM => PointM);
+    impl_shape_try_into!(try_into_point_z, Shape::PointZ => PointZ);
+    impl_shape_try_into!(try_into_polyline, Shape::Polyline => Polyline);
+    impl_shape_try_into!(try_into_polyline_m, Shape::PolylineM => PolylineM);
+    impl_shape_try_into!(try_into_polyline_z, Shape::PolylineZ => PolylineZ);
+    impl_shape_try_into!(try_into_polygon, Shape::Polygon => Polygon);
+    impl_shape_try_into!(try_into_polygon_m, Shape::PolygonM => PolygonM);
+    impl_shape_try_into!(try_into_polygon_z, Shape::PolygonZ => PolygonZ);
+    impl_shape_try_into!(try_into_multipoint, Shape::Multipoint => Multipoint);
+    impl_shape_try_into!(try_into_multipoint_m, Shape::MultipointM => MultipointM);
+    impl_shape_try_into!(try_into_multipoint_z, Shape::MultipointZ => MultipointZ);
+    impl_shape_try_into!(try_into_multipatch, Shape::Multipatch => Multipatch);
+}
+
 impl Shape {
     /// Returns the shapetype
     pub fn shapetype(&self) -> ShapeType {
@@ -259,6 +564,628 @@ impl Shape {
             Shape::NullShape => ShapeType::NullShape,
         }
     }
+
+    /// Returns true if none of the `x` and `y` coordinates of the shape
+    /// are NaN or infinite
+    ///
+    /// Shapes that do not have finite coordinates can't be written.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use shapefile::{Point, Shape};
+    /// assert!(Shape::Point(Point::new(1.0, 1.0)).has_finite_coords());
+    /// assert!(!Shape::Point(Point::new(f64::NAN, 1.0)).has_finite_coords());
+    /// assert!(Shape::NullShape.has_finite_coords());
+    /// ```
+    pub fn has_finite_coords(&self) -> bool {
+        match self {
+            Shape::NullShape => true,
+            Shape::Point(shp) => shp.has_finite_coords(),
+            Shape::PointM(shp) => shp.has_finite_coords(),
+            Shape::PointZ(shp) => shp.has_finite_coords(),
+            Shape::Polyline(shp) => shp.has_finite_coords(),
+            Shape::PolylineM(shp) => shp.has_finite_coords(),
+            Shape::PolylineZ(shp) => shp.has_finite_coords(),
+            Shape::Polygon(shp) => shp.has_finite_coords(),
+            Shape::PolygonM(shp) => shp.has_finite_coords(),
+            Shape::PolygonZ(shp) => shp.has_finite_coords(),
+            Shape::Multipoint(shp) => shp.has_finite_coords(),
+            Shape::MultipointM(shp) => shp.has_finite_coords(),
+            Shape::MultipointZ(shp) => shp.has_finite_coords(),
+            Shape::Multipatch(shp) => shp.has_finite_coords(),
+        }
+    }
+
+    /// Returns the total number of points of the shape
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use shapefile::{Point, Shape};
+    /// assert_eq!(Shape::Point(Point::new(1.0, 1.0)).point_count(), 1);
+    /// assert_eq!(Shape::NullShape.point_count(), 0);
+    /// ```
+    pub fn point_count(&self) -> usize {
+        match self {
+            Shape::NullShape => 0,
+            Shape::Point(_) | Shape::PointM(_) | Shape::PointZ(_) => 1,
+            Shape::Polyline(shp) => shp.total_point_count(),
+            Shape::PolylineM(shp) => shp.total_point_count(),
+            Shape::PolylineZ(shp) => shp.total_point_count(),
+            Shape::Polygon(shp) => shp.total_point_count(),
+            Shape::PolygonM(shp) => shp.total_point_count(),
+            Shape::PolygonZ(shp) => shp.total_point_count(),
+            Shape::Multipoint(shp) => shp.points().len(),
+            Shape::MultipointM(shp) => shp.points().len(),
+            Shape::MultipointZ(shp) => shp.points().len(),
+            Shape::Multipatch(shp) => shp.total_point_count(),
+        }
+    }
+
+    /// Returns the number of parts of the shape
+    ///
+    /// That is the number of rings for polygons (as stored in the file, so without
+    /// the empty outer ring added to inner rings that have none),
+    /// of parts for polylines and of patches for multipatches.
+    /// Points and multipoints have 1 part, null shapes have none.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use shapefile::{Point, Polyline, Shape};
+    /// let polyline = Polyline::with_parts(vec![
+    ///     vec![Point::new(1.0, 1.0), Point::new(2.0, 2.0)],
+    ///     vec![Point::new(3.0, 3.0), Point::new(4.0, 4.0)],
+    /// ]);
+    /// assert_eq!(Shape::Polyline(polyline).num_parts(), 2);
+    /// assert_eq!(Shape::NullShape.num_parts(), 0);
+    /// ```
+    pub fn num_parts(&self) -> usize {
+        match self {
+            Shape::NullShape => 0,
+            Shape::Point(_) | Shape::PointM(_) | Shape::PointZ(_) => 1,
+            Shape::Multipoint(_) | Shape::MultipointM(_) | Shape::MultipointZ(_) => 1,
+            Shape::Polyline(shp) => shp.parts().len(),
+            Shape::PolylineM(shp) => shp.parts().len(),
+            Shape::PolylineZ(shp) => shp.parts().len(),
+            Shape::Polygon(shp) => shp.file_rings().len(),
+            Shape::PolygonM(shp) => shp.file_rings().len(),
+            Shape::PolygonZ(shp) => shp.file_rings().len(),
+            Shape::Multipatch(shp) => shp.patches().len(),
+        }
+    }
+
+    /// Returns the 2D euclidean distance between the centers
+    /// of the bounding boxes of the two shapes
+    ///
+    /// This is a cheap distance, suited for things like clustering.
+    /// Returns `None` if one of the shapes is a `NullShape`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use shapefile::{Point, Shape};
+    /// let a = Shape::Point(Point::new(0.0, 0.0));
+    /// let b = Shape::Point(Point::new(3.0, 4.0));
+    /// assert_eq!(a.bbox_center_distance(&b), Some(5.0));
+    /// assert_eq!(a.bbox_center_distance(&Shape::NullShape), None);
+    /// ```
+    pub fn bbox_center_distance(&self, other: &Shape) -> Option<f64> {
+        let center = |shape: &Shape| {
+            bounding_box_of(std::slice::from_ref(shape)).map(|bbox| {
+                (
+                    (bbox.min.x + bbox.max.x) / 2.0,
+                    (bbox.min.y + bbox.max.y) / 2.0,
+                )
+            })
+        };
+        let (x1, y1) = center(self)?;
+        let (x2, y2) = center(other)?;
+        Some((x2 - x1).hypot(y2 - y1))
+    }
+
+    /// Computes the convex hull of all the points of the shape
+    ///
+    /// Only the x and y coordinates are taken into account,
+    /// the hull is returned as a `Polygon` with a single outer ring.
+    ///
+    /// Returns `None` for a `NullShape` or when the shape does not have
+    /// at least 3 distinct, non-collinear points.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use shapefile::{Multipoint, Point, Shape};
+    /// let shape = Shape::Multipoint(Multipoint::new(vec![
+    ///     Point::new(0.0, 0.0),
+    ///     Point::new(2.0, 0.0),
+    ///     Point::new(1.0, 1.0),
+    ///     Point::new(1.0, 2.0),
+    /// ]));
+    /// let hull = shape.convex_hull().unwrap();
+    /// assert_eq!(hull.rings()[0].len(), 4);
+    /// ```
+    pub fn convex_hull(&self) -> Option<Polygon> {
+        fn cross(o: &Point, a: &Point, b: &Point) -> f64 {
+            (a.x - o.x) * (b.y - o.y) - (a.y - o.y) * (b.x - o.x)
+        }
+
+        let mut points = self.xy_points();
+        points.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+        points.dedup();
+        if points.len() < 3 {
+            return None;
+        }
+
+        // Andrew's monotone chain
+        let mut lower: Vec<Point> = Vec::with_capacity(points.len());
+        for point in &points {
+            while lower.len() >= 2
+                && cross(&lower[lower.len() - 2], &lower[lower.len() - 1], point) <= 0.0
+            {
+                lower.pop();
+            }
+            lower.push(*point);
+        }
+
+        let mut upper: Vec<Point> = Vec::with_capacity(points.len());
+        for point in points.iter().rev() {
+            while upper.len() >= 2
+                && cross(&upper[upper.len() - 2], &upper[upper.len() - 1], point) <= 0.0
+            {
+                upper.pop();
+            }
+            upper.push(*point);
+        }
+
+        lower.pop();
+        upper.pop();
+        lower.append(&mut upper);
+        if lower.len() < 3 {
+            return None;
+        }
+        Some(Polygon::new(PolygonRing::Outer(lower)))
+    }
+
+    /// Rounds the coordinates of all the points to the given number of decimal places
+    ///
+    /// x and y, and also z and m when the shape has them, are rounded.
+    /// `m` values that are _no data_ are left untouched.
+    ///
+    /// The bounding box of the shape is recomputed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use shapefile::{Point, Shape};
+    /// let mut shape = Shape::Point(Point::new(1.23456, -9.87654));
+    /// shape.round_coordinates(2);
+    /// if let Shape::Point(point) = shape {
+    ///     assert_eq!(point, Point::new(1.23, -9.88));
+    /// }
+    /// ```
+    pub fn round_coordinates(&mut self, decimals: u32) {
+        let factor = 10f64.powi(decimals as i32);
+        let round = |value: f64| {
+            let scaled = value * factor;
+            if scaled.is_finite() {
+                scaled.round() / factor
+            } else {
+                value
+            }
+        };
+        let round_m = |m: f64| if is_no_data(m) { m } else { round(m) };
+        let round_point = |p: &mut Point| {
+            p.x = round(p.x);
+            p.y = round(p.y);
+        };
+        let round_point_m = |p: &mut PointM| {
+            p.x = round(p.x);
+            p.y = round(p.y);
+            p.m = round_m(p.m);
+        };
+        let round_point_z = |p: &mut PointZ| {
+            p.x = round(p.x);
+            p.y = round(p.y);
+            p.z = round(p.z);
+            p.m = round_m(p.m);
+        };
+
+        match self {
+            Shape::NullShape => {}
+            Shape::Point(shp) => round_point(shp),
+            Shape::PointM(shp) => round_point_m(shp),
+            Shape::PointZ(shp) => round_point_z(shp),
+            Shape::Polyline(shp) => shp.for_each_point_mut(round_point),
+            Shape::PolylineM(shp) => shp.for_each_point_mut(round_point_m),
+            Shape::PolylineZ(shp) => shp.for_each_point_mut(round_point_z),
+            Shape::Polygon(shp) => shp.for_each_point_mut(round_point),
+            Shape::PolygonM(shp) => shp.for_each_point_mut(round_point_m),
+            Shape::PolygonZ(shp) => shp.for_each_point_mut(round_point_z),
+            Shape::Multipoint(shp) => shp.for_each_point_mut(round_point),
+            Shape::MultipointM(shp) => shp.for_each_point_mut(round_point_m),
+            Shape::MultipointZ(shp) => shp.for_each_point_mut(round_point_z),
+            Shape::Multipatch(shp) => shp.for_each_point_mut(round_point_z),
+        }
+    }
+
+    /// Converts the shape to its Z variant
+    ///
+    /// Shapes without z get a z of `0.0`, shapes without m
+    /// get [NO_DATA] measures. Z shapes, multipatches and null shapes
+    /// are returned unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use shapefile::{Point, PointZ, Shape, NO_DATA};
+    /// let shape = Shape::Point(Point::new(1.0, 2.0)).to_z();
+    /// assert_eq!(shape, Shape::PointZ(PointZ::new(1.0, 2.0, 0.0, NO_DATA)));
+    /// ```
+    pub fn to_z(self) -> Shape {
+        fn from_point(p: Point) -> PointZ {
+            PointZ::new(p.x, p.y, 0.0, NO_DATA)
+        }
+        fn from_point_m(p: PointM) -> PointZ {
+            PointZ::new(p.x, p.y, 0.0, p.m)
+        }
+
+        match self {
+            Shape::Point(shp) => Shape::PointZ(from_point(shp)),
+            Shape::PointM(shp) => Shape::PointZ(from_point_m(shp)),
+            Shape::Polyline(shp) => Shape::PolylineZ(map_polyline_points(shp, from_point)),
+            Shape::PolylineM(shp) => Shape::PolylineZ(map_polyline_points(shp, from_point_m)),
+            Shape::Polygon(shp) => Shape::PolygonZ(map_polygon_points(shp, from_point)),
+            Shape::PolygonM(shp) => Shape::PolygonZ(map_polygon_points(shp, from_point_m)),
+            Shape::Multipoint(shp) => Shape::MultipointZ(map_multipoint_points(shp, from_point)),
+            Shape::MultipointM(shp) => Shape::MultipointZ(map_multipoint_points(shp, from_point_m)),
+            shape => shape,
+        }
+    }
+
+    /// Converts the shape to its 2D variant, dropping z and m
+    ///
+    /// 2D shapes and null shapes are returned unchanged, as are multipatches,
+    /// which have no 2D variant.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use shapefile::{Point, PointZ, Shape};
+    /// let shape = Shape::PointZ(PointZ::new(1.0, 2.0, 3.0, 4.0)).to_2d();
+    /// assert_eq!(shape, Shape::Point(Point::new(1.0, 2.0)));
+    /// ```
+    pub fn to_2d(self) -> Shape {
+        fn from_point_m(p: PointM) -> Point {
+            Point::new(p.x, p.y)
+        }
+        fn from_point_z(p: PointZ) -> Point {
+            Point::new(p.x, p.y)
+        }
+
+        match self {
+            Shape::PointM(shp) => Shape::Point(from_point_m(shp)),
+            Shape::PointZ(shp) => Shape::Point(from_point_z(shp)),
+            Shape::PolylineM(shp) => Shape::Polyline(map_polyline_points(shp, from_point_m)),
+            Shape::PolylineZ(shp) => Shape::Polyline(map_polyline_points(shp, from_point_z)),
+            Shape::PolygonM(shp) => Shape::Polygon(map_polygon_points(shp, from_point_m)),
+            Shape::PolygonZ(shp) => Shape::Polygon(map_polygon_points(shp, from_point_z)),
+            Shape::MultipointM(shp) => Shape::Multipoint(map_multipoint_points(shp, from_point_m)),
+            Shape::MultipointZ(shp) => Shape::Multipoint(map_multipoint_points(shp, from_point_z)),
+            shape => shape,
+        }
+    }
+
+    /// Splits the shape in its simplest components
+    ///
+    /// - multipoints give one point shape per point
+    /// - polylines give one single part polyline per part
+    /// - polygons give one polygon per outer ring, each with the
+    ///   inner rings that follow it
+    ///
+    /// Other shapes are returned as is, in a `Vec` of one element.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use shapefile::{Multipoint, Point, Shape};
+    /// let shape = Shape::Multipoint(Multipoint::new(vec![
+    ///     Point::new(1.0, 1.0),
+    ///     Point::new(2.0, 2.0),
+    /// ]));
+    /// let shapes = shape.explode();
+    /// assert_eq!(shapes, vec![
+    ///     Shape::Point(Point::new(1.0, 1.0)),
+    ///     Shape::Point(Point::new(2.0, 2.0)),
+    /// ]);
+    /// ```
+    pub fn explode(self) -> Vec<Shape> {
+        match self {
+            Shape::Multipoint(shp) => shp.into_inner().into_iter().map(Shape::Point).collect(),
+            Shape::MultipointM(shp) => shp.into_inner().into_iter().map(Shape::PointM).collect(),
+            Shape::MultipointZ(shp) => shp.into_inner().into_iter().map(Shape::PointZ).collect(),
+            Shape::Polyline(shp) => shp.split_parts().into_iter().map(Shape::Polyline).collect(),
+            Shape::PolylineM(shp) => shp
+                .split_parts()
+                .into_iter()
+                .map(Shape::PolylineM)
+                .collect(),
+            Shape::PolylineZ(shp) => shp
+                .split_parts()
+                .into_iter()
+                .map(Shape::PolylineZ)
+                .collect(),
+            Shape::Polygon(shp) => shp
+                .into_polygons()
+                .into_iter()
+                .map(Shape::Polygon)
+                .collect(),
+            Shape::PolygonM(shp) => shp
+                .into_polygons()
+                .into_iter()
+                .map(Shape::PolygonM)
+                .collect(),
+            Shape::PolygonZ(shp) => shp
+                .into_polygons()
+                .into_iter()
+                .map(Shape::PolygonZ)
+                .collect(),
+            shape => vec![shape],
+        }
+    }
+
+    /// Calls `f` with the coordinates of every point of the shape
+    ///
+    /// The arguments given to `f` are `x`, `y`, `z` and `m`.
+    /// `z` is `None` for shapes that do not have a z coordinate,
+    /// `m` is `None` for shapes that do not have a measure or when the measure
+    /// is _no data_.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use shapefile::{PointM, PolylineM, Shape, NO_DATA};
+    /// let shape = Shape::PolylineM(PolylineM::new(vec![
+    ///     PointM::new(1.0, 1.0, 5.0),
+    ///     PointM::new(2.0, 2.0, NO_DATA),
+    /// ]));
+    /// let mut measures = vec![];
+    /// shape.for_each_coord(|_x, _y, _z, m| measures.push(m));
+    /// assert_eq!(measures, vec![Some(5.0), None]);
+    /// ```
+    pub fn for_each_coord<F: FnMut(f64, f64, Option<f64>, Option<f64>)>(&self, mut f: F) {
+        type Coord = (f64, f64, Option<f64>, Option<f64>);
+        fn measure(m: f64) -> Option<f64> {
+            if is_no_data(m) {
+                None
+            } else {
+                Some(m)
+            }
+        }
+        fn xy(p: &Point) -> Coord {
+            (p.x, p.y, None, None)
+        }
+        fn xym(p: &PointM) -> Coord {
+            (p.x, p.y, None, measure(p.m))
+        }
+        fn xyzm(p: &PointZ) -> Coord {
+            (p.x, p.y, Some(p.z), measure(p.m))
+        }
+
+        let mut visit = |(x, y, z, m): Coord| f(x, y, z, m);
+        match self {
+            Shape::NullShape => {}
+            Shape::Point(shp) => visit(xy(shp)),
+            Shape::PointM(shp) => visit(xym(shp)),
+            Shape::PointZ(shp) => visit(xyzm(shp)),
+            Shape::Polyline(shp) => shp.parts().iter().flatten().map(xy).for_each(visit),
+            Shape::PolylineM(shp) => shp.parts().iter().flatten().map(xym).for_each(visit),
+            Shape::PolylineZ(shp) => shp.parts().iter().flatten().map(xyzm).for_each(visit),
+            Shape::Polygon(shp) => shp
+                .rings()
+                .iter()
+                .flat_map(|ring| ring.points())
+                .map(xy)
+                .for_each(visit),
+            Shape::PolygonM(shp) => shp
+                .rings()
+                .iter()
+                .flat_map(|ring| ring.points())
+                .map(xym)
+                .for_each(visit),
+            Shape::PolygonZ(shp) => shp
+                .rings()
+                .iter()
+                .flat_map(|ring| ring.points())
+                .map(xyzm)
+                .for_each(visit),
+            Shape::Multipoint(shp) => shp.points().iter().map(xy).for_each(visit),
+            Shape::MultipointM(shp) => shp.points().iter().map(xym).for_each(visit),
+            Shape::MultipointZ(shp) => shp.points().iter().map(xyzm).for_each(visit),
+            Shape::Multipatch(shp) => shp
+                .patches()
+                .iter()
+                .flat_map(|patch| patch.points())
+                .map(xyzm)
+                .for_each(visit),
+        }
+    }
+
+    /// Returns true if both shapes are of the same type, have the same structure
+    /// (parts, rings, patches) and all their coordinates are within `epsilon`
+    ///
+    /// Unlike `==`, this is tolerant to small floating point differences.
+    /// `m` values that are both _no data_ are considered equal.
+    /// Bounding boxes are not compared as they are computed from the points.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use shapefile::{Point, Shape};
+    /// let a = Shape::Point(Point::new(1.0, 1.0));
+    /// let b = Shape::Point(Point::new(1.0 + 1e-9, 1.0));
+    /// assert!(a != b);
+    /// assert!(a.approx_eq(&b, 1e-6));
+    /// ```
+    pub fn approx_eq(&self, other: &Shape, epsilon: f64) -> bool {
+        let point_eq: fn(&Point, &Point, f64) -> bool = Point::approx_eq;
+        let point_m_eq: fn(&PointM, &PointM, f64) -> bool = PointM::approx_eq;
+        let point_z_eq: fn(&PointZ, &PointZ, f64) -> bool = PointZ::approx_eq;
+        fn points_eq<P>(a: &[P], b: &[P], epsilon: f64, eq: fn(&P, &P, f64) -> bool) -> bool {
+            a.len() == b.len() && a.iter().zip(b).all(|(pa, pb)| eq(pa, pb, epsilon))
+        }
+        fn parts_eq<P>(
+            a: &[Vec<P>],
+            b: &[Vec<P>],
+            epsilon: f64,
+            eq: fn(&P, &P, f64) -> bool,
+        ) -> bool {
+            a.len() == b.len()
+                && a.iter()
+                    .zip(b)
+                    .all(|(pa, pb)| points_eq(pa, pb, epsilon, eq))
+        }
+        fn rings_eq<P>(
+            a: &[PolygonRing<P>],
+            b: &[PolygonRing<P>],
+            epsilon: f64,
+            eq: fn(&P, &P, f64) -> bool,
+        ) -> bool {
+            a.len() == b.len()
+                && a.iter().zip(b).all(|(ra, rb)| {
+                    std::mem::discriminant(ra) == std::mem::discriminant(rb)
+                        && points_eq(ra.points(), rb.points(), epsilon, eq)
+                })
+        }
+
+        match (self, other) {
+            (Shape::NullShape, Shape::NullShape) => true,
+            (Shape::Point(a), Shape::Point(b)) => point_eq(a, b, epsilon),
+            (Shape::PointM(a), Shape::PointM(b)) => point_m_eq(a, b, epsilon),
+            (Shape::PointZ(a), Shape::PointZ(b)) => point_z_eq(a, b, epsilon),
+            (Shape::Polyline(a), Shape::Polyline(b)) => {
+                parts_eq(a.parts(), b.parts(), epsilon, point_eq)
+            }
+            (Shape::PolylineM(a), Shape::PolylineM(b)) => {
+                parts_eq(a.parts(), b.parts(), epsilon, point_m_eq)
+            }
+            (Shape::PolylineZ(a), Shape::PolylineZ(b)) => {
+                parts_eq(a.parts(), b.parts(), epsilon, point_z_eq)
+            }
+            (Shape::Polygon(a), Shape::Polygon(b)) => {
+                rings_eq(a.rings(), b.rings(), epsilon, point_eq)
+            }
+            (Shape::PolygonM(a), Shape::PolygonM(b)) => {
+                rings_eq(a.rings(), b.rings(), epsilon, point_m_eq)
+            }
+            (Shape::PolygonZ(a), Shape::PolygonZ(b)) => {
+                rings_eq(a.rings(), b.rings(), epsilon, point_z_eq)
+            }
+            (Shape::Multipoint(a), Shape::Multipoint(b)) => {
+                points_eq(a.points(), b.points(), epsilon, point_eq)
+            }
+            (Shape::MultipointM(a), Shape::MultipointM(b)) => {
+                points_eq(a.points(), b.points(), epsilon, point_m_eq)
+            }
+            (Shape::MultipointZ(a), Shape::MultipointZ(b)) => {
+                points_eq(a.points(), b.points(), epsilon, point_z_eq)
+            }
+            (Shape::Multipatch(a), Shape::Multipatch(b)) => {
+                a.patches().len() == b.patches().len()
+                    && a.patches().iter().zip(b.patches()).all(|(pa, pb)| {
+                        pa.patch_type() == pb.patch_type()
+                            && points_eq(pa.points(), pb.points(), epsilon, point_z_eq)
+                    })
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the shape as the `d` attribute of a SVG path
+    ///
+    /// Only the x and y coordinates are used.
+    ///
+    /// - Each part of polylines is a `M x y L x y ...` sub path
+    /// - Each ring of polygons and each patch of multipatches is a sub path closed by `Z`
+    /// - Points are zero length sub paths (`M x y h 0`), visible when the path is
+    ///   drawn with `stroke-linecap="round"`
+    ///
+    /// A `NullShape` gives an empty string.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use shapefile::{Point, Polyline, Shape};
+    /// let shape = Shape::Polyline(Polyline::new(vec![
+    ///     Point::new(1.0, 1.0),
+    ///     Point::new(2.5, 3.0),
+    /// ]));
+    /// assert_eq!(shape.to_svg_path(), "M 1 1 L 2.5 3");
+    /// ```
+    pub fn to_svg_path(&self) -> String {
+        fn sub_path<P: HasXY + PartialEq>(points: &[P], closed: bool) -> String {
+            let points = if closed && is_part_closed(points) && points.len() > 1 {
+                &points[..points.len() - 1]
+            } else {
+                points
+            };
+            let mut path = String::new();
+            for (i, point) in points.iter().enumerate() {
+                let command = if i == 0 { "M" } else { " L" };
+                path.push_str(&format!("{} {} {}", command, point.x(), point.y()));
+            }
+            if closed && !path.is_empty() {
+                path.push_str(" Z");
+            }
+            path
+        }
+
+        fn dot<P: HasXY>(point: &P) -> String {
+            format!("M {} {} h 0", point.x(), point.y())
+        }
+
+        let sub_paths: Vec<String> = match self {
+            Shape::NullShape => vec![],
+            Shape::Point(shp) => vec![dot(shp)],
+            Shape::PointM(shp) => vec![dot(shp)],
+            Shape::PointZ(shp) => vec![dot(shp)],
+            Shape::Polyline(shp) => shp.parts().iter().map(|p| sub_path(p, false)).collect(),
+            Shape::PolylineM(shp) => shp.parts().iter().map(|p| sub_path(p, false)).collect(),
+            Shape::PolylineZ(shp) => shp.parts().iter().map(|p| sub_path(p, false)).collect(),
+            Shape::Polygon(shp) => shp
+                .file_rings()
+                .iter()
+                .map(|r| sub_path(r.points(), true))
+                .collect(),
+            Shape::PolygonM(shp) => shp
+                .file_rings()
+                .iter()
+                .map(|r| sub_path(r.points(), true))
+                .collect(),
+            Shape::PolygonZ(shp) => shp
+                .file_rings()
+                .iter()
+                .map(|r| sub_path(r.points(), true))
+                .collect(),
+            Shape::Multipoint(shp) => shp.points().iter().map(dot).collect(),
+            Shape::MultipointM(shp) => shp.points().iter().map(dot).collect(),
+            Shape::MultipointZ(shp) => shp.points().iter().map(dot).collect(),
+            Shape::Multipatch(shp) => shp
+                .patches()
+                .iter()
+                .map(|p| sub_path(p.points(), true))
+                .collect(),
+        };
+        sub_paths.join(" ")
+    }
+
+    /// Returns the x and y coordinates of all the points of the shape
+    fn xy_points(&self) -> Vec<Point> {
+        let mut points = Vec::with_capacity(self.point_count());
+        self.for_each_coord(|x, y, _, _| points.push(Point::new(x, y)));
+        points
+    }
 }
 
 impl fmt::Display for Shape {
@@ -283,6 +1210,43 @@ impl fmt::Display for Shape {
     }
 }
 
+fn map_polyline_points<P, Q>(polyline: GenericPolyline<P>, f: fn(P) -> Q) -> GenericPolyline<Q>
+where
+    Q: ShrinkablePoint + GrowablePoint + Copy,
+{
+    let parts = polyline
+        .into_inner()
+        .into_iter()
+        .map(|part| part.into_iter().map(f).collect())
+        .collect();
+    GenericPolyline::with_parts(parts)
+}
+
+fn map_polygon_points<P, Q>(polygon: GenericPolygon<P>, f: fn(P) -> Q) -> GenericPolygon<Q>
+where
+    Q: ShrinkablePoint + GrowablePoint + PartialEq + HasXY + Copy,
+{
+    let rings = polygon
+        .into_inner()
+        .into_iter()
+        .map(|ring| match ring {
+            PolygonRing::Outer(points) => PolygonRing::Outer(points.into_iter().map(f).collect()),
+            PolygonRing::Inner(points) => PolygonRing::Inner(points.into_iter().map(f).collect()),
+        })
+        .collect();
+    GenericPolygon::with_rings(rings)
+}
+
+fn map_multipoint_points<P, Q>(
+    multipoint: GenericMultipoint<P>,
+    f: fn(P) -> Q,
+) -> GenericMultipoint<Q>
+where
+    Q: ShrinkablePoint + GrowablePoint + Copy,
+{
+    GenericMultipoint::new(multipoint.into_inner().into_iter().map(f).collect())
+}
+
 /// Header of a shape record, present before any shape record
 #[derive(Debug, Copy, Clone)]
 pub(crate) struct RecordHeader {
@@ -398,6 +1362,7 @@ macro_rules! impl_to_way_conversion {
 }
 
 impl_concrete_shape_for!(Point);
+impl_concrete_shape_for!(PointF32);
 impl_concrete_shape_for!(PointM);
 impl_concrete_shape_for!(PointZ);
 impl_concrete_shape_for!(Polyline);
@@ -553,6 +1518,185 @@ mod tests {
         assert!(convert_shapes_to_vec_of::<Point>(shapes).is_ok());
     }
 
+    #[test]
+    fn shape_point_count() {
+        let shape = Shape::from(Polyline::with_parts(vec![
+            vec![Point::new(1.0, 1.0), Point::new(2.0, 2.0)],
+            vec![
+                Point::new(3.0, 3.0),
+                Point::new(4.0, 4.0),
+                Point::new(5.0, 5.0),
+            ],
+        ]));
+        assert_eq!(shape.point_count(), 5);
+    }
+
+    #[test]
+    fn shape_convex_hull() {
+        let shape = Shape::from(Multipoint::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(4.0, 0.0),
+            Point::new(2.0, 3.0),
+            Point::new(4.0, 4.0),
+            Point::new(2.0, 0.0),
+            Point::new(0.0, 4.0),
+            Point::new(3.0, 1.5),
+        ]));
+        let hull = shape.convex_hull().unwrap();
+        assert_eq!(hull.rings().len(), 1);
+        let ring = &hull.rings()[0];
+        assert!(matches!(ring, PolygonRing::Outer(_)));
+        assert_eq!(ring.len(), 5);
+        assert_eq!(ring.points()[0], ring.points()[4]);
+        for corner in &[
+            Point::new(0.0, 0.0),
+            Point::new(4.0, 0.0),
+            Point::new(4.0, 4.0),
+            Point::new(0.0, 4.0),
+        ] {
+            assert!(ring.points().contains(corner));
+        }
+        assert_eq!(hull.bbox().min, Point::new(0.0, 0.0));
+        assert_eq!(hull.bbox().max, Point::new(4.0, 4.0));
+
+        let collinear = Shape::from(Polyline::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(2.0, 2.0),
+        ]));
+        assert!(collinear.convex_hull().is_none());
+        assert!(Shape::NullShape.convex_hull().is_none());
+    }
+
+    #[test]
+    fn shape_round_coordinates() {
+        let mut shape = Shape::PointM(PointM::new(1.23456789, 9.87654321, NO_DATA));
+        shape.round_coordinates(2);
+        match shape {
+            Shape::PointM(point) => {
+                assert_eq!(point.x, 1.23);
+                assert_eq!(point.y, 9.88);
+                assert_eq!(point.m, NO_DATA);
+            }
+            _ => panic!("Expected a PointM"),
+        }
+
+        let mut shape = Shape::from(Polyline::new(vec![
+            Point::new(0.123456, 0.654321),
+            Point::new(2.987654, 3.011111),
+        ]));
+        shape.round_coordinates(1);
+        match shape {
+            Shape::Polyline(polyline) => {
+                assert_eq!(polyline.parts()[0][1], Point::new(3.0, 3.0));
+                assert_eq!(polyline.bbox().min, Point::new(0.1, 0.7));
+                assert_eq!(polyline.bbox().max, Point::new(3.0, 3.0));
+            }
+            _ => panic!("Expected a Polyline"),
+        }
+    }
+
+    #[test]
+    fn shape_for_each_coord() {
+        let shape = Shape::from(PolygonZ::with_rings(vec![
+            PolygonRing::Outer(vec![
+                PointZ::new(0.0, 0.0, 1.0, NO_DATA),
+                PointZ::new(0.0, 4.0, 2.0, 7.0),
+                PointZ::new(4.0, 4.0, 3.0, NO_DATA),
+                PointZ::new(4.0, 0.0, 4.0, NO_DATA),
+            ]),
+            PolygonRing::Inner(vec![
+                PointZ::new(1.0, 1.0, 0.0, NO_DATA),
+                PointZ::new(2.0, 1.0, 0.0, NO_DATA),
+                PointZ::new(2.0, 2.0, 0.0, NO_DATA),
+                PointZ::new(1.0, 2.0, 0.0, NO_DATA),
+            ]),
+        ]));
+
+        let mut count = 0;
+        let mut z_count = 0;
+        let mut measures = vec![];
+        shape.for_each_coord(|_x, _y, z, m| {
+            count += 1;
+            if z.is_some() {
+                z_count += 1;
+            }
+            if let Some(m) = m {
+                measures.push(m);
+            }
+        });
+        assert_eq!(count, shape.point_count());
+        assert_eq!(count, 10);
+        assert_eq!(z_count, 10);
+        assert_eq!(measures, vec![7.0]);
+    }
+
+    #[test]
+    fn shape_to_svg_path() {
+        let triangle = Shape::from(Polygon::new(PolygonRing::Outer(vec![
+            Point::new(0.0, 0.0),
+            Point::new(0.0, 1.0),
+            Point::new(1.0, 0.0),
+        ])));
+        assert_eq!(triangle.to_svg_path(), "M 0 0 L 0 1 L 1 0 Z");
+
+        let multipoint = Shape::from(Multipoint::new(vec![
+            Point::new(1.0, 2.0),
+            Point::new(3.5, 4.0),
+        ]));
+        assert_eq!(multipoint.to_svg_path(), "M 1 2 h 0 M 3.5 4 h 0");
+        assert_eq!(Shape::NullShape.to_svg_path(), "");
+    }
+
+    #[test]
+    fn shape_approx_eq() {
+        let a = Shape::from(Polyline::new(vec![
+            Point::new(1.0, 1.0),
+            Point::new(2.0, 2.0),
+        ]));
+        let b = Shape::from(Polyline::new(vec![
+            Point::new(1.0, 1.0 + 1e-12),
+            Point::new(2.0, 2.0),
+        ]));
+        assert_ne!(a, b);
+        assert!(a.approx_eq(&b, 1e-9));
+        assert!(!a.approx_eq(&b, 1e-13));
+
+        let c = Shape::from(Polyline::with_parts(vec![
+            vec![Point::new(1.0, 1.0), Point::new(2.0, 2.0)],
+            vec![Point::new(1.0, 1.0), Point::new(2.0, 2.0)],
+        ]));
+        assert!(!a.approx_eq(&c, 1e-9));
+        assert!(!a.approx_eq(&Shape::NullShape, 1e-9));
+        assert_eq!(a, a);
+    }
+
+    #[test]
+    fn close_points_with_epsilon() {
+        let mut points = vec![
+            Point::new(0.0, 0.0),
+            Point::new(0.0, 1.0),
+            Point::new(1.0, 1.0),
+            Point::new(1e-9, -1e-9),
+        ];
+        assert!(!is_part_closed(&points));
+        assert!(is_part_closed_eps(&points, 1e-6));
+        close_points_if_not_already_eps(&mut points, 1e-6);
+        assert_eq!(points.len(), 4);
+
+        assert!(!is_part_closed_eps(&points, 1e-12));
+        close_points_if_not_already_eps(&mut points, 1e-12);
+        assert_eq!(points.len(), 5);
+        assert_eq!(points[4], points[0]);
+
+        let a = PointZ::new(1.0, 2.0, 3.0, NO_DATA);
+        assert!(a.approx_eq(&PointZ::new(1.0, 2.0, 3.0 + 1e-9, NO_DATA), 1e-6));
+        assert!(!a.approx_eq(&PointZ::new(1.0, 2.0, 3.0, 4.0), 1e-6));
+        let b = PointM::new(1.0, 2.0, 3.0);
+        assert!(b.approx_eq(&PointM::new(1.0, 2.0 - 1e-9, 3.0), 1e-6));
+    }
+
     #[test]
     fn test_vertices_order() {
         let mut points = vec![
@@ -566,4 +1710,107 @@ mod tests {
         points.reverse();
         assert_eq!(ring_type_from_points_ordering(&points), RingType::OuterRing);
     }
+
+    #[test]
+    fn point_to_z_and_back() {
+        let point = Point::new(3.0, -4.5);
+        let shape = Shape::Point(point).to_z();
+        match shape {
+            Shape::PointZ(ref point_z) => {
+                assert_eq!(*point_z, PointZ::new(3.0, -4.5, 0.0, NO_DATA));
+            }
+            _ => panic!("Expected a PointZ, got {}", shape),
+        }
+        assert_eq!(shape.to_2d(), Shape::Point(point));
+    }
+
+    #[test]
+    fn polygon_m_to_z_keeps_measures() {
+        let polygon = PolygonM::new(PolygonRing::Outer(vec![
+            PointM::new(0.0, 0.0, 1.0),
+            PointM::new(0.0, 1.0, 2.0),
+            PointM::new(1.0, 1.0, 3.0),
+            PointM::new(0.0, 0.0, 1.0),
+        ]));
+        let shape = Shape::PolygonM(polygon).to_z();
+        let polygon_z = shape.as_polygon_z().unwrap();
+        assert_eq!(polygon_z.rings().len(), 1);
+        assert_eq!(
+            polygon_z.rings()[0].points()[1],
+            PointZ::new(0.0, 1.0, 0.0, 2.0)
+        );
+        assert_eq!(polygon_z.bbox().m_range(), [1.0, 3.0]);
+
+        let shape = shape.to_2d();
+        assert_eq!(shape.shapetype(), ShapeType::Polygon);
+        assert_eq!(shape.to_z().to_2d().point_count(), 4);
+        assert_eq!(Shape::NullShape.to_z(), Shape::NullShape);
+    }
+
+    #[test]
+    fn bbox_center_distance_of_points() {
+        let a = Shape::Point(Point::new(1.0, 1.0));
+        let b = Shape::PointZ(PointZ::new(4.0, 5.0, 10.0, NO_DATA));
+        assert_eq!(a.bbox_center_distance(&b), Some(5.0));
+        assert_eq!(b.bbox_center_distance(&a), Some(5.0));
+        assert_eq!(Shape::NullShape.bbox_center_distance(&a), None);
+
+        let polyline = Shape::Polyline(Polyline::new(vec![
+            Point::new(-4.0, 0.0),
+            Point::new(4.0, 0.0),
+        ]));
+        let point = Shape::Point(Point::new(0.0, 5.0));
+        assert_eq!(polyline.bbox_center_distance(&point), Some(5.0));
+    }
+
+    #[test]
+    fn explode_multipoint() {
+        let points = vec![
+            PointZ::new(1.0, 2.0, 3.0, 4.0),
+            PointZ::new(5.0, 6.0, 7.0, 8.0),
+            PointZ::new(9.0, 10.0, 11.0, 12.0),
+        ];
+        let shapes = Shape::MultipointZ(MultipointZ::new(points.clone())).explode();
+        assert_eq!(
+            shapes,
+            points.into_iter().map(Shape::PointZ).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn explode_polyline() {
+        let polyline = Polyline::with_parts(vec![
+            vec![Point::new(0.0, 0.0), Point::new(1.0, 1.0)],
+            vec![
+                Point::new(5.0, 5.0),
+                Point::new(6.0, 4.0),
+                Point::new(7.0, 7.0),
+            ],
+        ]);
+        let shapes = Shape::Polyline(polyline).explode();
+        assert_eq!(shapes.len(), 2);
+        let second = shapes[1].as_polyline().unwrap();
+        assert_eq!(second.parts().len(), 1);
+        assert_eq!(second.parts()[0].len(), 3);
+        assert_eq!(second.bbox().min, Point::new(5.0, 4.0));
+
+        let point = Point::new(1.0, 1.0);
+        assert_eq!(Shape::Point(point).explode(), vec![Shape::Point(point)]);
+    }
+
+    #[test]
+    fn failed_try_into_gives_back_the_shape() {
+        let polygon = Polygon::new(PolygonRing::Outer(vec![
+            Point::new(0.0, 0.0),
+            Point::new(0.0, 1.0),
+            Point::new(1.0, 1.0),
+            Point::new(0.0, 0.0),
+        ]));
+        let shape = Shape::Polygon(polygon.clone());
+
+        let shape = shape.try_into_polyline().unwrap_err();
+        assert_eq!(shape, Shape::Polygon(polygon.clone()));
+        let shape = shape.try_into_multipatch().unwrap_err();
+        assert_eq!(shape.try_into_polygon().unwrap(), polygon);
+    }
 }
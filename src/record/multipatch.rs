@@ -1,4 +1,5 @@
 //! Module for the Multipatch shape
+use crate::reader::ReadOptions;
 use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
 
 use std::fmt;
@@ -10,14 +11,18 @@ use super::ConcreteReadableShape;
 use super::{close_points_if_not_already, GenericBBox};
 use super::{Error, ShapeType};
 use super::{EsriShape, HasShapeType, Point, PointZ, WritableShape};
+use super::{PolygonRing, PolygonZ};
 
 #[cfg(feature = "geo-types")]
 use geo_types;
 #[cfg(feature = "geo-types")]
 use std::convert::TryFrom;
 
+/// The type of a [`Patch`], as stored in the file
+///
+/// [`Patch`]: enum.Patch.html
 #[derive(Debug, Copy, Clone, PartialEq)]
-enum PatchType {
+pub enum PatchType {
     TriangleStrip,
     TriangleFan,
     OuterRing,
@@ -27,11 +32,20 @@ enum PatchType {
 }
 
 impl PatchType {
-    pub fn read_from<T: Read>(source: &mut T) -> Result<PatchType, Error> {
+    pub(crate) fn read_from<T: Read>(source: &mut T) -> Result<PatchType, Error> {
         let code = source.read_i32::<LittleEndian>()?;
         Self::from(code).ok_or_else(|| Error::InvalidPatchType(code))
     }
 
+    /// Returns the PatchType corresponding to the input code
+    /// if the code is valid
+    ///
+    /// ```
+    /// use shapefile::PatchType;
+    ///
+    /// assert_eq!(PatchType::from(2), Some(PatchType::OuterRing));
+    /// assert_eq!(PatchType::from(6), None);
+    /// ```
     pub fn from(code: i32) -> Option<PatchType> {
         match code {
             0 => Some(PatchType::TriangleStrip),
@@ -43,6 +57,24 @@ impl PatchType {
             _ => None,
         }
     }
+
+    /// Returns the code of the PatchType
+    ///
+    /// ```
+    /// use shapefile::PatchType;
+    ///
+    /// assert_eq!(PatchType::InnerRing.to_code(), 3);
+    /// ```
+    pub fn to_code(self) -> i32 {
+        match self {
+            PatchType::TriangleStrip => 0,
+            PatchType::TriangleFan => 1,
+            PatchType::OuterRing => 2,
+            PatchType::InnerRing => 3,
+            PatchType::FirstRing => 4,
+            PatchType::Ring => 5,
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -71,6 +103,78 @@ pub enum Patch {
 }
 
 impl Patch {
+    /// Creates a [`Patch::TriangleStrip`]
+    ///
+    /// [`Patch::TriangleStrip`]: enum.Patch.html#variant.TriangleStrip
+    pub fn triangle_strip(points: Vec<PointZ>) -> Self {
+        Patch::TriangleStrip(points)
+    }
+
+    /// Creates a [`Patch::TriangleFan`]
+    ///
+    /// [`Patch::TriangleFan`]: enum.Patch.html#variant.TriangleFan
+    pub fn triangle_fan(points: Vec<PointZ>) -> Self {
+        Patch::TriangleFan(points)
+    }
+
+    /// Creates a [`Patch::OuterRing`], closing the ring if it is not
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use shapefile::{Patch, PointZ, NO_DATA};
+    /// let patch = Patch::outer_ring(vec![
+    ///     PointZ::new(0.0, 0.0, 0.0, NO_DATA),
+    ///     PointZ::new(0.0, 1.0, 0.0, NO_DATA),
+    ///     PointZ::new(1.0, 1.0, 0.0, NO_DATA),
+    /// ]);
+    /// assert_eq!(patch.points().len(), 4);
+    /// assert_eq!(patch.points().first(), patch.points().last());
+    /// ```
+    ///
+    /// [`Patch::OuterRing`]: enum.Patch.html#variant.OuterRing
+    pub fn outer_ring(points: Vec<PointZ>) -> Self {
+        Patch::OuterRing(points).closed()
+    }
+
+    /// Creates a [`Patch::InnerRing`], closing the ring if it is not
+    ///
+    /// [`Patch::InnerRing`]: enum.Patch.html#variant.InnerRing
+    pub fn inner_ring(points: Vec<PointZ>) -> Self {
+        Patch::InnerRing(points).closed()
+    }
+
+    /// Creates a [`Patch::FirstRing`], closing the ring if it is not
+    ///
+    /// [`Patch::FirstRing`]: enum.Patch.html#variant.FirstRing
+    pub fn first_ring(points: Vec<PointZ>) -> Self {
+        Patch::FirstRing(points).closed()
+    }
+
+    /// Creates a [`Patch::Ring`], closing the ring if it is not
+    ///
+    /// [`Patch::Ring`]: enum.Patch.html#variant.Ring
+    pub fn ring(points: Vec<PointZ>) -> Self {
+        Patch::Ring(points).closed()
+    }
+
+    /// Closes the patch if it is a ring type
+    fn close_if_ring(&mut self) {
+        match self {
+            Patch::TriangleStrip(_) => {}
+            Patch::TriangleFan(_) => {}
+            Patch::OuterRing(points) => close_points_if_not_already(points),
+            Patch::InnerRing(points) => close_points_if_not_already(points),
+            Patch::FirstRing(points) => close_points_if_not_already(points),
+            Patch::Ring(points) => close_points_if_not_already(points),
+        }
+    }
+
+    fn closed(mut self) -> Self {
+        self.close_if_ring();
+        self
+    }
+
     /// Returns the slice of points contained within the patch
     #[inline]
     pub fn points(&self) -> &[PointZ] {
@@ -83,6 +187,39 @@ impl Patch {
             Patch::Ring(points) => points,
         }
     }
+
+    #[inline]
+    fn points_vec_mut(&mut self) -> &mut Vec<PointZ> {
+        match self {
+            Patch::TriangleStrip(points) => points,
+            Patch::TriangleFan(points) => points,
+            Patch::OuterRing(points) => points,
+            Patch::InnerRing(points) => points,
+            Patch::FirstRing(points) => points,
+            Patch::Ring(points) => points,
+        }
+    }
+
+    /// Returns the type of the patch
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use shapefile::{Patch, PatchType};
+    /// let patch = Patch::OuterRing(vec![]);
+    /// assert_eq!(patch.patch_type(), PatchType::OuterRing);
+    /// ```
+    #[inline]
+    pub fn patch_type(&self) -> PatchType {
+        match self {
+            Patch::TriangleStrip(_) => PatchType::TriangleStrip,
+            Patch::TriangleFan(_) => PatchType::TriangleFan,
+            Patch::OuterRing(_) => PatchType::OuterRing,
+            Patch::InnerRing(_) => PatchType::InnerRing,
+            Patch::FirstRing(_) => PatchType::FirstRing,
+            Patch::Ring(_) => PatchType::Ring,
+        }
+    }
 }
 
 impl AsRef<[PointZ]> for Patch {
@@ -103,7 +240,9 @@ impl AsRef<[PointZ]> for Patch {
 ///
 /// [`TriangleStrip`]: enum.Patch.html#variant.TriangleStrip
 /// [`TriangleFan`]: enum.Patch.html#variant.TriangleFan
-#[derive(Debug, PartialEq, Clone)]
+///
+/// The [`Default`] multipatch has no patches and a default (zeroed) bounding box.
+#[derive(Debug, Default, PartialEq, Clone)]
 pub struct Multipatch {
     bbox: GenericBBox<PointZ>,
     patches: Vec<Patch>,
@@ -154,16 +293,7 @@ impl Multipatch {
     /// ]);
     /// ```
     pub fn with_parts(mut patches: Vec<Patch>) -> Self {
-        for patch in patches.iter_mut() {
-            match patch {
-                Patch::TriangleStrip(_) => {}
-                Patch::TriangleFan(_) => {}
-                Patch::OuterRing(points) => close_points_if_not_already(points),
-                Patch::InnerRing(points) => close_points_if_not_already(points),
-                Patch::FirstRing(points) => close_points_if_not_already(points),
-                Patch::Ring(points) => close_points_if_not_already(points),
-            }
-        }
+        patches.iter_mut().for_each(Patch::close_if_ring);
         let mut bbox = GenericBBox::<PointZ>::from_points(patches[0].points());
         for patch in &patches[1..] {
             bbox.grow_from_points(patch.points());
@@ -172,6 +302,18 @@ impl Multipatch {
         Self { bbox, patches }
     }
 
+    /// Applies `f` on each point and recomputes the bounding box
+    pub(crate) fn for_each_point_mut<F: FnMut(&mut PointZ)>(&mut self, mut f: F) {
+        for patch in self.patches.iter_mut() {
+            patch.points_vec_mut().iter_mut().for_each(&mut f);
+        }
+        if let Some(bbox) =
+            GenericBBox::from_points_iter(self.patches.iter().flat_map(|patch| patch.points()))
+        {
+            self.bbox = bbox;
+        }
+    }
+
     /// Returns the bounding box of the points contained in this multipatch
     #[inline]
     pub fn bbox(&self) -> &GenericBBox<PointZ> {
@@ -201,6 +343,130 @@ impl Multipatch {
         self.patches.iter().map(|patch| patch.points().len()).sum()
     }
 
+    /// Groups the indices of the ring patches into the polygons they form
+    ///
+    /// Following the specification:
+    /// - A `FirstRing` starts a group, the `Ring`s that follow it join the group
+    /// - An `OuterRing` starts a group, the `InnerRing`s that follow it join the group
+    /// - A `Ring` not preceded by a `FirstRing` is an outer ring without holes,
+    ///   so it is alone in its group, and the same goes for an `InnerRing`
+    ///   not preceded by an `OuterRing`
+    ///
+    /// `TriangleStrip` and `TriangleFan` patches are not part of any group.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use shapefile::{Multipatch, Patch, PointZ, NO_DATA};
+    /// let square = vec![
+    ///     PointZ::new(0.0, 0.0, 0.0, NO_DATA),
+    ///     PointZ::new(0.0, 1.0, 0.0, NO_DATA),
+    ///     PointZ::new(1.0, 1.0, 0.0, NO_DATA),
+    ///     PointZ::new(0.0, 0.0, 0.0, NO_DATA),
+    /// ];
+    /// let multipatch = Multipatch::with_parts(vec![
+    ///     Patch::OuterRing(square.clone()),
+    ///     Patch::InnerRing(square.clone()),
+    ///     Patch::TriangleFan(square.clone()),
+    ///     Patch::OuterRing(square),
+    /// ]);
+    /// assert_eq!(multipatch.ring_groups(), vec![vec![0, 1], vec![3]]);
+    /// ```
+    pub fn ring_groups(&self) -> Vec<Vec<usize>> {
+        let mut groups = Vec::<Vec<usize>>::new();
+        // The type of the patch that started the current group, if any
+        let mut current_group_start = None;
+        for (index, patch) in self.patches.iter().enumerate() {
+            match (patch, current_group_start) {
+                (Patch::TriangleStrip(_), _) | (Patch::TriangleFan(_), _) => {
+                    current_group_start = None;
+                }
+                (Patch::Ring(_), Some(PatchType::FirstRing))
+                | (Patch::InnerRing(_), Some(PatchType::OuterRing)) => {
+                    if let Some(group) = groups.last_mut() {
+                        group.push(index);
+                    }
+                }
+                (Patch::FirstRing(_), _) => {
+                    groups.push(vec![index]);
+                    current_group_start = Some(PatchType::FirstRing);
+                }
+                (Patch::OuterRing(_), _) => {
+                    groups.push(vec![index]);
+                    current_group_start = Some(PatchType::OuterRing);
+                }
+                (Patch::Ring(_), _) | (Patch::InnerRing(_), _) => {
+                    groups.push(vec![index]);
+                    current_group_start = None;
+                }
+            }
+        }
+        groups
+    }
+
+    /// Converts the Multipatch into a PolygonZ
+    ///
+    /// - `OuterRing` patches become [`Outer`] rings
+    /// - `InnerRing` patches become [`Inner`] rings
+    /// - `FirstRing` and `Ring` patches, whose type is not known, become [`Outer`] rings
+    ///
+    /// As with the other polygon constructors, the points of the rings are reordered
+    /// to match their ring type.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::GeometryConversion`] if the multipatch
+    /// contains a `TriangleStrip` or `TriangleFan` patch.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), shapefile::Error> {
+    /// use shapefile::{PointZ, Multipatch, NO_DATA, Patch, PolygonRing};
+    /// let multipatch = Multipatch::new(Patch::OuterRing(vec![
+    ///     PointZ::new(0.0, 0.0, 0.0, NO_DATA),
+    ///     PointZ::new(0.0, 4.0, 0.0, NO_DATA),
+    ///     PointZ::new(4.0, 4.0, 0.0, NO_DATA),
+    ///     PointZ::new(4.0, 0.0, 0.0, NO_DATA),
+    /// ]));
+    /// let polygon = multipatch.into_polygon_z()?;
+    /// assert!(matches!(polygon.rings()[0], PolygonRing::Outer(_)));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Outer`]: ../polygon/enum.PolygonRing.html#variant.Outer
+    /// [`Inner`]: ../polygon/enum.PolygonRing.html#variant.Inner
+    pub fn into_polygon_z(self) -> Result<PolygonZ, Error> {
+        let mut rings = Vec::<PolygonRing<PointZ>>::with_capacity(self.patches.len());
+        for patch in self.patches {
+            let ring = match patch {
+                Patch::TriangleStrip(_) => {
+                    return Err(Error::GeometryConversion(
+                        "Cannot convert Multipatch::TriangleStrip to PolygonZ",
+                    ))
+                }
+                Patch::TriangleFan(_) => {
+                    return Err(Error::GeometryConversion(
+                        "Cannot convert Multipatch::TriangleFan to PolygonZ",
+                    ))
+                }
+                Patch::OuterRing(points) | Patch::FirstRing(points) | Patch::Ring(points) => {
+                    PolygonRing::Outer(points)
+                }
+                Patch::InnerRing(points) => PolygonRing::Inner(points),
+            };
+            rings.push(ring);
+        }
+
+        if rings.is_empty() {
+            return Err(Error::GeometryConversion(
+                "Cannot convert a Multipatch without patches to PolygonZ",
+            ));
+        }
+        Ok(PolygonZ::with_rings(rings))
+    }
+
     pub(crate) fn size_of_record(num_points: i32, num_parts: i32, is_m_used: bool) -> usize {
         let mut size = 0usize;
         size += 4 * size_of::<f64>(); // BBOX
@@ -234,7 +500,15 @@ impl HasShapeType for Multipatch {
 
 impl ConcreteReadableShape for Multipatch {
     fn read_shape_content<T: Read>(source: &mut T, record_size: i32) -> Result<Self, Error> {
-        let reader = MultiPartShapeReader::<PointZ, T>::new(source)?;
+        Self::read_shape_content_with_options(source, record_size, ReadOptions::default())
+    }
+
+    fn read_shape_content_with_options<T: Read>(
+        source: &mut T,
+        record_size: i32,
+        options: ReadOptions,
+    ) -> Result<Self, Error> {
+        let reader = MultiPartShapeReader::<PointZ, T>::new(source, record_size)?;
 
         let record_size_with_m =
             Self::size_of_record(reader.num_points, reader.num_parts, true) as i32;
@@ -252,7 +526,9 @@ impl ConcreteReadableShape for Multipatch {
             let (bbox, patches_points) = reader
                 .read_xy()
                 .and_then(|rdr| rdr.read_zs())
-                .and_then(|rdr| rdr.read_ms_if(record_size == record_size_with_m))
+                .and_then(|rdr| {
+                    rdr.read_ms_if(record_size == record_size_with_m, options.no_data_threshold)
+                })
                 .map_err(Error::IoError)
                 .map(|rdr| (rdr.bbox, rdr.parts))?;
 
@@ -296,14 +572,8 @@ impl WritableShape for Multipatch {
             .and_then(|wrt| wrt.write_parts_array())
             .and_then(|wrt| {
                 for patch in self.patches.iter() {
-                    match patch {
-                        Patch::TriangleStrip(_) => wrt.dst.write_i32::<LittleEndian>(0)?,
-                        Patch::TriangleFan(_) => wrt.dst.write_i32::<LittleEndian>(1)?,
-                        Patch::OuterRing(_) => wrt.dst.write_i32::<LittleEndian>(2)?,
-                        Patch::InnerRing(_) => wrt.dst.write_i32::<LittleEndian>(3)?,
-                        Patch::FirstRing(_) => wrt.dst.write_i32::<LittleEndian>(4)?,
-                        Patch::Ring(_) => wrt.dst.write_i32::<LittleEndian>(5)?,
-                    }
+                    wrt.dst
+                        .write_i32::<LittleEndian>(patch.patch_type().to_code())?;
                 }
                 Ok(wrt)
             })
@@ -318,6 +588,10 @@ impl WritableShape for Multipatch {
 }
 
 impl EsriShape for Multipatch {
+    fn has_finite_coords(&self) -> bool {
+        super::are_xy_finite(self.patches.iter().flat_map(|patch| patch.points()))
+    }
+
     fn x_range(&self) -> [f64; 2] {
         self.bbox.x_range()
     }
@@ -397,3 +671,120 @@ impl TryFrom<Multipatch> for geo_types::MultiPolygon<f64> {
         Ok(polygons.into())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::NO_DATA;
+
+    #[test]
+    fn test_patch_constructors_close_rings() {
+        let points = vec![
+            PointZ::new(0.0, 0.0, 1.0, NO_DATA),
+            PointZ::new(0.0, 2.0, 1.0, NO_DATA),
+            PointZ::new(2.0, 2.0, 1.0, NO_DATA),
+        ];
+
+        let patch = Patch::outer_ring(points.clone());
+        assert_eq!(patch.patch_type(), PatchType::OuterRing);
+        assert_eq!(patch.points().len(), 4);
+        assert_eq!(patch.points()[3], points[0]);
+
+        // Already closed rings are not closed a second time
+        let patch = Patch::inner_ring(patch.points().to_vec());
+        assert_eq!(patch.points().len(), 4);
+
+        let patch = Patch::triangle_fan(points.clone());
+        assert_eq!(patch.points(), points.as_slice());
+    }
+
+    #[test]
+    fn default_multipatch_is_empty() {
+        let multipatch = Multipatch::default();
+        assert!(multipatch.patches().is_empty());
+        assert_eq!(multipatch.total_point_count(), 0);
+    }
+
+    #[test]
+    fn multipatch_ring_groups() {
+        let triangle = vec![
+            PointZ::new(0.0, 0.0, 1.0, NO_DATA),
+            PointZ::new(0.0, 4.0, 1.0, NO_DATA),
+            PointZ::new(4.0, 4.0, 1.0, NO_DATA),
+            PointZ::new(0.0, 0.0, 1.0, NO_DATA),
+        ];
+        let multipatch = Multipatch::with_parts(vec![
+            Patch::FirstRing(triangle.clone()),
+            Patch::Ring(triangle.clone()),
+            Patch::Ring(triangle.clone()),
+        ]);
+        assert_eq!(multipatch.ring_groups(), vec![vec![0, 1, 2]]);
+
+        let multipatch = Multipatch::with_parts(vec![
+            Patch::Ring(triangle.clone()),
+            Patch::Ring(triangle.clone()),
+            Patch::FirstRing(triangle.clone()),
+            Patch::Ring(triangle.clone()),
+            Patch::TriangleStrip(triangle.clone()),
+            Patch::Ring(triangle.clone()),
+            Patch::InnerRing(triangle),
+        ]);
+        assert_eq!(
+            multipatch.ring_groups(),
+            vec![vec![0], vec![1], vec![2, 3], vec![5], vec![6]]
+        );
+    }
+
+    #[test]
+    fn multipatch_rings_into_polygon_z() {
+        let multipatch = Multipatch::with_parts(vec![
+            Patch::OuterRing(vec![
+                PointZ::new(0.0, 0.0, 1.0, NO_DATA),
+                PointZ::new(0.0, 4.0, 1.0, NO_DATA),
+                PointZ::new(4.0, 4.0, 1.0, NO_DATA),
+                PointZ::new(4.0, 0.0, 1.0, NO_DATA),
+            ]),
+            Patch::InnerRing(vec![
+                PointZ::new(1.0, 1.0, 1.0, NO_DATA),
+                PointZ::new(2.0, 1.0, 1.0, NO_DATA),
+                PointZ::new(2.0, 2.0, 1.0, NO_DATA),
+                PointZ::new(1.0, 2.0, 1.0, NO_DATA),
+            ]),
+            Patch::FirstRing(vec![
+                PointZ::new(10.0, 10.0, 2.0, NO_DATA),
+                PointZ::new(10.0, 14.0, 2.0, NO_DATA),
+                PointZ::new(14.0, 14.0, 2.0, NO_DATA),
+                PointZ::new(14.0, 10.0, 2.0, NO_DATA),
+            ]),
+        ]);
+
+        let polygon = multipatch.into_polygon_z().unwrap();
+        assert_eq!(polygon.rings().len(), 3);
+        assert!(matches!(polygon.rings()[0], PolygonRing::Outer(_)));
+        assert!(matches!(polygon.rings()[1], PolygonRing::Inner(_)));
+        assert!(matches!(polygon.rings()[2], PolygonRing::Outer(_)));
+        assert_eq!(polygon.rings()[1].len(), 5);
+        assert_eq!(polygon.bbox().z_range(), [1.0, 2.0]);
+    }
+
+    #[test]
+    fn multipatch_with_triangles_into_polygon_z() {
+        let multipatch = Multipatch::with_parts(vec![
+            Patch::OuterRing(vec![
+                PointZ::new(0.0, 0.0, 0.0, NO_DATA),
+                PointZ::new(0.0, 4.0, 0.0, NO_DATA),
+                PointZ::new(4.0, 4.0, 0.0, NO_DATA),
+            ]),
+            Patch::TriangleFan(vec![
+                PointZ::new(0.0, 0.0, 0.0, NO_DATA),
+                PointZ::new(0.0, 4.0, 0.0, NO_DATA),
+                PointZ::new(4.0, 4.0, 0.0, NO_DATA),
+            ]),
+        ]);
+
+        assert!(matches!(
+            multipatch.into_polygon_z(),
+            Err(Error::GeometryConversion(_))
+        ));
+    }
+}
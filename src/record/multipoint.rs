@@ -3,6 +3,7 @@
 //! All three variant of Multipoint Shape (Multipoint, MultipointM, MultipointZ)
 //! are specialization of the `GenericMultipoint`
 //!
+use crate::reader::ReadOptions;
 use std::fmt;
 use std::io::{Read, Write};
 use std::mem::size_of;
@@ -12,6 +13,7 @@ use std::slice::SliceIndex;
 use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
 
 use super::io::*;
+use super::polyline::GenericPolyline;
 use super::traits::{GrowablePoint, ShrinkablePoint};
 use super::EsriShape;
 use super::{ConcreteReadableShape, GenericBBox};
@@ -60,8 +62,10 @@ use geo_types;
 /// # fn main() {}
 /// ```
 ///
+/// The [`Default`] multipoint has no points and a default (zeroed) bounding box.
+///
 /// [`new`]: #method.new
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Default, Clone, PartialEq)]
 pub struct GenericMultipoint<PointType> {
     pub(crate) bbox: GenericBBox<PointType>,
     pub(crate) points: Vec<PointType>,
@@ -106,6 +110,34 @@ impl<PointType: ShrinkablePoint + GrowablePoint + Copy> GenericMultipoint<PointT
         let bbox = GenericBBox::<PointType>::from_points(&points);
         Self { bbox, points }
     }
+
+    /// Consumes the multipoint and returns a polyline with one part
+    /// made of its points, in order
+    ///
+    /// Returns `None` if there are less than 2 points.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use shapefile::{Multipoint, Point};
+    /// let multipoint = Multipoint::new(vec![Point::new(1.0, 1.0), Point::new(2.0, 2.0)]);
+    /// let polyline = multipoint.to_polyline().unwrap();
+    /// assert_eq!(polyline.parts().len(), 1);
+    ///
+    /// let multipoint = Multipoint::new(vec![Point::new(1.0, 1.0)]);
+    /// assert!(multipoint.to_polyline().is_none());
+    /// ```
+    pub fn to_polyline(self) -> Option<GenericPolyline<PointType>> {
+        GenericPolyline::try_new(self.points).ok()
+    }
+
+    /// Applies `f` on each point and recomputes the bounding box
+    pub(crate) fn for_each_point_mut<F: FnMut(&mut PointType)>(&mut self, f: F) {
+        self.points.iter_mut().for_each(f);
+        if let Some(bbox) = GenericBBox::from_points_iter(&self.points) {
+            self.bbox = bbox;
+        }
+    }
 }
 
 impl<PointType> GenericMultipoint<PointType> {
@@ -171,6 +203,15 @@ where
     }
 }
 
+impl<PointType> From<&[PointType]> for GenericMultipoint<PointType>
+where
+    PointType: ShrinkablePoint + GrowablePoint + Copy,
+{
+    fn from(points: &[PointType]) -> Self {
+        Self::new(points.to_vec())
+    }
+}
+
 impl<PointType, I: SliceIndex<[PointType]>> Index<I> for GenericMultipoint<PointType> {
     type Output = I::Output;
 
@@ -223,6 +264,98 @@ where
     }
 }
 
+/// Builder to create a multipoint one point at a time
+///
+/// The bounding box is grown as points are pushed,
+/// so it does not need a second pass over the points like [`GenericMultipoint::new`].
+///
+/// # Example
+///
+/// ```
+/// use shapefile::{MultipointBuilder, Point};
+/// let mut builder = MultipointBuilder::new();
+/// builder.push(Point::new(1.0, 1.0));
+/// builder.push(Point::new(3.0, -1.0));
+/// let multipoint = builder.build();
+/// assert_eq!(multipoint.points().len(), 2);
+/// assert_eq!(multipoint.bbox().max, Point::new(3.0, 1.0));
+/// ```
+#[derive(Debug, Clone)]
+pub struct GenericMultipointBuilder<PointType> {
+    bbox: Option<GenericBBox<PointType>>,
+    points: Vec<PointType>,
+}
+
+impl<PointType: ShrinkablePoint + GrowablePoint + Copy> GenericMultipointBuilder<PointType> {
+    /// Creates a builder with no points
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    /// Creates a builder with room for `capacity` points
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            bbox: None,
+            points: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Adds a point
+    pub fn push(&mut self, point: PointType) {
+        match self.bbox {
+            Some(ref mut bbox) => {
+                bbox.min.shrink(&point);
+                bbox.max.grow(&point);
+            }
+            None => {
+                self.bbox = Some(GenericBBox {
+                    min: point,
+                    max: point,
+                })
+            }
+        }
+        self.points.push(point);
+    }
+
+    /// Returns the number of points pushed so far
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    /// Returns true if no points were pushed
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// Creates the multipoint
+    ///
+    /// When no points were pushed, the multipoint is the same as the [`Default`] one.
+    pub fn build(self) -> GenericMultipoint<PointType>
+    where
+        PointType: Default,
+    {
+        GenericMultipoint {
+            bbox: self.bbox.unwrap_or_default(),
+            points: self.points,
+        }
+    }
+}
+
+impl<PointType: ShrinkablePoint + GrowablePoint + Copy> Default
+    for GenericMultipointBuilder<PointType>
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Builder for [`Multipoint`]
+pub type MultipointBuilder = GenericMultipointBuilder<Point>;
+/// Builder for [`MultipointM`]
+pub type MultipointMBuilder = GenericMultipointBuilder<PointM>;
+/// Builder for [`MultipointZ`]
+pub type MultipointZBuilder = GenericMultipointBuilder<PointZ>;
+
 /*
  * Multipoint
  */
@@ -259,6 +392,9 @@ impl ConcreteReadableShape for Multipoint {
         bbox_read_xy_from(&mut bbox, source)?;
 
         let num_points = source.read_i32::<LittleEndian>()?;
+        // bbox + num_points
+        let fixed_size = 4 * size_of::<f64>() + size_of::<i32>();
+        check_counts_fit_record(record_size, fixed_size, 0, num_points)?;
         if record_size == Self::size_of_record(num_points) as i32 {
             let points = read_xy_in_vec_of::<Point, T>(source, num_points)?;
             Ok(Self { bbox, points })
@@ -289,6 +425,10 @@ impl WritableShape for Multipoint {
 }
 
 impl EsriShape for Multipoint {
+    fn has_finite_coords(&self) -> bool {
+        super::are_xy_finite(self.points.iter())
+    }
+
     fn x_range(&self) -> [f64; 2] {
         self.bbox.x_range()
     }
@@ -331,10 +471,21 @@ impl HasShapeType for MultipointM {
 
 impl ConcreteReadableShape for MultipointM {
     fn read_shape_content<T: Read>(source: &mut T, record_size: i32) -> Result<Self, Error> {
+        Self::read_shape_content_with_options(source, record_size, ReadOptions::default())
+    }
+
+    fn read_shape_content_with_options<T: Read>(
+        source: &mut T,
+        record_size: i32,
+        options: ReadOptions,
+    ) -> Result<Self, Error> {
         let mut bbox = GenericBBox::<PointM>::default();
         bbox_read_xy_from(&mut bbox, source)?;
 
         let num_points = source.read_i32::<LittleEndian>()?;
+        // bbox + num_points
+        let fixed_size = 4 * size_of::<f64>() + size_of::<i32>();
+        check_counts_fit_record(record_size, fixed_size, 0, num_points)?;
 
         let size_with_m = Self::size_of_record(num_points, true) as i32;
         let size_without_m = Self::size_of_record(num_points, false) as i32;
@@ -347,7 +498,7 @@ impl ConcreteReadableShape for MultipointM {
 
             if m_is_used {
                 bbox_read_m_range_from(&mut bbox, source)?;
-                read_ms_into(source, &mut points)?;
+                read_ms_into(source, &mut points, options.no_data_threshold)?;
             }
             Ok(Self { bbox, points })
         }
@@ -377,6 +528,10 @@ impl WritableShape for MultipointM {
 }
 
 impl EsriShape for MultipointM {
+    fn has_finite_coords(&self) -> bool {
+        super::are_xy_finite(self.points.iter())
+    }
+
     fn x_range(&self) -> [f64; 2] {
         self.bbox.x_range()
     }
@@ -388,6 +543,21 @@ impl EsriShape for MultipointM {
     fn m_range(&self) -> [f64; 2] {
         self.bbox.m_range()
     }
+
+    fn size_in_bytes_2d(&self) -> usize {
+        let mut size = 0usize;
+        size += 4 * size_of::<f64>();
+        size += size_of::<i32>();
+        size += 2 * size_of::<f64>() * self.points.len();
+        size
+    }
+
+    fn write_2d_to<T: Write>(&self, mut dest: &mut T) -> Result<(), Error> {
+        bbox_write_xy_to(&self.bbox, dest)?;
+        dest.write_i32::<LittleEndian>(self.points.len() as i32)?;
+        write_points(&mut dest, &self.points)?;
+        Ok(())
+    }
 }
 
 /*
@@ -426,9 +596,20 @@ impl HasShapeType for MultipointZ {
 
 impl ConcreteReadableShape for MultipointZ {
     fn read_shape_content<T: Read>(source: &mut T, record_size: i32) -> Result<Self, Error> {
+        Self::read_shape_content_with_options(source, record_size, ReadOptions::default())
+    }
+
+    fn read_shape_content_with_options<T: Read>(
+        source: &mut T,
+        record_size: i32,
+        options: ReadOptions,
+    ) -> Result<Self, Error> {
         let mut bbox = GenericBBox::<PointZ>::default();
         bbox_read_xy_from(&mut bbox, source)?;
         let num_points = source.read_i32::<LittleEndian>()?;
+        // bbox + num_points
+        let fixed_size = 4 * size_of::<f64>() + size_of::<i32>();
+        check_counts_fit_record(record_size, fixed_size, 0, num_points)?;
 
         let size_with_m = Self::size_of_record(num_points, true) as i32;
         let size_without_m = Self::size_of_record(num_points, false) as i32;
@@ -444,7 +625,7 @@ impl ConcreteReadableShape for MultipointZ {
 
             if m_is_used {
                 bbox_read_m_range_from(&mut bbox, source)?;
-                read_ms_into(source, &mut points)?;
+                read_ms_into(source, &mut points, options.no_data_threshold)?;
             }
 
             Ok(Self { bbox, points })
@@ -480,6 +661,10 @@ impl WritableShape for MultipointZ {
 }
 
 impl EsriShape for MultipointZ {
+    fn has_finite_coords(&self) -> bool {
+        super::are_xy_finite(self.points.iter())
+    }
+
     fn x_range(&self) -> [f64; 2] {
         self.bbox.x_range()
     }
@@ -495,6 +680,21 @@ impl EsriShape for MultipointZ {
     fn m_range(&self) -> [f64; 2] {
         self.bbox.m_range()
     }
+
+    fn size_in_bytes_2d(&self) -> usize {
+        let mut size = 0usize;
+        size += 4 * size_of::<f64>();
+        size += size_of::<i32>();
+        size += 2 * size_of::<f64>() * self.points.len();
+        size
+    }
+
+    fn write_2d_to<T: Write>(&self, mut dest: &mut T) -> Result<(), Error> {
+        bbox_write_xy_to(&self.bbox, dest)?;
+        dest.write_i32::<LittleEndian>(self.points.len() as i32)?;
+        write_points(&mut dest, &self.points)?;
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -589,7 +789,52 @@ mod test_geo_types_conversions {
 
 #[cfg(test)]
 mod tests {
-    use super::{MultipointZ, PointZ};
+    use super::{Multipoint, MultipointBuilder, MultipointZ, MultipointZBuilder, PointZ};
+    use crate::Point;
+
+    #[test]
+    fn test_multipoint_builder() {
+        let points = [
+            Point::new(1.0, 4.0),
+            Point::new(-2.0, 0.5),
+            Point::new(3.0, 2.0),
+        ];
+        let mut builder = MultipointBuilder::with_capacity(points.len());
+        for point in points {
+            builder.push(point);
+        }
+        assert_eq!(builder.len(), 3);
+        assert_eq!(builder.build(), Multipoint::new(points.to_vec()));
+
+        let mut builder = MultipointZBuilder::new();
+        builder.push(PointZ::new(1.0, 1.0, 5.0, 2.0));
+        builder.push(PointZ::new(0.0, 3.0, -1.0, 4.0));
+        let multipoint = builder.build();
+        assert_eq!(multipoint.bbox().z_range(), [-1.0, 5.0]);
+        assert_eq!(multipoint.bbox().m_range(), [2.0, 4.0]);
+
+        assert_eq!(MultipointBuilder::new().build(), Multipoint::default());
+    }
+
+    #[test]
+    fn test_default_multipoint_is_empty() {
+        let multipoint = Multipoint::default();
+        assert!(multipoint.points().is_empty());
+    }
+
+    #[test]
+    fn test_multipoint_from_slice() {
+        let points = [
+            Point::new(1.0, 1.0),
+            Point::new(5.0, 3.0),
+            Point::new(2.0, 7.0),
+            Point::new(-4.0, 0.0),
+        ];
+        let multipoint = Multipoint::from(&points[1..3]);
+        assert_eq!(multipoint.points(), &points[1..3]);
+        assert_eq!(multipoint.bbox().min, Point::new(2.0, 3.0));
+        assert_eq!(multipoint.bbox().max, Point::new(5.0, 7.0));
+    }
 
     #[test]
     fn test_multipoint_index() {
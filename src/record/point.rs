@@ -1,5 +1,6 @@
 //! Module with the definition of Point, PointM and PointZ
 
+use crate::reader::ReadOptions;
 use std::io::{Read, Write};
 
 use super::EsriShape;
@@ -7,6 +8,7 @@ use super::{ShapeType, NO_DATA};
 use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
 use std::mem::size_of;
 
+use super::io::m_or_no_data;
 use super::ConcreteReadableShape;
 use super::Error;
 use super::{is_no_data, HasShapeType, WritableShape};
@@ -43,6 +45,30 @@ impl Point {
     pub fn new(x: f64, y: f64) -> Self {
         Self { x, y }
     }
+
+    /// Returns true if the `x` and `y` of both points are within `epsilon`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use shapefile::Point;
+    /// let point = Point::new(1.0, 42.0);
+    /// assert!(point.approx_eq(&Point::new(1.0 + 1e-9, 42.0), 1e-6));
+    /// assert!(!point.approx_eq(&Point::new(1.1, 42.0), 1e-6));
+    /// ```
+    pub fn approx_eq(&self, other: &Point, epsilon: f64) -> bool {
+        f64_approx_eq(self.x, other.x, epsilon) && f64_approx_eq(self.y, other.y, epsilon)
+    }
+}
+
+/// Returns true if `a` and `b` are within `epsilon`
+pub(crate) fn f64_approx_eq(a: f64, b: f64, epsilon: f64) -> bool {
+    a == b || (a - b).abs() <= epsilon
+}
+
+/// Same as [f64_approx_eq] but two _no data_ measures are considered equal
+pub(crate) fn m_approx_eq(a: f64, b: f64, epsilon: f64) -> bool {
+    (is_no_data(a) && is_no_data(b)) || f64_approx_eq(a, b, epsilon)
 }
 
 impl HasShapeType for Point {
@@ -85,6 +111,34 @@ impl EsriShape for Point {
     }
 }
 
+/// Creates a point from `(x, y)`
+///
+/// # Example
+///
+/// ```
+/// use shapefile::Point;
+/// assert_eq!(Point::from((1.0, 2.0)), Point::new(1.0, 2.0));
+/// ```
+impl From<(f64, f64)> for Point {
+    fn from((x, y): (f64, f64)) -> Self {
+        Point::new(x, y)
+    }
+}
+
+/// Creates a point from `[x, y]`
+///
+/// # Example
+///
+/// ```
+/// use shapefile::Point;
+/// assert_eq!(Point::from([1.0, 2.0]), Point::new(1.0, 2.0));
+/// ```
+impl From<[f64; 2]> for Point {
+    fn from([x, y]: [f64; 2]) -> Self {
+        Point::new(x, y)
+    }
+}
+
 impl fmt::Display for Point {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "Point(x: {}, y: {})", self.x, self.y)
@@ -154,6 +208,34 @@ impl PointM {
     pub fn new(x: f64, y: f64, m: f64) -> Self {
         Self { x, y, m }
     }
+
+    /// Returns true if the `x`, `y` and `m` of both points are within `epsilon`
+    ///
+    /// Two _no data_ `m` are considered equal.
+    pub fn approx_eq(&self, other: &PointM, epsilon: f64) -> bool {
+        f64_approx_eq(self.x, other.x, epsilon)
+            && f64_approx_eq(self.y, other.y, epsilon)
+            && m_approx_eq(self.m, other.m, epsilon)
+    }
+
+    /// Returns true if the point has a measure (its `m` is not _no data_)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use shapefile::PointM;
+    /// let point = PointM::new(1.0, 42.0, 13.37);
+    /// assert!(point.has_measures());
+    /// assert!(!point.without_measure().has_measures());
+    /// ```
+    pub fn has_measures(&self) -> bool {
+        !is_no_data(self.m)
+    }
+
+    /// Returns the point with its `m` set to [NO_DATA]
+    pub fn without_measure(self) -> Self {
+        Self { m: NO_DATA, ..self }
+    }
 }
 
 impl HasShapeType for PointM {
@@ -164,10 +246,21 @@ impl HasShapeType for PointM {
 
 impl ConcreteReadableShape for PointM {
     fn read_shape_content<T: Read>(source: &mut T, record_size: i32) -> Result<Self, Error> {
+        Self::read_shape_content_with_options(source, record_size, ReadOptions::default())
+    }
+
+    fn read_shape_content_with_options<T: Read>(
+        source: &mut T,
+        record_size: i32,
+        options: ReadOptions,
+    ) -> Result<Self, Error> {
         if record_size == 3 * size_of::<f64>() as i32 {
             let x = source.read_f64::<LittleEndian>()?;
             let y = source.read_f64::<LittleEndian>()?;
-            let m = source.read_f64::<LittleEndian>()?;
+            let m = m_or_no_data(
+                source.read_f64::<LittleEndian>()?,
+                options.no_data_threshold,
+            );
             Ok(Self { x, y, m })
         } else {
             Err(Error::InvalidShapeRecordSize)
@@ -204,6 +297,44 @@ impl EsriShape for PointM {
             [self.m, self.m]
         }
     }
+
+    fn size_in_bytes_2d(&self) -> usize {
+        2 * size_of::<f64>()
+    }
+
+    fn write_2d_to<T: Write>(&self, dest: &mut T) -> Result<(), Error> {
+        dest.write_f64::<LittleEndian>(self.x)?;
+        dest.write_f64::<LittleEndian>(self.y)?;
+        Ok(())
+    }
+}
+
+/// Creates a point from `(x, y, m)`
+///
+/// # Example
+///
+/// ```
+/// use shapefile::PointM;
+/// assert_eq!(PointM::from((1.0, 2.0, 3.0)), PointM::new(1.0, 2.0, 3.0));
+/// ```
+impl From<(f64, f64, f64)> for PointM {
+    fn from((x, y, m): (f64, f64, f64)) -> Self {
+        PointM::new(x, y, m)
+    }
+}
+
+/// Creates a point from `[x, y, m]`
+///
+/// # Example
+///
+/// ```
+/// use shapefile::PointM;
+/// assert_eq!(PointM::from([1.0, 2.0, 3.0]), PointM::new(1.0, 2.0, 3.0));
+/// ```
+impl From<[f64; 3]> for PointM {
+    fn from([x, y, m]: [f64; 3]) -> Self {
+        PointM::new(x, y, m)
+    }
 }
 
 impl fmt::Display for PointM {
@@ -288,6 +419,16 @@ impl PointZ {
         Self { x, y, z, m }
     }
 
+    /// Returns true if the `x`, `y`, `z` and `m` of both points are within `epsilon`
+    ///
+    /// Two _no data_ `m` are considered equal.
+    pub fn approx_eq(&self, other: &PointZ, epsilon: f64) -> bool {
+        f64_approx_eq(self.x, other.x, epsilon)
+            && f64_approx_eq(self.y, other.y, epsilon)
+            && f64_approx_eq(self.z, other.z, epsilon)
+            && m_approx_eq(self.m, other.m, epsilon)
+    }
+
     fn read_xyz<R: Read>(source: &mut R) -> std::io::Result<Self> {
         let x = source.read_f64::<LittleEndian>()?;
         let y = source.read_f64::<LittleEndian>()?;
@@ -309,12 +450,23 @@ impl HasShapeType for PointZ {
 
 impl ConcreteReadableShape for PointZ {
     fn read_shape_content<T: Read>(source: &mut T, record_size: i32) -> Result<Self, Error> {
+        Self::read_shape_content_with_options(source, record_size, ReadOptions::default())
+    }
+
+    fn read_shape_content_with_options<T: Read>(
+        source: &mut T,
+        record_size: i32,
+        options: ReadOptions,
+    ) -> Result<Self, Error> {
         if record_size == 3 * size_of::<f64>() as i32 {
             let point = Self::read_xyz(source)?;
             Ok(point)
         } else if record_size == 4 * size_of::<f64>() as i32 {
             let mut point = Self::read_xyz(source)?;
-            point.m = source.read_f64::<LittleEndian>()?;
+            point.m = m_or_no_data(
+                source.read_f64::<LittleEndian>()?,
+                options.no_data_threshold,
+            );
             Ok(point)
         } else {
             Err(Error::InvalidShapeRecordSize)
@@ -356,6 +508,16 @@ impl EsriShape for PointZ {
             [self.m, self.m]
         }
     }
+
+    fn size_in_bytes_2d(&self) -> usize {
+        2 * size_of::<f64>()
+    }
+
+    fn write_2d_to<T: Write>(&self, dest: &mut T) -> Result<(), Error> {
+        dest.write_f64::<LittleEndian>(self.x)?;
+        dest.write_f64::<LittleEndian>(self.y)?;
+        Ok(())
+    }
 }
 
 impl Default for PointZ {
@@ -369,6 +531,38 @@ impl Default for PointZ {
     }
 }
 
+/// Creates a point from `(x, y, z, m)`, note that `z` comes before `m`
+///
+/// # Example
+///
+/// ```
+/// use shapefile::{PointZ, NO_DATA};
+/// let point = PointZ::from((1.0, 2.0, 3.0, NO_DATA));
+/// assert_eq!(point, PointZ::new(1.0, 2.0, 3.0, NO_DATA));
+/// assert_eq!(point.z, 3.0);
+/// ```
+impl From<(f64, f64, f64, f64)> for PointZ {
+    fn from((x, y, z, m): (f64, f64, f64, f64)) -> Self {
+        PointZ::new(x, y, z, m)
+    }
+}
+
+/// Creates a point from `[x, y, z, m]`, note that `z` comes before `m`
+///
+/// # Example
+///
+/// ```
+/// use shapefile::PointZ;
+/// let point = PointZ::from([1.0, 2.0, 3.0, 4.0]);
+/// assert_eq!(point, PointZ::new(1.0, 2.0, 3.0, 4.0));
+/// assert_eq!(point.m, 4.0);
+/// ```
+impl From<[f64; 4]> for PointZ {
+    fn from([x, y, z, m]: [f64; 4]) -> Self {
+        PointZ::new(x, y, z, m)
+    }
+}
+
 impl fmt::Display for PointZ {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         if is_no_data(self.m) {
@@ -419,6 +613,168 @@ impl From<PointZ> for geo_types::Coordinate<f64> {
     }
 }
 
+/// Wrapper around a point that implements [Eq] and [Hash](std::hash::Hash),
+/// so that points can be used as keys of a `HashMap` or in a `HashSet`,
+/// created with [Point::to_hashable] (or its equivalent for `PointM` and `PointZ`)
+///
+/// Two wrapped points are equal if their coordinates have exactly the
+/// same bit patterns (see [f64::to_bits]): there is no tolerance,
+/// `0.0` and `-0.0` are different and a `NaN` is equal to a `NaN` with the same bits.
+///
+/// # Example
+///
+/// ```
+/// use std::collections::HashSet;
+/// use shapefile::Point;
+/// let points = vec![Point::new(1.0, 2.0), Point::new(3.0, 4.0), Point::new(1.0, 2.0)];
+/// let unique = points
+///     .iter()
+///     .map(|point| point.to_hashable())
+///     .collect::<HashSet<_>>();
+/// assert_eq!(unique.len(), 2);
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct HashablePoint<PointType = Point>(pub PointType);
+
+macro_rules! impl_hashable_point {
+    ($PointType:ty, $($coord:ident),+) => {
+        impl $PointType {
+            /// Wraps the point in a [HashablePoint],
+            /// to use it as a key of a `HashMap` or in a `HashSet`
+            pub fn to_hashable(self) -> HashablePoint<$PointType> {
+                HashablePoint(self)
+            }
+        }
+
+        impl PartialEq for HashablePoint<$PointType> {
+            fn eq(&self, other: &Self) -> bool {
+                $(self.0.$coord.to_bits() == other.0.$coord.to_bits())&&+
+            }
+        }
+
+        impl Eq for HashablePoint<$PointType> {}
+
+        impl std::hash::Hash for HashablePoint<$PointType> {
+            fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+                $(self.0.$coord.to_bits().hash(state);)+
+            }
+        }
+    };
+}
+
+impl_hashable_point!(Point, x, y);
+impl_hashable_point!(PointM, x, y, m);
+impl_hashable_point!(PointZ, x, y, z, m);
+
+/// Point with `x` and `y` coordinates stored as `f32`
+///
+/// This type can only be read, it is meant for consumers where memory
+/// matters more than precision: the `f64` coordinates of the file
+/// are narrowed to `f32` when read, which loses precision
+/// (`f32` only has about 7 significant decimal digits).
+///
+/// # Example
+///
+/// ```
+/// # fn main() -> Result<(), shapefile::Error> {
+/// let points = shapefile::read_shapes_as::<_, shapefile::PointF32>("tests/data/point.shp")?;
+/// assert_eq!(points.len(), 1);
+/// assert_eq!(points[0].x, 122.0);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(PartialEq, Debug, Default, Copy, Clone)]
+pub struct PointF32 {
+    pub x: f32,
+    pub y: f32,
+}
+
+impl PointF32 {
+    /// Creates a new point
+    pub fn new(x: f32, y: f32) -> Self {
+        Self { x, y }
+    }
+}
+
+impl HasShapeType for PointF32 {
+    fn shapetype() -> ShapeType {
+        ShapeType::Point
+    }
+}
+
+impl ConcreteReadableShape for PointF32 {
+    fn read_shape_content<T: Read>(source: &mut T, record_size: i32) -> Result<Self, Error> {
+        let point = Point::read_shape_content(source, record_size)?;
+        Ok(Self::from(point))
+    }
+}
+
+impl From<Point> for PointF32 {
+    fn from(p: Point) -> Self {
+        Self {
+            x: p.x as f32,
+            y: p.y as f32,
+        }
+    }
+}
+
+impl From<PointF32> for Point {
+    fn from(p: PointF32) -> Self {
+        Self {
+            x: f64::from(p.x),
+            y: f64::from(p.y),
+        }
+    }
+}
+
+impl fmt::Display for PointF32 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Point(x: {}, y: {})", self.x, self.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_dedup_points_with_hashable_point() {
+        let points = vec![
+            Point::new(1.0, 2.0),
+            Point::new(3.0, 4.0),
+            Point::new(1.0, 2.0),
+            Point::new(-0.0, 0.0),
+            Point::new(0.0, 0.0),
+        ];
+        let mut seen = HashSet::new();
+        let unique = points
+            .into_iter()
+            .filter(|point| seen.insert(point.to_hashable()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            unique,
+            vec![
+                Point::new(1.0, 2.0),
+                Point::new(3.0, 4.0),
+                Point::new(-0.0, 0.0),
+                Point::new(0.0, 0.0),
+            ]
+        );
+
+        let points_z = [
+            PointZ::new(1.0, 2.0, 3.0, NO_DATA),
+            PointZ::new(1.0, 2.0, 4.0, NO_DATA),
+            PointZ::new(1.0, 2.0, 3.0, NO_DATA),
+        ];
+        let unique = points_z
+            .iter()
+            .map(|point| point.to_hashable())
+            .collect::<HashSet<_>>();
+        assert_eq!(unique.len(), 2);
+    }
+}
+
 #[cfg(test)]
 #[cfg(feature = "geo-types")]
 mod test_geo_types {
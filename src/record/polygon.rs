@@ -3,13 +3,14 @@ use super::io::MultiPartShapeWriter;
 use super::polyline::GenericPolyline;
 use super::traits::{GrowablePoint, HasXY, ShrinkablePoint};
 use super::{
-    close_points_if_not_already, ring_type_from_points_ordering, ConcreteReadableShape, EsriShape,
-    GenericBBox, RingType, WritableShape,
+    close_points_if_not_already, is_part_closed, ring_type_from_points_ordering,
+    ConcreteReadableShape, EsriShape, GenericBBox, RingType, WritableShape,
 };
-use super::{Error, ShapeType};
+use super::{is_no_data, Error, ShapeType, NO_DATA};
 use super::{HasShapeType, Point};
 use super::{PointM, PointZ};
 use super::{Polyline, PolylineM, PolylineZ};
+use crate::reader::ReadOptions;
 use core::fmt;
 use std::io::{Read, Write};
 use std::mem::size_of;
@@ -169,6 +170,84 @@ where
     }
 }
 
+impl<PointType> PolygonRing<PointType>
+where
+    PointType: PartialEq + HasXY,
+{
+    /// Returns true if no segment of the ring intersects (or touches)
+    /// another non-adjacent segment of the ring
+    ///
+    /// Consecutive duplicated points are ignored.
+    ///
+    /// Every pair of segments is tested, so the complexity is O(n²)
+    /// where n is the number of points.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use shapefile::{Point, PolygonRing};
+    /// let bowtie = PolygonRing::Outer(vec![
+    ///     Point::new(0.0, 0.0),
+    ///     Point::new(1.0, 1.0),
+    ///     Point::new(1.0, 0.0),
+    ///     Point::new(0.0, 1.0),
+    ///     Point::new(0.0, 0.0),
+    /// ]);
+    /// assert!(!bowtie.is_simple());
+    /// ```
+    pub fn is_simple(&self) -> bool {
+        let mut points: Vec<&PointType> = self.points().iter().collect();
+        points.dedup();
+        let is_closed = points.len() > 1 && points.first() == points.last();
+        let segments: Vec<_> = points.windows(2).map(|w| (w[0], w[1])).collect();
+        let num_segments = segments.len();
+
+        for i in 0..num_segments {
+            for j in (i + 2)..num_segments {
+                if is_closed && i == 0 && j == num_segments - 1 {
+                    // The first and last segments share the closing point
+                    continue;
+                }
+                let (a, b) = segments[i];
+                let (c, d) = segments[j];
+                if segments_intersect(a, b, c, d) {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+}
+
+/// Returns true if the segments [a, b] and [c, d] intersect or touch
+fn segments_intersect<P: HasXY>(a: &P, b: &P, c: &P, d: &P) -> bool {
+    fn orientation<P: HasXY>(p: &P, q: &P, r: &P) -> f64 {
+        (q.x() - p.x()) * (r.y() - p.y()) - (q.y() - p.y()) * (r.x() - p.x())
+    }
+    fn is_on_segment<P: HasXY>(p: &P, q: &P, r: &P) -> bool {
+        r.x() >= p.x().min(q.x())
+            && r.x() <= p.x().max(q.x())
+            && r.y() >= p.y().min(q.y())
+            && r.y() <= p.y().max(q.y())
+    }
+
+    let o1 = orientation(a, b, c);
+    let o2 = orientation(a, b, d);
+    let o3 = orientation(c, d, a);
+    let o4 = orientation(c, d, b);
+
+    if ((o1 > 0.0 && o2 < 0.0) || (o1 < 0.0 && o2 > 0.0))
+        && ((o3 > 0.0 && o4 < 0.0) || (o3 < 0.0 && o4 > 0.0))
+    {
+        return true;
+    }
+
+    (o1 == 0.0 && is_on_segment(a, b, c))
+        || (o2 == 0.0 && is_on_segment(a, b, d))
+        || (o3 == 0.0 && is_on_segment(c, d, a))
+        || (o4 == 0.0 && is_on_segment(c, d, b))
+}
+
 impl<PointType, I: SliceIndex<[PointType]>> Index<I> for PolygonRing<PointType> {
     type Output = I::Output;
 
@@ -202,13 +281,28 @@ impl<PointType: HasXY> From<Vec<PointType>> for PolygonRing<PointType> {
 ///   **(this is done by the constructors if you do not do it yourself)**
 /// - The order of rings is not significant (p 13/34)
 /// - A polygon may have multiple [`Outer`] rings (p12/34)
+/// - The [`Default`] polygon has no rings and a default (zeroed) bounding box
 ///
+/// # Inner rings without an outer ring
+///
+/// When reading, the rings are kept in the order of the file, except when the
+/// first ring of the polygon is an inner ring: an empty [`Outer`] ring is then
+/// added before it, so that every inner ring follows an outer ring.
+/// This empty ring is not written to files, so no ring is added to the file
+/// when it is written back.
+/// It is only visible through [`rings`](GenericPolygon::rings) and the geo-types conversions,
+/// the other methods (e.g. [`outer_ring_count`](GenericPolygon::outer_ring_count),
+/// [`is_valid`](GenericPolygon::is_valid) or the `Display` implementation) ignore it.
 ///
 /// # geo-types
 ///
 /// shapefile's Polygons can be converted to geo-types's `MultiPolygon<f64>`,
 /// but not geo-types's Polygon<f64> as they only allow polygons with one outer ring.
 ///
+/// The empty outer ring added to inner rings without an outer ring becomes
+/// the empty exterior of a geo-types polygon, and the other way around,
+/// so such rings survive a round trip.
+///
 /// geo-types's `Polygon<f64>` and `MultiPolygon<f64>` can be converted to shapefile's Polygon
 ///
 /// ```
@@ -226,7 +320,7 @@ impl<PointType: HasXY> From<Vec<PointType>> for PolygonRing<PointType> {
 /// [`new`]: #method.new
 /// [`with_rings`]: #method.with_rings
 /// [`Outer`]: enum.PolygonRing.html#variant.Outer
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Default, Clone, PartialEq)]
 pub struct GenericPolygon<PointType> {
     bbox: GenericBBox<PointType>,
     rings: Vec<PolygonRing<PointType>>,
@@ -305,14 +399,156 @@ where
     /// [`PolygonRing`]: enum.PolygonRing.html
     pub fn with_rings(mut rings: Vec<PolygonRing<PointType>>) -> Self {
         rings.iter_mut().for_each(PolygonRing::close_and_reorder);
-        let mut bbox = GenericBBox::<PointType>::from_points(rings[0].points());
-        for ring in &rings[1..] {
-            bbox.grow_from_points(ring.points());
-        }
+        Self::with_rings_unchecked(rings)
+    }
+
+    /// Creates a polygon with multiple rings, used as is
+    ///
+    /// Unlike [`with_rings`], the rings are neither closed nor reordered,
+    /// only the bounding box is computed.
+    ///
+    /// It is up to the caller to give closed rings, with outer rings
+    /// in clockwise order and inner rings in counterclockwise order,
+    /// otherwise the written file will not respect the specification
+    /// and other programs may not read it correctly.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use shapefile::{Point, Polygon, PolygonRing};
+    /// // Counterclockwise, so `with_rings` would reverse it
+    /// let points = vec![
+    ///     Point::new(0.0, 0.0),
+    ///     Point::new(1.0, 0.0),
+    ///     Point::new(1.0, 1.0),
+    ///     Point::new(0.0, 0.0),
+    /// ];
+    /// let polygon = Polygon::with_rings_unchecked(vec![PolygonRing::Outer(points.clone())]);
+    /// assert_eq!(polygon.rings()[0].points(), points.as_slice());
+    /// ```
+    ///
+    /// [`with_rings`]: #method.with_rings
+    pub fn with_rings_unchecked(rings: Vec<PolygonRing<PointType>>) -> Self {
+        // The first ring may be the empty outer ring of orphan inner rings
+        let bbox =
+            GenericBBox::<PointType>::from_points_iter(rings.iter().flat_map(|ring| ring.points()))
+                .expect("The polygon must have at least one point");
         Self { bbox, rings }
     }
 }
 
+impl<PointType> GenericPolygon<PointType>
+where
+    PointType: ShrinkablePoint + GrowablePoint + PartialEq + Copy,
+{
+    /// Removes the consecutive repeated points in each ring
+    /// and recomputes the bounding box.
+    ///
+    /// Points are compared with all their coordinates (so including `m` and `z`).
+    ///
+    /// Rings that were closed stay closed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use shapefile::{Point, Polygon, PolygonRing};
+    /// let mut polygon = Polygon::new(PolygonRing::Outer(vec![
+    ///     Point::new(0.0, 0.0),
+    ///     Point::new(0.0, 1.0),
+    ///     Point::new(0.0, 1.0),
+    ///     Point::new(1.0, 1.0),
+    ///     Point::new(0.0, 0.0),
+    /// ]));
+    /// polygon.remove_consecutive_duplicates();
+    /// assert_eq!(polygon.total_point_count(), 4);
+    /// ```
+    pub fn remove_consecutive_duplicates(&mut self) {
+        for ring in self.rings.iter_mut() {
+            let points = ring.points_vec_mut();
+            let was_closed = is_part_closed(points);
+            points.dedup();
+            if was_closed {
+                close_points_if_not_already(points);
+            }
+        }
+        self.recompute_bbox();
+    }
+}
+
+impl<PointType> GenericPolygon<PointType>
+where
+    PointType: ShrinkablePoint + GrowablePoint + Copy,
+{
+    /// Applies `f` on each point and recomputes the bounding box
+    pub(crate) fn for_each_point_mut<F: FnMut(&mut PointType)>(&mut self, mut f: F) {
+        for ring in self.rings.iter_mut() {
+            ring.points_vec_mut().iter_mut().for_each(&mut f);
+        }
+        self.recompute_bbox();
+    }
+
+    /// Splits the polygon in one polygon per outer ring,
+    /// each inner ring goes with the outer ring that precedes it
+    ///
+    /// This is the same grouping as the one done when converting to
+    /// a `geo_types::MultiPolygon`, but the shapefile types are kept.
+    /// Inner rings that come before any outer ring are grouped in a polygon of their own.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use shapefile::{Point, Polygon, PolygonRing};
+    /// let square = |x: f64| {
+    ///     PolygonRing::Outer(vec![
+    ///         Point::new(x, 0.0),
+    ///         Point::new(x, 1.0),
+    ///         Point::new(x + 1.0, 1.0),
+    ///         Point::new(x + 1.0, 0.0),
+    ///         Point::new(x, 0.0),
+    ///     ])
+    /// };
+    /// let polygon = Polygon::with_rings(vec![square(0.0), square(5.0)]);
+    /// let polygons = polygon.into_polygons();
+    /// assert_eq!(polygons, vec![Polygon::new(square(0.0)), Polygon::new(square(5.0))]);
+    /// ```
+    pub fn into_polygons(self) -> Vec<Self>
+    where
+        PointType: Default,
+    {
+        let added_rings = self.rings.len() - self.file_rings().len();
+        let mut groups = Vec::<Vec<PolygonRing<PointType>>>::new();
+        for ring in self.rings.into_iter().skip(added_rings) {
+            match (ring, groups.last_mut()) {
+                (PolygonRing::Inner(points), Some(group)) => group.push(PolygonRing::Inner(points)),
+                (ring, _) => groups.push(vec![ring]),
+            }
+        }
+        groups
+            .into_iter()
+            .map(|rings| Self {
+                bbox: GenericBBox::from_points_iter(rings.iter().flat_map(|ring| ring.points()))
+                    .unwrap_or_default(),
+                rings,
+            })
+            .collect()
+    }
+
+    /// Recomputes the bounding box from the points of the rings
+    ///
+    /// This needs to be called after modifying the rings
+    /// with [`rings_mut`], the bounding box is left untouched
+    /// if the polygon has no points.
+    ///
+    /// [`rings_mut`]: #method.rings_mut
+    pub fn recompute_bbox(&mut self) {
+        if let Some(bbox) =
+            GenericBBox::from_points_iter(self.rings.iter().flat_map(|ring| ring.points()))
+        {
+            self.bbox = bbox;
+        }
+    }
+}
+
 impl<PointType> GenericPolygon<PointType> {
     /// Returns the bounding box associated to the polygon
     #[inline]
@@ -326,6 +562,39 @@ impl<PointType> GenericPolygon<PointType> {
         &self.rings
     }
 
+    /// Returns the rings of the polygon, to modify them in place
+    ///
+    /// # Important
+    ///
+    /// The bounding box of the polygon is not updated when rings are modified,
+    /// [`recompute_bbox`] must be called after the modifications.
+    ///
+    /// Also, it is up to the caller to keep the rings closed
+    /// and their points ordered according to their type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use shapefile::{Point, Polygon, PolygonRing};
+    /// let mut polygon = Polygon::new(PolygonRing::Outer(vec![
+    ///     Point::new(0.0, 0.0),
+    ///     Point::new(0.0, 1.0),
+    ///     Point::new(1.0, 1.0),
+    ///     Point::new(0.0, 0.0),
+    /// ]));
+    /// if let PolygonRing::Outer(points) = &mut polygon.rings_mut()[0] {
+    ///     points[1].y = 2.0;
+    /// }
+    /// polygon.recompute_bbox();
+    /// assert_eq!(polygon.bbox().max.y, 2.0);
+    /// ```
+    ///
+    /// [`recompute_bbox`]: #method.recompute_bbox
+    #[inline]
+    pub fn rings_mut(&mut self) -> &mut [PolygonRing<PointType>] {
+        &mut self.rings
+    }
+
     /// Returns the ring as index
     ///
     /// # Example
@@ -356,11 +625,94 @@ impl<PointType> GenericPolygon<PointType> {
         self.rings
     }
 
+    /// Adds an empty outer ring before the first ring if it is an inner ring
+    pub(crate) fn attach_orphan_inner_rings(mut self) -> Self {
+        if let Some(PolygonRing::Inner(_)) = self.rings.first() {
+            self.rings.insert(0, PolygonRing::Outer(Vec::new()));
+        }
+        self
+    }
+
+    /// Returns the rings as stored in files, that is without the
+    /// empty outer ring added by [`attach_orphan_inner_rings`](Self::attach_orphan_inner_rings)
+    pub(crate) fn file_rings(&self) -> &[PolygonRing<PointType>] {
+        match self.rings.as_slice() {
+            [PolygonRing::Outer(points), PolygonRing::Inner(_), ..] if points.is_empty() => {
+                &self.rings[1..]
+            }
+            rings => rings,
+        }
+    }
+
     /// Returns the sum of points of all the rings
     #[inline]
     pub fn total_point_count(&self) -> usize {
         self.rings.iter().map(|ring| ring.len()).sum()
     }
+
+    /// Returns the number of outer rings of the polygon
+    #[inline]
+    pub fn outer_ring_count(&self) -> usize {
+        self.file_rings()
+            .iter()
+            .filter(|ring| matches!(ring, PolygonRing::Outer(_)))
+            .count()
+    }
+
+    /// Returns true if the polygon has more than one outer ring,
+    /// meaning it is in fact made of multiple polygons
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use shapefile::{Point, Polygon, PolygonRing};
+    /// let square = |x: f64| {
+    ///     PolygonRing::Outer(vec![
+    ///         Point::new(x, 0.0),
+    ///         Point::new(x, 1.0),
+    ///         Point::new(x + 1.0, 1.0),
+    ///         Point::new(x + 1.0, 0.0),
+    ///         Point::new(x, 0.0),
+    ///     ])
+    /// };
+    /// assert!(!Polygon::new(square(0.0)).is_multi());
+    /// assert!(Polygon::with_rings(vec![square(0.0), square(5.0)]).is_multi());
+    /// ```
+    #[inline]
+    pub fn is_multi(&self) -> bool {
+        self.outer_ring_count() > 1
+    }
+}
+
+impl<PointType> GenericPolygon<PointType>
+where
+    PointType: PartialEq + HasXY,
+{
+    /// Returns true if every ring of the polygon:
+    ///
+    /// - is closed and has at least 4 points
+    /// - is simple (see [PolygonRing::is_simple])
+    /// - has the winding that matches its type
+    ///   (clockwise for [`Outer`], counterclockwise for [`Inner`])
+    ///
+    /// Relations between rings (e.g. holes being inside an outer ring)
+    /// are not checked.
+    ///
+    /// [`Outer`]: enum.PolygonRing.html#variant.Outer
+    /// [`Inner`]: enum.PolygonRing.html#variant.Inner
+    pub fn is_valid(&self) -> bool {
+        self.file_rings().iter().all(|ring| {
+            let points = ring.points();
+            let expected_ring_type = match ring {
+                PolygonRing::Outer(_) => RingType::OuterRing,
+                PolygonRing::Inner(_) => RingType::InnerRing,
+            };
+            points.len() >= 4
+                && is_part_closed(points)
+                && ring.is_simple()
+                && ring_type_from_points_ordering(points) == expected_ring_type
+        })
+    }
 }
 
 impl<PointType: HasXY> From<GenericPolyline<PointType>> for GenericPolygon<PointType> {
@@ -376,6 +728,183 @@ impl<PointType: HasXY> From<GenericPolyline<PointType>> for GenericPolygon<Point
     }
 }
 
+/// Each ring of the polygon becomes a part of the polyline,
+/// the points keep their order.
+///
+/// # Example
+///
+/// ```
+/// use shapefile::{Point, Polygon, PolygonRing, Polyline};
+/// let polygon = Polygon::new(PolygonRing::Outer(vec![
+///     Point::new(0.0, 0.0),
+///     Point::new(0.0, 1.0),
+///     Point::new(1.0, 1.0),
+///     Point::new(0.0, 0.0),
+/// ]));
+/// let polyline = Polyline::from(polygon.clone());
+/// assert_eq!(polyline.parts()[0].as_slice(), polygon.rings()[0].points());
+/// ```
+impl<PointType> From<GenericPolygon<PointType>> for GenericPolyline<PointType> {
+    fn from(polygon: GenericPolygon<PointType>) -> Self {
+        let parts = polygon
+            .rings
+            .into_iter()
+            .map(PolygonRing::into_inner)
+            .collect();
+        Self {
+            bbox: polygon.bbox,
+            parts,
+        }
+    }
+}
+
+/// Points for which a point between two points can be computed,
+/// used when clipping polygons
+trait InterpolablePoint: HasXY + Copy {
+    /// Returns the point at `t` (in `[0, 1]`) on the segment from `self` to `other`
+    fn interpolate(&self, other: &Self, t: f64) -> Self;
+}
+
+fn lerp(a: f64, b: f64, t: f64) -> f64 {
+    a + (b - a) * t
+}
+
+impl InterpolablePoint for Point {
+    fn interpolate(&self, other: &Self, t: f64) -> Self {
+        Point::new(lerp(self.x, other.x, t), lerp(self.y, other.y, t))
+    }
+}
+
+fn lerp_m(a: f64, b: f64, t: f64) -> f64 {
+    if is_no_data(a) || is_no_data(b) {
+        NO_DATA
+    } else {
+        lerp(a, b, t)
+    }
+}
+
+impl InterpolablePoint for PointM {
+    fn interpolate(&self, other: &Self, t: f64) -> Self {
+        PointM::new(
+            lerp(self.x, other.x, t),
+            lerp(self.y, other.y, t),
+            lerp_m(self.m, other.m, t),
+        )
+    }
+}
+
+impl InterpolablePoint for PointZ {
+    fn interpolate(&self, other: &Self, t: f64) -> Self {
+        PointZ::new(
+            lerp(self.x, other.x, t),
+            lerp(self.y, other.y, t),
+            lerp(self.z, other.z, t),
+            lerp_m(self.m, other.m, t),
+        )
+    }
+}
+
+/// Clips the (open) ring against one edge of the clipping rectangle
+///
+/// `inside` tells if a point is on the inner side of the edge and
+/// `intersection_t` returns where the segment between two points crosses the edge
+fn clip_ring_against_edge<PointType, In, Inter>(
+    points: &[PointType],
+    inside: In,
+    intersection_t: Inter,
+) -> Vec<PointType>
+where
+    PointType: InterpolablePoint,
+    In: Fn(&PointType) -> bool,
+    Inter: Fn(&PointType, &PointType) -> f64,
+{
+    let mut output = Vec::with_capacity(points.len() + 4);
+    let mut previous = match points.last() {
+        Some(point) => point,
+        None => return output,
+    };
+    for current in points {
+        if inside(current) {
+            if !inside(previous) {
+                output.push(previous.interpolate(current, intersection_t(previous, current)));
+            }
+            output.push(*current);
+        } else if inside(previous) {
+            output.push(previous.interpolate(current, intersection_t(previous, current)));
+        }
+        previous = current;
+    }
+    output
+}
+
+/// Sutherland–Hodgman clipping of a ring against a rectangle
+///
+/// Returns `None` if the clipped ring is empty or has no area
+fn clip_ring<PointType>(
+    points: &[PointType],
+    bbox: &GenericBBox<PointType>,
+) -> Option<Vec<PointType>>
+where
+    PointType: InterpolablePoint + PartialEq,
+{
+    let (xmin, ymin, xmax, ymax) = (bbox.min.x(), bbox.min.y(), bbox.max.x(), bbox.max.y());
+    let x_t = |x: f64| move |a: &PointType, b: &PointType| (x - a.x()) / (b.x() - a.x());
+    let y_t = |y: f64| move |a: &PointType, b: &PointType| (y - a.y()) / (b.y() - a.y());
+
+    let mut clipped = points.to_vec();
+    if is_part_closed(&clipped) {
+        clipped.pop();
+    }
+    clipped = clip_ring_against_edge(&clipped, |p| p.x() >= xmin, x_t(xmin));
+    clipped = clip_ring_against_edge(&clipped, |p| p.x() <= xmax, x_t(xmax));
+    clipped = clip_ring_against_edge(&clipped, |p| p.y() >= ymin, y_t(ymin));
+    clipped = clip_ring_against_edge(&clipped, |p| p.y() <= ymax, y_t(ymax));
+    clipped.dedup();
+
+    if clipped.len() < 3 {
+        return None;
+    }
+    close_points_if_not_already(&mut clipped);
+    let doubled_area = clipped
+        .windows(2)
+        .map(|pts| pts[0].x() * pts[1].y() - pts[1].x() * pts[0].y())
+        .sum::<f64>();
+    if doubled_area == 0.0 {
+        None
+    } else {
+        Some(clipped)
+    }
+}
+
+fn clip_polygon<PointType>(
+    polygon: &GenericPolygon<PointType>,
+    bbox: &GenericBBox<PointType>,
+) -> Option<GenericPolygon<PointType>>
+where
+    PointType: InterpolablePoint + GrowablePoint + ShrinkablePoint + PartialEq,
+{
+    let rings = polygon
+        .rings
+        .iter()
+        .filter_map(|ring| {
+            let points = clip_ring(ring.points(), bbox)?;
+            Some(match ring {
+                PolygonRing::Outer(_) => PolygonRing::Outer(points),
+                PolygonRing::Inner(_) => PolygonRing::Inner(points),
+            })
+        })
+        .collect::<Vec<_>>();
+
+    if rings
+        .iter()
+        .any(|ring| matches!(ring, PolygonRing::Outer(_)))
+    {
+        Some(GenericPolygon::with_rings(rings))
+    } else {
+        None
+    }
+}
+
 /*
  * Polygon
 */
@@ -383,9 +912,48 @@ impl<PointType: HasXY> From<GenericPolyline<PointType>> for GenericPolygon<Point
 /// ( collection of [Point](../point/struct.Point.html))
 pub type Polygon = GenericPolygon<Point>;
 
+impl Polygon {
+    /// Clips the polygon to the bounding box
+    ///
+    /// Each ring is clipped using the Sutherland–Hodgman algorithm,
+    /// holes that fall entirely outside of the bbox are dropped.
+    ///
+    /// Returns `None` if nothing of the polygon remains.
+    ///
+    /// # Note
+    ///
+    /// As with any Sutherland–Hodgman implementation, clipping a concave ring
+    /// that exits and re-enters the bbox results in one ring with
+    /// degenerate edges along the bbox border, instead of multiple rings.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use shapefile::{Point, Polygon, PolygonRing};
+    /// use shapefile::record::GenericBBox;
+    /// let polygon = Polygon::new(PolygonRing::Outer(vec![
+    ///     Point::new(0.0, 0.0),
+    ///     Point::new(0.0, 4.0),
+    ///     Point::new(4.0, 4.0),
+    ///     Point::new(4.0, 0.0),
+    ///     Point::new(0.0, 0.0),
+    /// ]));
+    /// let bbox = GenericBBox::<Point>::from_ranges([2.0, 10.0], [-1.0, 3.0]);
+    /// let clipped = polygon.clip_to_bbox(&bbox).unwrap();
+    /// assert_eq!(clipped.bbox().min, Point::new(2.0, 0.0));
+    /// assert_eq!(clipped.bbox().max, Point::new(4.0, 3.0));
+    ///
+    /// let bbox = GenericBBox::<Point>::from_ranges([5.0, 10.0], [5.0, 10.0]);
+    /// assert!(polygon.clip_to_bbox(&bbox).is_none());
+    /// ```
+    pub fn clip_to_bbox(&self, bbox: &GenericBBox<Point>) -> Option<Self> {
+        clip_polygon(self, bbox)
+    }
+}
+
 impl fmt::Display for Polygon {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "Polygon({} rings)", self.rings.len())
+        write!(f, "Polygon({} rings)", self.file_rings().len())
     }
 }
 
@@ -397,7 +965,9 @@ impl HasShapeType for Polygon {
 
 impl ConcreteReadableShape for Polygon {
     fn read_shape_content<T: Read>(source: &mut T, record_size: i32) -> Result<Self, Error> {
-        Polyline::read_shape_content(source, record_size).map(Polygon::from)
+        Polyline::read_shape_content(source, record_size)
+            .map(Polygon::from)
+            .map(Polygon::attach_orphan_inner_rings)
     }
 }
 
@@ -407,13 +977,13 @@ impl WritableShape for Polygon {
         size += size_of::<f64>() * 4;
         size += size_of::<i32>(); // num parts
         size += size_of::<i32>(); //num points
-        size += size_of::<i32>() * self.rings.len();
+        size += size_of::<i32>() * self.file_rings().len();
         size += 2 * size_of::<f64>() * self.total_point_count();
         size
     }
 
     fn write_to<T: Write>(&self, dest: &mut T) -> Result<(), Error> {
-        let parts_iter = self.rings().iter().map(|ring| ring.points());
+        let parts_iter = self.file_rings().iter().map(|ring| ring.points());
         let writer = MultiPartShapeWriter::new(&self.bbox, parts_iter, dest);
         writer.write_point_shape()?;
         Ok(())
@@ -421,6 +991,10 @@ impl WritableShape for Polygon {
 }
 
 impl EsriShape for Polygon {
+    fn has_finite_coords(&self) -> bool {
+        super::are_xy_finite(self.rings.iter().flat_map(|ring| ring.points()))
+    }
+
     fn x_range(&self) -> [f64; 2] {
         self.bbox.x_range()
     }
@@ -438,9 +1012,20 @@ impl EsriShape for Polygon {
 /// ( collection of [PointM](../point/struct.PointM.html))
 pub type PolygonM = GenericPolygon<PointM>;
 
+impl PolygonM {
+    /// Clips the polygon to the bounding box (only the `x` and `y` of the bbox are used)
+    ///
+    /// The `m` values of the points created on the bbox border are interpolated.
+    ///
+    /// See [Polygon::clip_to_bbox]
+    pub fn clip_to_bbox(&self, bbox: &GenericBBox<PointM>) -> Option<Self> {
+        clip_polygon(self, bbox)
+    }
+}
+
 impl fmt::Display for PolygonM {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "PolygonM({} rings)", self.rings.len())
+        write!(f, "PolygonM({} rings)", self.file_rings().len())
     }
 }
 
@@ -452,7 +1037,17 @@ impl HasShapeType for PolygonM {
 
 impl ConcreteReadableShape for PolygonM {
     fn read_shape_content<T: Read>(source: &mut T, record_size: i32) -> Result<Self, Error> {
-        PolylineM::read_shape_content(source, record_size).map(PolygonM::from)
+        Self::read_shape_content_with_options(source, record_size, ReadOptions::default())
+    }
+
+    fn read_shape_content_with_options<T: Read>(
+        source: &mut T,
+        record_size: i32,
+        options: ReadOptions,
+    ) -> Result<Self, Error> {
+        PolylineM::read_shape_content_with_options(source, record_size, options)
+            .map(PolygonM::from)
+            .map(PolygonM::attach_orphan_inner_rings)
     }
 }
 
@@ -462,14 +1057,14 @@ impl WritableShape for PolygonM {
         size += size_of::<f64>() * 4;
         size += size_of::<i32>(); // num parts
         size += size_of::<i32>(); //num points
-        size += size_of::<i32>() * self.rings.len();
+        size += size_of::<i32>() * self.file_rings().len();
         size += 3 * size_of::<f64>() * self.total_point_count();
         size += 2 * size_of::<f64>();
         size
     }
 
     fn write_to<T: Write>(&self, dest: &mut T) -> Result<(), Error> {
-        let parts_iter = self.rings().iter().map(|ring| ring.points());
+        let parts_iter = self.file_rings().iter().map(|ring| ring.points());
         let writer = MultiPartShapeWriter::new(&self.bbox, parts_iter, dest);
         writer.write_point_m_shape()?;
         Ok(())
@@ -477,6 +1072,10 @@ impl WritableShape for PolygonM {
 }
 
 impl EsriShape for PolygonM {
+    fn has_finite_coords(&self) -> bool {
+        super::are_xy_finite(self.rings.iter().flat_map(|ring| ring.points()))
+    }
+
     fn x_range(&self) -> [f64; 2] {
         self.bbox.x_range()
     }
@@ -488,6 +1087,23 @@ impl EsriShape for PolygonM {
     fn m_range(&self) -> [f64; 2] {
         self.bbox.m_range()
     }
+
+    fn size_in_bytes_2d(&self) -> usize {
+        let mut size = 0_usize;
+        size += size_of::<f64>() * 4;
+        size += size_of::<i32>(); // num parts
+        size += size_of::<i32>(); //num points
+        size += size_of::<i32>() * self.file_rings().len();
+        size += 2 * size_of::<f64>() * self.total_point_count();
+        size
+    }
+
+    fn write_2d_to<T: Write>(&self, dest: &mut T) -> Result<(), Error> {
+        let parts_iter = self.file_rings().iter().map(|ring| ring.points());
+        let writer = MultiPartShapeWriter::new(&self.bbox, parts_iter, dest);
+        writer.write_point_shape()?;
+        Ok(())
+    }
 }
 
 /*
@@ -498,9 +1114,20 @@ impl EsriShape for PolygonM {
 /// ( collection of [PointZ](../point/struct.PointZ.html))
 pub type PolygonZ = GenericPolygon<PointZ>;
 
+impl PolygonZ {
+    /// Clips the polygon to the bounding box (only the `x` and `y` of the bbox are used)
+    ///
+    /// The `z` and `m` values of the points created on the bbox border are interpolated.
+    ///
+    /// See [Polygon::clip_to_bbox]
+    pub fn clip_to_bbox(&self, bbox: &GenericBBox<PointZ>) -> Option<Self> {
+        clip_polygon(self, bbox)
+    }
+}
+
 impl fmt::Display for PolygonZ {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "PolygonZ({} rings)", self.rings.len())
+        write!(f, "PolygonZ({} rings)", self.file_rings().len())
     }
 }
 
@@ -512,7 +1139,17 @@ impl HasShapeType for PolygonZ {
 
 impl ConcreteReadableShape for PolygonZ {
     fn read_shape_content<T: Read>(source: &mut T, record_size: i32) -> Result<Self, Error> {
-        PolylineZ::read_shape_content(source, record_size).map(PolygonZ::from)
+        Self::read_shape_content_with_options(source, record_size, ReadOptions::default())
+    }
+
+    fn read_shape_content_with_options<T: Read>(
+        source: &mut T,
+        record_size: i32,
+        options: ReadOptions,
+    ) -> Result<Self, Error> {
+        PolylineZ::read_shape_content_with_options(source, record_size, options)
+            .map(PolygonZ::from)
+            .map(PolygonZ::attach_orphan_inner_rings)
     }
 }
 
@@ -522,7 +1159,7 @@ impl WritableShape for PolygonZ {
         size += size_of::<f64>() * 4;
         size += size_of::<i32>(); // num parts
         size += size_of::<i32>(); //num points
-        size += size_of::<i32>() * self.rings.len();
+        size += size_of::<i32>() * self.file_rings().len();
         size += 4 * size_of::<f64>() * self.total_point_count();
         size += 2 * size_of::<f64>();
         size += 2 * size_of::<f64>();
@@ -530,7 +1167,7 @@ impl WritableShape for PolygonZ {
     }
 
     fn write_to<T: Write>(&self, dest: &mut T) -> Result<(), Error> {
-        let parts_iter = self.rings().iter().map(|ring| ring.points());
+        let parts_iter = self.file_rings().iter().map(|ring| ring.points());
         let writer = MultiPartShapeWriter::new(&self.bbox, parts_iter, dest);
         writer.write_point_z_shape()?;
         Ok(())
@@ -538,6 +1175,10 @@ impl WritableShape for PolygonZ {
 }
 
 impl EsriShape for PolygonZ {
+    fn has_finite_coords(&self) -> bool {
+        super::are_xy_finite(self.rings.iter().flat_map(|ring| ring.points()))
+    }
+
     fn x_range(&self) -> [f64; 2] {
         self.bbox.x_range()
     }
@@ -553,6 +1194,23 @@ impl EsriShape for PolygonZ {
     fn m_range(&self) -> [f64; 2] {
         self.bbox.m_range()
     }
+
+    fn size_in_bytes_2d(&self) -> usize {
+        let mut size = 0_usize;
+        size += size_of::<f64>() * 4;
+        size += size_of::<i32>(); // num parts
+        size += size_of::<i32>(); //num points
+        size += size_of::<i32>() * self.file_rings().len();
+        size += 2 * size_of::<f64>() * self.total_point_count();
+        size
+    }
+
+    fn write_2d_to<T: Write>(&self, dest: &mut T) -> Result<(), Error> {
+        let parts_iter = self.file_rings().iter().map(|ring| ring.points());
+        let writer = MultiPartShapeWriter::new(&self.bbox, parts_iter, dest);
+        writer.write_point_shape()?;
+        Ok(())
+    }
 }
 
 #[cfg(feature = "geo-types")]
@@ -586,8 +1244,9 @@ where
                     if let Some(poly) = last_poly.as_mut() {
                         poly.interiors_push(interior);
                     } else {
-                        // This is the strange (?) case: inner ring without a previous outer ring
-                        polygons.push(geo_types::Polygon::<f64>::new(
+                        // Inner ring without a previous outer ring,
+                        // the same as following an empty outer ring
+                        last_poly = Some(geo_types::Polygon::<f64>::new(
                             LineString::<f64>::from(Vec::<Coordinate<f64>>::new()),
                             vec![LineString::from(interior)],
                         ));
@@ -628,6 +1287,51 @@ where
     }
 }
 
+#[cfg(feature = "geo-types")]
+impl PolygonZ {
+    /// Creates a PolygonZ from a geo_types Polygon, the z of each vertex
+    /// being given by `z_fn`
+    ///
+    /// The rings are handled like in the `From<geo_types::Polygon<f64>>` conversion,
+    /// only the z values differ. The measures are [NO_DATA].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "geo-types")]
+    /// # fn main() {
+    /// use shapefile::PolygonZ;
+    /// let polygon = geo_types::Polygon::new(
+    ///     vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 0.0)].into(),
+    ///     vec![],
+    /// );
+    /// let polygon_z = PolygonZ::from_geo_with_z(polygon, |coord| coord.x * 10.0);
+    /// assert_eq!(polygon_z.bbox().z_range(), [0.0, 10.0]);
+    /// # }
+    /// # #[cfg(not(feature = "geo-types"))]
+    /// # fn main() {}
+    /// ```
+    pub fn from_geo_with_z<F>(polygon: geo_types::Polygon<f64>, mut z_fn: F) -> Self
+    where
+        F: FnMut(geo_types::Coordinate<f64>) -> f64,
+    {
+        let mut to_point =
+            |coord: geo_types::Coordinate<f64>| PointZ::new(coord.x, coord.y, z_fn(coord), NO_DATA);
+        let (outer, inners) = polygon.into_inner();
+        let mut rings = Vec::<PolygonRing<PointZ>>::with_capacity(inners.len() + 1);
+
+        rings.push(PolygonRing::Outer(
+            outer.0.into_iter().map(&mut to_point).collect(),
+        ));
+        for inner in inners {
+            rings.push(PolygonRing::Inner(
+                inner.0.into_iter().map(&mut to_point).collect(),
+            ));
+        }
+        Self::with_rings(rings)
+    }
+}
+
 #[cfg(feature = "geo-types")]
 impl<PointType> From<geo_types::MultiPolygon<f64>> for GenericPolygon<PointType>
 where
@@ -649,6 +1353,291 @@ where
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_polygon_is_empty() {
+        let polygon = PolygonZ::default();
+        assert!(polygon.rings().is_empty());
+        assert_eq!(polygon.total_point_count(), 0);
+        assert_eq!(polygon.bbox(), &GenericBBox::<PointZ>::default());
+    }
+
+    #[test]
+    fn test_with_rings_unchecked_keeps_point_order() {
+        // Inner ring in clockwise order and not closed
+        let outer = vec![
+            Point::new(0.0, 0.0),
+            Point::new(0.0, 10.0),
+            Point::new(10.0, 10.0),
+            Point::new(10.0, 0.0),
+            Point::new(0.0, 0.0),
+        ];
+        let inner = vec![
+            Point::new(2.0, 2.0),
+            Point::new(2.0, 4.0),
+            Point::new(4.0, 4.0),
+        ];
+        let rings = vec![
+            PolygonRing::Outer(outer.clone()),
+            PolygonRing::Inner(inner.clone()),
+        ];
+
+        let polygon = Polygon::with_rings_unchecked(rings.clone());
+        assert_eq!(polygon.rings(), rings.as_slice());
+        assert_eq!(polygon.bbox().x_range(), [0.0, 10.0]);
+
+        let polygon = Polygon::with_rings(rings);
+        assert_ne!(polygon.rings()[1].points(), inner.as_slice());
+        assert_eq!(polygon.rings()[0].points(), outer.as_slice());
+    }
+
+    #[test]
+    fn test_polygon_outer_ring_count() {
+        let outer = |x: f64| {
+            PolygonRing::Outer(vec![
+                Point::new(x, 0.0),
+                Point::new(x, 10.0),
+                Point::new(x + 10.0, 10.0),
+                Point::new(x + 10.0, 0.0),
+                Point::new(x, 0.0),
+            ])
+        };
+        let inner = PolygonRing::Inner(vec![
+            Point::new(2.0, 2.0),
+            Point::new(4.0, 2.0),
+            Point::new(4.0, 4.0),
+            Point::new(2.0, 4.0),
+            Point::new(2.0, 2.0),
+        ]);
+
+        let polygon = Polygon::with_rings(vec![outer(0.0), inner]);
+        assert_eq!(polygon.outer_ring_count(), 1);
+        assert!(!polygon.is_multi());
+
+        let polygon = Polygon::with_rings(vec![outer(0.0), outer(20.0)]);
+        assert_eq!(polygon.outer_ring_count(), 2);
+        assert!(polygon.is_multi());
+    }
+
+    #[test]
+    fn test_polygon_into_polygons() {
+        let outer = |x: f64| {
+            PolygonRing::Outer(vec![
+                Point::new(x, 0.0),
+                Point::new(x, 10.0),
+                Point::new(x + 10.0, 10.0),
+                Point::new(x + 10.0, 0.0),
+                Point::new(x, 0.0),
+            ])
+        };
+        let inner = |x: f64| {
+            PolygonRing::Inner(vec![
+                Point::new(x + 2.0, 2.0),
+                Point::new(x + 4.0, 2.0),
+                Point::new(x + 4.0, 4.0),
+                Point::new(x + 2.0, 4.0),
+                Point::new(x + 2.0, 2.0),
+            ])
+        };
+        let polygon = Polygon::with_rings(vec![outer(0.0), inner(0.0), outer(20.0), inner(20.0)]);
+
+        let polygons = polygon.into_polygons();
+        assert_eq!(polygons.len(), 2);
+        assert_eq!(
+            polygons[0],
+            Polygon::with_rings(vec![outer(0.0), inner(0.0)])
+        );
+        assert_eq!(
+            polygons[1],
+            Polygon::with_rings(vec![outer(20.0), inner(20.0)])
+        );
+        assert_eq!(polygons[1].bbox().x_range(), [20.0, 30.0]);
+        assert!(polygons.iter().all(|polygon| !polygon.is_multi()));
+    }
+
+    #[test]
+    fn test_bowtie_ring_is_not_simple() {
+        let bowtie = PolygonRing::Outer(vec![
+            Point::new(0.0, 0.0),
+            Point::new(0.0, 1.0),
+            Point::new(1.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(0.0, 0.0),
+        ]);
+        assert!(!bowtie.is_simple());
+        assert!(!Polygon::new(bowtie).is_valid());
+
+        let square = PolygonRing::Outer(vec![
+            Point::new(0.0, 0.0),
+            Point::new(0.0, 1.0),
+            Point::new(1.0, 1.0),
+            Point::new(1.0, 0.0),
+            Point::new(0.0, 0.0),
+        ]);
+        assert!(square.is_simple());
+        assert!(Polygon::new(square.clone()).is_valid());
+
+        // Wrong winding for an outer ring
+        let reversed = PolygonRing::Outer(square.into_inner().into_iter().rev().collect());
+        assert!(reversed.is_simple());
+        let polygon = Polygon {
+            bbox: GenericBBox::default(),
+            rings: vec![reversed],
+        };
+        assert!(!polygon.is_valid());
+    }
+
+    #[test]
+    fn test_polygon_clip_to_bbox() {
+        // The square overhangs the bbox on its right and top sides
+        let polygon = Polygon::with_rings(vec![
+            PolygonRing::Outer(vec![
+                Point::new(0.0, 0.0),
+                Point::new(0.0, 10.0),
+                Point::new(10.0, 10.0),
+                Point::new(10.0, 0.0),
+                Point::new(0.0, 0.0),
+            ]),
+            // Partially inside the bbox
+            PolygonRing::Inner(vec![
+                Point::new(4.0, 4.0),
+                Point::new(8.0, 4.0),
+                Point::new(8.0, 8.0),
+                Point::new(4.0, 8.0),
+                Point::new(4.0, 4.0),
+            ]),
+            // Outside of the bbox
+            PolygonRing::Inner(vec![
+                Point::new(7.0, 1.0),
+                Point::new(9.0, 1.0),
+                Point::new(9.0, 3.0),
+                Point::new(7.0, 1.0),
+            ]),
+        ]);
+        let bbox = GenericBBox::<Point>::from_ranges([-5.0, 6.0], [-5.0, 6.0]);
+        let clipped = polygon.clip_to_bbox(&bbox).unwrap();
+
+        assert_eq!(clipped.rings().len(), 2);
+        assert!(clipped.is_valid());
+        assert_eq!(clipped.bbox().min, Point::new(0.0, 0.0));
+        assert_eq!(clipped.bbox().max, Point::new(6.0, 6.0));
+
+        let outer = &clipped.rings()[0];
+        assert!(matches!(outer, PolygonRing::Outer(_)));
+        assert_eq!(outer.len(), 5);
+        for point in &[
+            Point::new(0.0, 0.0),
+            Point::new(0.0, 6.0),
+            Point::new(6.0, 6.0),
+            Point::new(6.0, 0.0),
+        ] {
+            assert!(outer.points().contains(point));
+        }
+
+        let inner = &clipped.rings()[1];
+        assert!(matches!(inner, PolygonRing::Inner(_)));
+        assert_eq!(inner.len(), 5);
+        for point in &[
+            Point::new(4.0, 4.0),
+            Point::new(6.0, 4.0),
+            Point::new(6.0, 6.0),
+            Point::new(4.0, 6.0),
+        ] {
+            assert!(inner.points().contains(point));
+        }
+    }
+
+    #[test]
+    fn test_polygon_z_clip_interpolates_z() {
+        let polygon = PolygonZ::new(PolygonRing::Outer(vec![
+            PointZ::new(0.0, 0.0, 0.0, NO_DATA),
+            PointZ::new(0.0, 10.0, 0.0, NO_DATA),
+            PointZ::new(10.0, 10.0, 10.0, NO_DATA),
+            PointZ::new(10.0, 0.0, 10.0, NO_DATA),
+            PointZ::new(0.0, 0.0, 0.0, NO_DATA),
+        ]));
+        let bbox =
+            GenericBBox::<PointZ>::from_ranges([0.0, 5.0], [0.0, 10.0], [0.0, 0.0], [0.0, 0.0]);
+        let clipped = polygon.clip_to_bbox(&bbox).unwrap();
+        assert!(clipped.rings()[0]
+            .points()
+            .contains(&PointZ::new(5.0, 10.0, 5.0, NO_DATA)));
+    }
+
+    #[test]
+    fn test_polygon_rings_mut_and_recompute_bbox() {
+        let mut polygon = Polygon::new(PolygonRing::Outer(vec![
+            Point::new(0.0, 0.0),
+            Point::new(0.0, 2.0),
+            Point::new(2.0, 2.0),
+            Point::new(2.0, 0.0),
+            Point::new(0.0, 0.0),
+        ]));
+        if let PolygonRing::Outer(points) = &mut polygon.rings_mut()[0] {
+            points[2] = Point::new(5.0, 3.0);
+        }
+        // The bbox is stale until recomputed
+        assert_eq!(polygon.bbox().max, Point::new(2.0, 2.0));
+
+        polygon.recompute_bbox();
+        assert_eq!(polygon.bbox().min, Point::new(0.0, 0.0));
+        assert_eq!(polygon.bbox().max, Point::new(5.0, 3.0));
+    }
+
+    #[test]
+    fn test_polygon_into_polyline() {
+        let polygon = Polygon::with_rings(vec![
+            PolygonRing::Outer(vec![
+                Point::new(0.0, 0.0),
+                Point::new(0.0, 4.0),
+                Point::new(4.0, 4.0),
+                Point::new(4.0, 0.0),
+                Point::new(0.0, 0.0),
+            ]),
+            PolygonRing::Inner(vec![
+                Point::new(1.0, 1.0),
+                Point::new(2.0, 1.0),
+                Point::new(2.0, 2.0),
+                Point::new(1.0, 2.0),
+                Point::new(1.0, 1.0),
+            ]),
+        ]);
+        let polyline = Polyline::from(polygon.clone());
+
+        assert_eq!(polyline.parts().len(), 2);
+        assert_eq!(polyline.parts()[0].as_slice(), polygon.rings()[0].points());
+        assert_eq!(polyline.parts()[1].as_slice(), polygon.rings()[1].points());
+        assert_eq!(polyline.bbox(), polygon.bbox());
+    }
+
+    #[test]
+    fn test_polygon_remove_consecutive_duplicates() {
+        let mut polygon = Polygon::new(PolygonRing::Outer(vec![
+            Point::new(0.0, 0.0),
+            Point::new(0.0, 0.0),
+            Point::new(0.0, 2.0),
+            Point::new(2.0, 2.0),
+            Point::new(2.0, 2.0),
+            Point::new(2.0, 0.0),
+            Point::new(0.0, 0.0),
+        ]));
+        polygon.remove_consecutive_duplicates();
+        assert_eq!(
+            polygon.rings()[0].points(),
+            &[
+                Point::new(0.0, 0.0),
+                Point::new(0.0, 2.0),
+                Point::new(2.0, 2.0),
+                Point::new(2.0, 0.0),
+                Point::new(0.0, 0.0),
+            ]
+        );
+    }
+}
+
 #[cfg(test)]
 #[cfg(feature = "geo-types")]
 mod test_geo_types {
@@ -817,4 +1806,28 @@ mod test_geo_types {
 
         assert_eq!(converted_polygon, expected_polygon);
     }
+
+    #[test]
+    fn geotypes_polygon_to_polygon_z_with_z() {
+        let polygon = geo_types::Polygon::new(
+            LineString::from(vec![(0.0, 0.0), (0.0, 4.0), (3.0, 4.0), (0.0, 0.0)]),
+            vec![LineString::from(vec![
+                (0.5, 2.0),
+                (1.0, 3.0),
+                (1.0, 2.0),
+                (0.5, 2.0),
+            ])],
+        );
+        let polygon_z = PolygonZ::from_geo_with_z(polygon, |coord| coord.x + coord.y);
+
+        assert_eq!(polygon_z.rings().len(), 2);
+        assert!(polygon_z.rings()[0]
+            .points()
+            .contains(&PointZ::new(3.0, 4.0, 7.0, NO_DATA)));
+        assert!(polygon_z.rings()[1]
+            .points()
+            .contains(&PointZ::new(1.0, 3.0, 4.0, NO_DATA)));
+        assert!(matches!(polygon_z.rings()[1], PolygonRing::Inner(_)));
+        assert_eq!(polygon_z.bbox().z_range(), [0.0, 7.0]);
+    }
 }
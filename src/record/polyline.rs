@@ -1,14 +1,16 @@
 //! Module with the definition of Polyline, PolylineM, PolylineZ
 
+use crate::reader::ReadOptions;
 use std::fmt;
 use std::io::{Read, Write};
 use std::mem::size_of;
 
 use super::io::*;
+use super::multipoint::GenericMultipoint;
 use super::traits::{GrowablePoint, ShrinkablePoint};
 use super::ConcreteReadableShape;
 use super::GenericBBox;
-use super::{Error, ShapeType};
+use super::{is_no_data, Error, ShapeType, NO_DATA};
 use super::{EsriShape, HasShapeType, WritableShape};
 use super::{Point, PointM, PointZ};
 
@@ -41,9 +43,11 @@ use geo_types;
 /// # fn main() {}
 /// ```
 ///
+/// The [`Default`] polyline has no parts and a default (zeroed) bounding box.
+///
 /// [`new`]: #method.new
 /// [`with_parts`]: #method.with_parts
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Default, Clone, PartialEq)]
 pub struct GenericPolyline<PointType> {
     pub(crate) bbox: GenericBBox<PointType>,
     pub(crate) parts: Vec<Vec<PointType>>,
@@ -65,12 +69,12 @@ impl<PointType: ShrinkablePoint + GrowablePoint + Copy> GenericPolyline<PointTyp
     ///
     /// # panic
     ///
-    /// This will panic if the vec has less than 2 points
+    /// This will panic if the vec has less than 2 points,
+    /// so it is meant for trusted input only, see [`try_new`].
+    ///
+    /// [`try_new`]: #method.try_new
     pub fn new(points: Vec<PointType>) -> Self {
-        assert!(
-            points.len() >= 2,
-            "Polylines parts must have at least 2 points"
-        );
+        assert!(points.len() >= 2, "{}", NOT_ENOUGH_POINTS_MSG);
         Self {
             bbox: GenericBBox::<PointType>::from_points(&points),
             parts: vec![points],
@@ -102,17 +106,190 @@ impl<PointType: ShrinkablePoint + GrowablePoint + Copy> GenericPolyline<PointTyp
     ///
     /// # panic
     ///
-    /// This will panic if any of the parts are less than 2 points
+    /// This will panic if any of the parts are less than 2 points,
+    /// so it is meant for trusted input only, see [`try_with_parts`].
+    ///
+    /// [`try_with_parts`]: #method.try_with_parts
     pub fn with_parts(parts: Vec<Vec<PointType>>) -> Self {
         assert!(
             parts.iter().all(|p| p.len() >= 2),
-            "Polylines parts must have at least 2 points"
+            "{}",
+            NOT_ENOUGH_POINTS_MSG
         );
         Self {
             bbox: GenericBBox::<PointType>::from_parts(&parts),
             parts,
         }
     }
+
+    /// Same as [`new`] but returns an error instead of panicking
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidShape`] if the vec has less than 2 points
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use shapefile::{Point, Polyline};
+    /// assert!(Polyline::try_new(vec![Point::new(1.0, 1.0)]).is_err());
+    /// assert!(Polyline::try_new(vec![Point::new(1.0, 1.0), Point::new(2.0, 2.0)]).is_ok());
+    /// ```
+    ///
+    /// [`new`]: #method.new
+    /// [`Error::InvalidShape`]: ../../enum.Error.html#variant.InvalidShape
+    pub fn try_new(points: Vec<PointType>) -> Result<Self, Error> {
+        if points.len() < 2 {
+            return Err(Error::InvalidShape(NOT_ENOUGH_POINTS_MSG));
+        }
+        Ok(Self::new(points))
+    }
+
+    /// Same as [`with_parts`] but returns an error instead of panicking
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidShape`] if there are no parts or
+    /// if any of the parts has less than 2 points
+    ///
+    /// [`with_parts`]: #method.with_parts
+    /// [`Error::InvalidShape`]: ../../enum.Error.html#variant.InvalidShape
+    pub fn try_with_parts(parts: Vec<Vec<PointType>>) -> Result<Self, Error> {
+        if parts.is_empty() {
+            return Err(Error::InvalidShape("Polylines must have at least 1 part"));
+        }
+        if parts.iter().any(|p| p.len() < 2) {
+            return Err(Error::InvalidShape(NOT_ENOUGH_POINTS_MSG));
+        }
+        Ok(Self::with_parts(parts))
+    }
+
+    /// Returns a multipoint made of the points of all the parts
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use shapefile::{Point, Polyline};
+    /// let polyline = Polyline::with_parts(vec![
+    ///     vec![Point::new(1.0, 1.0), Point::new(2.0, 2.0)],
+    ///     vec![Point::new(3.0, 3.0), Point::new(4.0, 4.0)],
+    /// ]);
+    /// let multipoint = polyline.to_multipoint();
+    /// assert_eq!(multipoint.points().len(), 4);
+    /// ```
+    pub fn to_multipoint(&self) -> GenericMultipoint<PointType> {
+        GenericMultipoint::new(self.parts.iter().flatten().copied().collect())
+    }
+}
+
+const NOT_ENOUGH_POINTS_MSG: &str = "Polylines parts must have at least 2 points";
+
+impl<PointType> GenericPolyline<PointType>
+where
+    PointType: ShrinkablePoint + GrowablePoint + PartialEq + Copy,
+{
+    /// Removes the consecutive repeated points in each part
+    /// and recomputes the bounding box.
+    ///
+    /// Points are compared with all their coordinates (so including `m` and `z`).
+    ///
+    /// Note that parts made only of the same point
+    /// will end up with less than 2 points.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use shapefile::{Point, Polyline};
+    /// let mut polyline = Polyline::new(vec![
+    ///     Point::new(1.0, 1.0),
+    ///     Point::new(1.0, 1.0),
+    ///     Point::new(2.0, 2.0),
+    /// ]);
+    /// polyline.remove_consecutive_duplicates();
+    /// assert_eq!(polyline.total_point_count(), 2);
+    /// ```
+    pub fn remove_consecutive_duplicates(&mut self) {
+        for part in self.parts.iter_mut() {
+            part.dedup();
+        }
+        if let Some(bbox) = GenericBBox::from_points_iter(self.parts.iter().flatten()) {
+            self.bbox = bbox;
+        }
+    }
+}
+
+impl<PointType> GenericPolyline<PointType>
+where
+    PointType: ShrinkablePoint + GrowablePoint + Copy,
+{
+    /// Applies `f` on each point and recomputes the bounding box
+    pub(crate) fn for_each_point_mut<F: FnMut(&mut PointType)>(&mut self, f: F) {
+        self.parts.iter_mut().flatten().for_each(f);
+        if let Some(bbox) = GenericBBox::from_points_iter(self.parts.iter().flatten()) {
+            self.bbox = bbox;
+        }
+    }
+
+    /// Merges the polylines into one multipart polyline
+    ///
+    /// Each part of each polyline becomes a part of the resulting polyline,
+    /// and the bounding box is recomputed.
+    ///
+    /// Merging no polylines gives the default (empty) polyline.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use shapefile::{Point, Polyline};
+    /// let first = Polyline::new(vec![Point::new(1.0, 1.0), Point::new(2.0, 2.0)]);
+    /// let second = Polyline::new(vec![Point::new(5.0, 5.0), Point::new(6.0, 7.0)]);
+    /// let merged = Polyline::merge(vec![first, second]);
+    /// assert_eq!(merged.parts().len(), 2);
+    /// assert_eq!(merged.bbox().max, Point::new(6.0, 7.0));
+    /// ```
+    pub fn merge<I: IntoIterator<Item = Self>>(polylines: I) -> Self
+    where
+        PointType: Default,
+    {
+        let parts = polylines
+            .into_iter()
+            .flat_map(|polyline| polyline.parts)
+            .collect::<Vec<_>>();
+        let bbox = GenericBBox::from_points_iter(parts.iter().flatten()).unwrap_or_default();
+        Self { bbox, parts }
+    }
+
+    /// Splits the polyline into single-part polylines,
+    /// each one with its own bounding box
+    ///
+    /// This is the inverse of [`merge`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use shapefile::{Point, Polyline};
+    /// let polyline = Polyline::with_parts(vec![
+    ///     vec![Point::new(1.0, 1.0), Point::new(2.0, 2.0)],
+    ///     vec![Point::new(5.0, 5.0), Point::new(6.0, 7.0)],
+    /// ]);
+    /// let polylines = polyline.split_parts();
+    /// assert_eq!(polylines.len(), 2);
+    /// assert_eq!(polylines[1].bbox().min, Point::new(5.0, 5.0));
+    /// ```
+    ///
+    /// [`merge`]: #method.merge
+    pub fn split_parts(self) -> Vec<Self>
+    where
+        PointType: Default,
+    {
+        self.parts
+            .into_iter()
+            .map(|part| Self {
+                bbox: GenericBBox::from_points_iter(part.iter()).unwrap_or_default(),
+                parts: vec![part],
+            })
+            .collect()
+    }
 }
 
 impl<PointType> GenericPolyline<PointType> {
@@ -177,7 +354,7 @@ impl HasShapeType for Polyline {
 
 impl ConcreteReadableShape for Polyline {
     fn read_shape_content<T: Read>(source: &mut T, record_size: i32) -> Result<Self, Error> {
-        let rdr = MultiPartShapeReader::<Point, T>::new(source)?;
+        let rdr = MultiPartShapeReader::<Point, T>::new(source, record_size)?;
         if record_size != Self::size_of_record(rdr.num_points, rdr.num_parts) as i32 {
             Err(Error::InvalidShapeRecordSize)
         } else {
@@ -209,6 +386,10 @@ impl WritableShape for Polyline {
 }
 
 impl EsriShape for Polyline {
+    fn has_finite_coords(&self) -> bool {
+        super::are_xy_finite(self.parts.iter().flatten())
+    }
+
     fn x_range(&self) -> [f64; 2] {
         self.bbox.x_range()
     }
@@ -235,6 +416,38 @@ impl PolylineM {
         }
         size
     }
+
+    /// Returns true if at least one point has a measure (`m` that is not _no data_)
+    ///
+    /// When no point has a measure, the optional M block is not written.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use shapefile::{PointM, PolylineM, NO_DATA};
+    /// let polyline = PolylineM::new(vec![
+    ///     PointM::new(1.0, 1.0, NO_DATA),
+    ///     PointM::new(2.0, 2.0, 4.0),
+    /// ]);
+    /// assert!(polyline.has_measures());
+    /// assert!(!polyline.without_measures().has_measures());
+    /// ```
+    pub fn has_measures(&self) -> bool {
+        self.parts
+            .iter()
+            .flatten()
+            .any(|point| !is_no_data(point.m))
+    }
+
+    /// Returns the polyline with the `m` of every point set to [NO_DATA](crate::NO_DATA)
+    pub fn without_measures(mut self) -> Self {
+        for point in self.parts.iter_mut().flatten() {
+            point.m = NO_DATA;
+        }
+        self.bbox.min.m = NO_DATA;
+        self.bbox.max.m = NO_DATA;
+        self
+    }
 }
 
 impl fmt::Display for PolylineM {
@@ -251,7 +464,15 @@ impl HasShapeType for PolylineM {
 
 impl ConcreteReadableShape for PolylineM {
     fn read_shape_content<T: Read>(source: &mut T, record_size: i32) -> Result<Self, Error> {
-        let rdr = MultiPartShapeReader::<PointM, T>::new(source)?;
+        Self::read_shape_content_with_options(source, record_size, ReadOptions::default())
+    }
+
+    fn read_shape_content_with_options<T: Read>(
+        source: &mut T,
+        record_size: i32,
+        options: ReadOptions,
+    ) -> Result<Self, Error> {
+        let rdr = MultiPartShapeReader::<PointM, T>::new(source, record_size)?;
 
         let record_size_with_m = Self::size_of_record(rdr.num_points, rdr.num_parts, true) as i32;
         let record_size_without_m =
@@ -261,7 +482,9 @@ impl ConcreteReadableShape for PolylineM {
             Err(Error::InvalidShapeRecordSize)
         } else {
             rdr.read_xy()
-                .and_then(|rdr| rdr.read_ms_if(record_size == record_size_with_m))
+                .and_then(|rdr| {
+                    rdr.read_ms_if(record_size == record_size_with_m, options.no_data_threshold)
+                })
                 .map_err(Error::IoError)
                 .map(|rdr| Self {
                     bbox: rdr.bbox,
@@ -278,20 +501,31 @@ impl WritableShape for PolylineM {
         size += size_of::<i32>(); // num parts
         size += size_of::<i32>(); //num points
         size += size_of::<i32>() * self.parts.len();
-        size += 3 * size_of::<f64>() * self.total_point_count();
-        size += 2 * size_of::<f64>();
+        size += 2 * size_of::<f64>() * self.total_point_count();
+        if self.has_measures() {
+            size += size_of::<f64>() * self.total_point_count();
+            size += 2 * size_of::<f64>();
+        }
         size
     }
 
     fn write_to<T: Write>(&self, dest: &mut T) -> Result<(), Error> {
         let parts_iter = self.parts.iter().map(|part| part.as_slice());
         let writer = MultiPartShapeWriter::new(&self.bbox, parts_iter, dest);
-        writer.write_point_m_shape()?;
+        if self.has_measures() {
+            writer.write_point_m_shape()?;
+        } else {
+            writer.write_point_m_shape_without_ms()?;
+        }
         Ok(())
     }
 }
 
 impl EsriShape for PolylineM {
+    fn has_finite_coords(&self) -> bool {
+        super::are_xy_finite(self.parts.iter().flatten())
+    }
+
     fn x_range(&self) -> [f64; 2] {
         self.bbox.x_range()
     }
@@ -303,6 +537,23 @@ impl EsriShape for PolylineM {
     fn m_range(&self) -> [f64; 2] {
         self.bbox.m_range()
     }
+
+    fn size_in_bytes_2d(&self) -> usize {
+        let mut size = 0usize;
+        size += 4 * size_of::<f64>();
+        size += size_of::<i32>();
+        size += size_of::<i32>();
+        size += size_of::<i32>() * self.parts.len();
+        size += 2 * size_of::<f64>() * self.total_point_count();
+        size
+    }
+
+    fn write_2d_to<T: Write>(&self, dest: &mut T) -> Result<(), Error> {
+        let parts_iter = self.parts.iter().map(|part| part.as_slice());
+        let writer = MultiPartShapeWriter::new(&self.bbox, parts_iter, dest);
+        writer.write_point_shape()?;
+        Ok(())
+    }
 }
 
 /*
@@ -340,7 +591,15 @@ impl HasShapeType for PolylineZ {
 
 impl ConcreteReadableShape for PolylineZ {
     fn read_shape_content<T: Read>(source: &mut T, record_size: i32) -> Result<Self, Error> {
-        let rdr = MultiPartShapeReader::<PointZ, T>::new(source)?;
+        Self::read_shape_content_with_options(source, record_size, ReadOptions::default())
+    }
+
+    fn read_shape_content_with_options<T: Read>(
+        source: &mut T,
+        record_size: i32,
+        options: ReadOptions,
+    ) -> Result<Self, Error> {
+        let rdr = MultiPartShapeReader::<PointZ, T>::new(source, record_size)?;
 
         let record_size_with_m = Self::size_of_record(rdr.num_points, rdr.num_parts, true) as i32;
         let record_size_without_m =
@@ -351,7 +610,9 @@ impl ConcreteReadableShape for PolylineZ {
         } else {
             rdr.read_xy()
                 .and_then(|rdr| rdr.read_zs())
-                .and_then(|rdr| rdr.read_ms_if(record_size == record_size_with_m))
+                .and_then(|rdr| {
+                    rdr.read_ms_if(record_size == record_size_with_m, options.no_data_threshold)
+                })
                 .map_err(Error::IoError)
                 .map(|rdr| Self {
                     bbox: rdr.bbox,
@@ -383,6 +644,10 @@ impl WritableShape for PolylineZ {
 }
 
 impl EsriShape for PolylineZ {
+    fn has_finite_coords(&self) -> bool {
+        super::are_xy_finite(self.parts.iter().flatten())
+    }
+
     fn x_range(&self) -> [f64; 2] {
         self.bbox.x_range()
     }
@@ -398,6 +663,23 @@ impl EsriShape for PolylineZ {
     fn m_range(&self) -> [f64; 2] {
         self.bbox.m_range()
     }
+
+    fn size_in_bytes_2d(&self) -> usize {
+        let mut size = 0usize;
+        size += 4 * size_of::<f64>();
+        size += size_of::<i32>();
+        size += size_of::<i32>();
+        size += size_of::<i32>() * self.parts.len();
+        size += 2 * size_of::<f64>() * self.total_point_count();
+        size
+    }
+
+    fn write_2d_to<T: Write>(&self, dest: &mut T) -> Result<(), Error> {
+        let parts_iter = self.parts.iter().map(|part| part.as_slice());
+        let writer = MultiPartShapeWriter::new(&self.bbox, parts_iter, dest);
+        writer.write_point_shape()?;
+        Ok(())
+    }
 }
 
 #[cfg(feature = "geo-types")]
@@ -460,6 +742,106 @@ where
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_polyline_multipoint_conversions() {
+        let polyline = Polyline::with_parts(vec![
+            vec![Point::new(1.0, 1.0), Point::new(2.0, 2.0)],
+            vec![
+                Point::new(3.0, 1.0),
+                Point::new(5.0, 6.0),
+                Point::new(7.0, 2.0),
+            ],
+        ]);
+        let multipoint = polyline.to_multipoint();
+        assert_eq!(
+            multipoint.points(),
+            &[
+                Point::new(1.0, 1.0),
+                Point::new(2.0, 2.0),
+                Point::new(3.0, 1.0),
+                Point::new(5.0, 6.0),
+                Point::new(7.0, 2.0),
+            ]
+        );
+        assert_eq!(multipoint.bbox(), polyline.bbox());
+
+        let points = multipoint.points().to_vec();
+        let back = multipoint.to_polyline().unwrap();
+        assert_eq!(back.parts(), &vec![points]);
+        assert_eq!(back.bbox(), polyline.bbox());
+
+        let single = GenericMultipoint::new(vec![Point::new(1.0, 1.0)]);
+        assert!(single.to_polyline().is_none());
+    }
+
+    #[test]
+    fn test_polyline_try_new() {
+        match Polyline::try_new(vec![Point::new(1.0, 1.0)]) {
+            Err(Error::InvalidShape(_)) => {}
+            _ => panic!("Expected an InvalidShape error"),
+        }
+        assert!(Polyline::try_with_parts(vec![]).is_err());
+        assert!(Polyline::try_with_parts(vec![
+            vec![Point::new(1.0, 1.0), Point::new(2.0, 2.0)],
+            vec![Point::new(1.0, 1.0)],
+        ])
+        .is_err());
+        let polyline = Polyline::try_new(vec![Point::new(1.0, 1.0), Point::new(2.0, 2.0)]).unwrap();
+        assert_eq!(polyline.total_point_count(), 2);
+    }
+
+    #[test]
+    fn test_polyline_merge() {
+        let first = Polyline::new(vec![Point::new(1.0, 1.0), Point::new(2.0, 2.0)]);
+        let second = Polyline::new(vec![Point::new(-1.0, 5.0), Point::new(0.0, 3.0)]);
+        let merged = Polyline::merge(vec![first.clone(), second.clone()]);
+
+        assert_eq!(merged.parts().len(), 2);
+        assert_eq!(merged.parts()[0], first.parts()[0]);
+        assert_eq!(merged.parts()[1], second.parts()[0]);
+        assert_eq!(merged.bbox().min, Point::new(-1.0, 1.0));
+        assert_eq!(merged.bbox().max, Point::new(2.0, 5.0));
+
+        assert_eq!(Polyline::merge(vec![]), Polyline::default());
+    }
+
+    #[test]
+    fn test_polyline_split_parts() {
+        let polyline = Polyline::with_parts(vec![
+            vec![Point::new(1.0, 1.0), Point::new(2.0, 2.0)],
+            vec![
+                Point::new(3.0, 3.0),
+                Point::new(4.0, 1.0),
+                Point::new(5.0, 0.0),
+            ],
+            vec![
+                Point::new(-1.0, 5.0),
+                Point::new(0.0, 3.0),
+                Point::new(1.0, 4.0),
+                Point::new(2.0, 6.0),
+            ],
+        ]);
+        let polylines = polyline.split_parts();
+
+        assert_eq!(polylines.len(), 3);
+        for (polyline, expected_count) in polylines.iter().zip([2, 3, 4]) {
+            assert_eq!(polyline.parts().len(), 1);
+            assert_eq!(polyline.total_point_count(), expected_count);
+        }
+        assert_eq!(polylines[1].bbox().min, Point::new(3.0, 0.0));
+        assert_eq!(polylines[1].bbox().max, Point::new(5.0, 3.0));
+        assert_eq!(polylines[2].bbox().min, Point::new(-1.0, 3.0));
+        assert_eq!(polylines[2].bbox().max, Point::new(2.0, 6.0));
+    }
+
+    #[test]
+    fn test_default_polyline_is_empty() {
+        let polyline = Polyline::default();
+        assert!(polyline.parts().is_empty());
+        assert_eq!(polyline.total_point_count(), 0);
+        assert_eq!(polyline.bbox(), &GenericBBox::<Point>::default());
+    }
+
     #[test]
     #[should_panic(expected = "Polylines parts must have at least 2 points")]
     fn test_polyline_new_less_than_2_points() {
@@ -474,6 +856,39 @@ mod tests {
             vec![Point::new(1.0, 1.0)],
         ]);
     }
+
+    #[test]
+    fn test_polyline_remove_consecutive_duplicates() {
+        let a = Point::new(1.0, 1.0);
+        let b = Point::new(2.0, 3.0);
+        let mut polyline = Polyline::with_parts(vec![vec![a, a, b, b, a], vec![b, a]]);
+        polyline.remove_consecutive_duplicates();
+        assert_eq!(polyline.parts(), &vec![vec![a, b, a], vec![b, a]]);
+        assert_eq!(polyline.bbox().min, a);
+        assert_eq!(polyline.bbox().max, b);
+    }
+
+    #[test]
+    fn test_polyline_z_remove_consecutive_duplicates() {
+        let a = PointZ::new(1.0, 1.0, 1.0, 0.0);
+        let b = PointZ::new(1.0, 1.0, 2.0, 0.0);
+        let mut polyline = PolylineZ::new(vec![a, a, b]);
+        polyline.remove_consecutive_duplicates();
+        assert_eq!(polyline.parts(), &vec![vec![a, b]]);
+    }
+
+    #[test]
+    fn test_polyline_remove_consecutive_duplicates_with_empty_part() {
+        let a = Point::new(1.0, 1.0);
+        let b = Point::new(2.0, 3.0);
+        let mut polyline = Polyline {
+            bbox: GenericBBox::from_points(&[Point::new(0.0, 0.0), Point::new(10.0, 10.0)]),
+            parts: vec![vec![a, a, b], vec![]],
+        };
+        polyline.remove_consecutive_duplicates();
+        assert_eq!(polyline.bbox().min, a);
+        assert_eq!(polyline.bbox().max, b);
+    }
 }
 
 #[cfg(test)]
@@ -1,3 +1,7 @@
+use super::multipatch::{Multipatch, Patch};
+use super::multipoint::GenericMultipoint;
+use super::polygon::{GenericPolygon, PolygonRing};
+use super::polyline::GenericPolyline;
 use super::{Point, PointM, PointZ};
 use crate::writer::{f64_max, f64_min};
 
@@ -114,6 +118,86 @@ impl HasMutZ for PointZ {
     }
 }
 
+/// Trait to promote a point to a [PointZ], used by [HasCoords]
+///
+/// Missing z are `0.0`, missing m are [NO_DATA](super::NO_DATA).
+pub(crate) trait ToPointZ {
+    fn to_point_z(&self) -> PointZ;
+}
+
+impl ToPointZ for Point {
+    fn to_point_z(&self) -> PointZ {
+        PointZ::new(self.x, self.y, 0.0, super::NO_DATA)
+    }
+}
+
+impl ToPointZ for PointM {
+    fn to_point_z(&self) -> PointZ {
+        PointZ::new(self.x, self.y, 0.0, self.m)
+    }
+}
+
+impl ToPointZ for PointZ {
+    fn to_point_z(&self) -> PointZ {
+        *self
+    }
+}
+
+/// Trait to iterate over all the vertices of a shape, promoted to [PointZ]
+///
+/// Missing z are `0.0` and missing m are [NO_DATA](super::NO_DATA).
+///
+/// This trait is implemented for all the points and concrete shapes.
+///
+/// # Example
+///
+/// ```
+/// use shapefile::record::traits::HasCoords;
+/// use shapefile::{Point, PointZ, Polyline, NO_DATA};
+/// let polyline = Polyline::new(vec![Point::new(1.0, 1.0), Point::new(2.0, 2.0)]);
+/// let coords = polyline.coords().collect::<Vec<_>>();
+/// assert_eq!(coords[1], PointZ::new(2.0, 2.0, 0.0, NO_DATA));
+/// ```
+pub trait HasCoords {
+    /// Returns an iterator over all the vertices
+    fn coords(&self) -> Box<dyn Iterator<Item = PointZ> + '_>;
+}
+
+impl<PointType: ToPointZ> HasCoords for PointType {
+    fn coords(&self) -> Box<dyn Iterator<Item = PointZ> + '_> {
+        Box::new(std::iter::once(self.to_point_z()))
+    }
+}
+
+impl<PointType: ToPointZ> HasCoords for GenericMultipoint<PointType> {
+    fn coords(&self) -> Box<dyn Iterator<Item = PointZ> + '_> {
+        Box::new(self.points().iter().map(ToPointZ::to_point_z))
+    }
+}
+
+impl<PointType: ToPointZ> HasCoords for GenericPolyline<PointType> {
+    fn coords(&self) -> Box<dyn Iterator<Item = PointZ> + '_> {
+        Box::new(self.parts().iter().flatten().map(ToPointZ::to_point_z))
+    }
+}
+
+impl<PointType: ToPointZ> HasCoords for GenericPolygon<PointType> {
+    fn coords(&self) -> Box<dyn Iterator<Item = PointZ> + '_> {
+        Box::new(
+            self.rings()
+                .iter()
+                .flat_map(PolygonRing::points)
+                .map(ToPointZ::to_point_z),
+        )
+    }
+}
+
+impl HasCoords for Multipatch {
+    fn coords(&self) -> Box<dyn Iterator<Item = PointZ> + '_> {
+        Box::new(self.patches().iter().flat_map(Patch::points).copied())
+    }
+}
+
 pub trait ShrinkablePoint {
     fn shrink(&mut self, other: &Self);
 }
@@ -0,0 +1,205 @@
+//! Writing of spatial index files
+//!
+//! The only supported format is the `.qix` quadtree, as written
+//! by MapServer's `shptree` and understood by GDAL/OGR and QGIS.
+use std::io::Write;
+
+use byteorder::{LittleEndian, WriteBytesExt};
+
+use crate::record::GenericBBox;
+use crate::{Error, Point};
+
+/// Ratio used when splitting the bounds of a node,
+/// it is higher than 0.5 so that the children overlap a bit.
+const SPLIT_RATIO: f64 = 0.55;
+/// Byte order flag meaning the file is in little endian
+const LSB_ORDER: u8 = 1;
+const QIX_VERSION: u8 = 1;
+
+/// A rectangle as (xmin, ymin, xmax, ymax)
+type Rect = [f64; 4];
+
+fn rect_from_bbox(bbox: &GenericBBox<Point>) -> Rect {
+    [bbox.min.x, bbox.min.y, bbox.max.x, bbox.max.y]
+}
+
+fn rect_contains(outer: &Rect, inner: &Rect) -> bool {
+    inner[0] >= outer[0] && inner[2] <= outer[2] && inner[1] >= outer[1] && inner[3] <= outer[3]
+}
+
+/// Splits the rect in two overlapping halves, along its longest dimension
+fn split_rect(rect: &Rect) -> (Rect, Rect) {
+    let width = rect[2] - rect[0];
+    let height = rect[3] - rect[1];
+    let (mut first, mut second) = (*rect, *rect);
+    if width > height {
+        first[2] = rect[0] + width * SPLIT_RATIO;
+        second[0] = rect[2] - width * SPLIT_RATIO;
+    } else {
+        first[3] = rect[1] + height * SPLIT_RATIO;
+        second[1] = rect[3] - height * SPLIT_RATIO;
+    }
+    (first, second)
+}
+
+struct QuadTreeNode {
+    rect: Rect,
+    shape_ids: Vec<i32>,
+    subnodes: Vec<QuadTreeNode>,
+}
+
+impl QuadTreeNode {
+    fn new(rect: Rect) -> Self {
+        Self {
+            rect,
+            shape_ids: vec![],
+            subnodes: vec![],
+        }
+    }
+
+    /// Inserts the shape in the deepest node that fully contains its rect
+    fn insert(&mut self, id: i32, rect: &Rect, max_depth: u32) {
+        if max_depth > 1 {
+            if self.subnodes.is_empty() {
+                let (half1, half2) = split_rect(&self.rect);
+                let (quad1, quad2) = split_rect(&half1);
+                let (quad3, quad4) = split_rect(&half2);
+                let quads = [quad1, quad2, quad3, quad4];
+                if quads.iter().any(|quad| rect_contains(quad, rect)) {
+                    self.subnodes = quads.iter().copied().map(QuadTreeNode::new).collect();
+                }
+            }
+            if let Some(subnode) = self
+                .subnodes
+                .iter_mut()
+                .find(|subnode| rect_contains(&subnode.rect, rect))
+            {
+                subnode.insert(id, rect, max_depth - 1);
+                return;
+            }
+        }
+        self.shape_ids.push(id);
+    }
+
+    /// Removes the subnodes that have no shapes, returns true if the node itself is empty
+    fn trim(&mut self) -> bool {
+        self.subnodes.retain_mut(|subnode| !subnode.trim());
+        self.subnodes.is_empty() && self.shape_ids.is_empty()
+    }
+
+    /// Size in bytes of the node, without its subnodes
+    fn size_in_bytes(&self) -> usize {
+        4 * std::mem::size_of::<f64>() + (self.shape_ids.len() + 3) * std::mem::size_of::<i32>()
+    }
+
+    /// Size in bytes taken by all the descendants of the node
+    fn subnodes_size_in_bytes(&self) -> usize {
+        self.subnodes
+            .iter()
+            .map(|subnode| subnode.size_in_bytes() + subnode.subnodes_size_in_bytes())
+            .sum()
+    }
+
+    fn write_to<W: Write>(&self, dest: &mut W) -> Result<(), Error> {
+        dest.write_i32::<LittleEndian>(self.subnodes_size_in_bytes() as i32)?;
+        for coord in &self.rect {
+            dest.write_f64::<LittleEndian>(*coord)?;
+        }
+        dest.write_i32::<LittleEndian>(self.shape_ids.len() as i32)?;
+        for id in &self.shape_ids {
+            dest.write_i32::<LittleEndian>(*id)?;
+        }
+        dest.write_i32::<LittleEndian>(self.subnodes.len() as i32)?;
+        for subnode in &self.subnodes {
+            subnode.write_to(dest)?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the depth of the tree, chosen so that there are
+/// about 8 shapes per leaf node, like `shptree` does
+fn default_max_depth(num_shapes: usize) -> u32 {
+    let mut max_depth = 0;
+    let mut num_nodes = 1;
+    while num_nodes * 4 < num_shapes {
+        max_depth += 1;
+        num_nodes *= 2;
+    }
+    max_depth
+}
+
+/// Writes a `.qix` quadtree index
+///
+/// `bboxes` are the bounding boxes of the non-null shapes, along with their
+/// (0 based) index in the file, `num_shapes` is the total number of shapes
+/// (including null shapes) and `bounds` the bounds of the whole file.
+pub(crate) fn write_qix<W: Write>(
+    dest: &mut W,
+    bboxes: &[(i32, GenericBBox<Point>)],
+    num_shapes: usize,
+    bounds: &GenericBBox<Point>,
+) -> Result<(), Error> {
+    let max_depth = default_max_depth(num_shapes);
+    let mut root = QuadTreeNode::new(rect_from_bbox(bounds));
+    for (id, bbox) in bboxes {
+        root.insert(*id, &rect_from_bbox(bbox), max_depth);
+    }
+    root.trim();
+
+    dest.write_all(b"SQT")?;
+    dest.write_all(&[LSB_ORDER, QIX_VERSION, 0, 0, 0])?;
+    dest.write_i32::<LittleEndian>(num_shapes as i32)?;
+    dest.write_i32::<LittleEndian>(max_depth as i32)?;
+    root.write_to(dest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_qix_single_node() {
+        let bounds = GenericBBox::<Point>::from_ranges([0.0, 10.0], [0.0, 10.0]);
+        let bboxes = vec![
+            (0, GenericBBox::<Point>::from_ranges([1.0, 2.0], [1.0, 2.0])),
+            (2, GenericBBox::<Point>::from_ranges([8.0, 9.0], [8.0, 9.0])),
+        ];
+        let mut dest = vec![];
+        write_qix(&mut dest, &bboxes, 3, &bounds).unwrap();
+
+        // 3 shapes only need a depth of 0, so all the ids are in the root node
+        let header_size = 8 + 2 * 4;
+        let root_size = 4 + 4 * 8 + 4 + 2 * 4 + 4;
+        assert_eq!(dest.len(), header_size + root_size);
+        assert_eq!(&dest[..3], b"SQT");
+        // The root has no descendants
+        assert_eq!(&dest[16..20], &[0, 0, 0, 0]);
+        assert_eq!(&dest[dest.len() - 4..], &[0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_qix_shapes_go_in_subnodes() {
+        let bounds = GenericBBox::<Point>::from_ranges([0.0, 100.0], [0.0, 100.0]);
+        let bboxes = (0..20)
+            .map(|i| {
+                let x = (i * 5) as f64;
+                (
+                    i,
+                    GenericBBox::<Point>::from_ranges([x, x + 1.0], [x, x + 1.0]),
+                )
+            })
+            .collect::<Vec<_>>();
+        let mut root = QuadTreeNode::new(rect_from_bbox(&bounds));
+        for (id, bbox) in &bboxes {
+            root.insert(*id, &rect_from_bbox(bbox), default_max_depth(20));
+        }
+        root.trim();
+
+        assert!(!root.subnodes.is_empty());
+        fn count_ids(node: &QuadTreeNode) -> usize {
+            node.shape_ids.len() + node.subnodes.iter().map(count_ids).sum::<usize>()
+        }
+        assert_eq!(count_ids(&root), 20);
+    }
+}
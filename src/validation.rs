@@ -0,0 +1,241 @@
+//! Validation of a whole shapefile, see [validate_file]
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+
+use crate::reader::bboxes_match;
+use crate::record::{is_part_closed, BBoxZ, PolygonRing};
+use crate::{Error, Shape, ShapeReader, ShapeType};
+
+/// Kind of problem found by [validate_file]
+#[derive(Debug, Clone, PartialEq)]
+pub enum IssueKind {
+    /// The _.shx_ file is missing
+    MissingShx,
+    /// The _.dbf_ file is missing
+    MissingDbf,
+    /// The _.shx_ does not have one entry per record of the _.shp_
+    ShxCountMismatch { shp: usize, shx: usize },
+    /// The _.dbf_ does not have one record per record of the _.shp_
+    DbfCountMismatch { shp: usize, dbf: usize },
+    /// The bounding box of the header is not the one of the shapes
+    BboxMismatch { header: BBoxZ, computed: BBoxZ },
+    /// The record could not be read (invalid record size, shape type, etc),
+    /// the validation of the following records stops there
+    UnreadableRecord(String),
+    /// The record is not of the shape type of the file (nor a null shape)
+    WrongShapeType {
+        expected: ShapeType,
+        actual: ShapeType,
+    },
+    /// The part of a polyline has less than 2 points
+    TooFewPartPoints { part: usize },
+    /// The ring of a polygon has less than 4 points
+    TooFewRingPoints { ring: usize },
+    /// The ring of a polygon is not closed
+    UnclosedRing { ring: usize },
+    /// The ring has the winding of an inner ring, but no outer ring comes before it
+    WrongRingWinding { ring: usize },
+}
+
+/// A problem found by [validate_file]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Issue {
+    /// The record number (starting at 1) of the shape that has the problem,
+    /// `None` for problems that concern the whole file
+    pub record_number: Option<usize>,
+    pub kind: IssueKind,
+}
+
+/// Result of [validate_file]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ValidationReport {
+    /// The problems found, in the order of the records
+    pub issues: Vec<Issue>,
+}
+
+impl ValidationReport {
+    /// Returns true if no problems were found
+    pub fn is_valid(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    fn push(&mut self, record_number: Option<usize>, kind: IssueKind) {
+        self.issues.push(Issue {
+            record_number,
+            kind,
+        });
+    }
+}
+
+/// Checks the parts of polylines and the rings of polygons
+fn validate_shape(shape: &Shape, record_number: usize, report: &mut ValidationReport) {
+    fn validate_parts<P>(parts: &[Vec<P>], record_number: usize, report: &mut ValidationReport) {
+        for (part, points) in parts.iter().enumerate() {
+            if points.len() < 2 {
+                report.push(Some(record_number), IssueKind::TooFewPartPoints { part });
+            }
+        }
+    }
+
+    fn validate_rings<P: PartialEq>(
+        rings: &[PolygonRing<P>],
+        record_number: usize,
+        report: &mut ValidationReport,
+    ) {
+        if let Some(PolygonRing::Inner(_)) = rings.first() {
+            report.push(Some(record_number), IssueKind::WrongRingWinding { ring: 0 });
+        }
+        for (ring, points) in rings.iter().map(PolygonRing::points).enumerate() {
+            if points.len() < 4 {
+                report.push(Some(record_number), IssueKind::TooFewRingPoints { ring });
+            }
+            if !is_part_closed(points) {
+                report.push(Some(record_number), IssueKind::UnclosedRing { ring });
+            }
+        }
+    }
+
+    match shape {
+        Shape::Polyline(shp) => validate_parts(shp.parts(), record_number, report),
+        Shape::PolylineM(shp) => validate_parts(shp.parts(), record_number, report),
+        Shape::PolylineZ(shp) => validate_parts(shp.parts(), record_number, report),
+        Shape::Polygon(shp) => validate_rings(shp.file_rings(), record_number, report),
+        Shape::PolygonM(shp) => validate_rings(shp.file_rings(), record_number, report),
+        Shape::PolygonZ(shp) => validate_rings(shp.file_rings(), record_number, report),
+        _ => {}
+    }
+}
+
+/// Validates a whole shapefile and returns the problems found
+///
+/// The following is checked:
+///
+/// - the _.shx_ and _.dbf_ exist and have as many entries as the _.shp_
+/// - the bounding box of the header is the one of the shapes
+/// - every record can be read (which checks the record sizes)
+///   and has the shape type of the file
+/// - the parts of polylines have at least 2 points
+/// - the rings of polygons are closed, have at least 4 points,
+///   and the first ring of each polygon has the winding of an outer ring
+///
+/// # Errors
+///
+/// Errors are only returned when the files cannot be opened,
+/// or when their headers cannot be read.
+///
+/// # Example
+///
+/// ```
+/// # fn main() -> Result<(), shapefile::Error> {
+/// let report = shapefile::validate_file("tests/data/line.shp")?;
+/// // There is no line.dbf
+/// assert_eq!(report.issues.len(), 1);
+/// assert_eq!(report.issues[0].kind, shapefile::validation::IssueKind::MissingDbf);
+/// # Ok(())
+/// # }
+/// ```
+pub fn validate_file<P: AsRef<Path>>(path: P) -> Result<ValidationReport, Error> {
+    let path = path.as_ref();
+    let mut report = ValidationReport::default();
+
+    // The records are read one after the other, without using the .shx
+    let mut reader = ShapeReader::new(BufReader::new(File::open(path)?))?;
+    let header = *reader.header();
+
+    let mut computed_bbox = BBoxZ::inverted();
+    let mut is_empty = true;
+    let mut shp_count = 0;
+    let mut all_read = true;
+    for shape in reader.iter_shapes() {
+        let record_number = shp_count + 1;
+        let shape = match shape {
+            Ok(shape) => shape,
+            Err(error) => {
+                report.push(
+                    Some(record_number),
+                    IssueKind::UnreadableRecord(error.to_string()),
+                );
+                all_read = false;
+                break;
+            }
+        };
+        shp_count += 1;
+
+        let shape_type = shape.shapetype();
+        if shape_type != ShapeType::NullShape {
+            // Shape::shapetype does not distinguish multipoints
+            let actual = match &shape {
+                Shape::MultipointM(_) => ShapeType::MultipointM,
+                Shape::MultipointZ(_) => ShapeType::MultipointZ,
+                _ => shape_type,
+            };
+            if actual != header.shape_type {
+                report.push(
+                    Some(record_number),
+                    IssueKind::WrongShapeType {
+                        expected: header.shape_type,
+                        actual,
+                    },
+                );
+            }
+            computed_bbox.grow_from_any_shape(&shape);
+            is_empty = false;
+        }
+        validate_shape(&shape, record_number, &mut report);
+    }
+
+    if all_read {
+        if is_empty {
+            computed_bbox = BBoxZ::default();
+        }
+        computed_bbox.zero_ungrown_z_and_m();
+        if !bboxes_match(&header.bbox, &computed_bbox, header.shape_type) {
+            report.push(
+                None,
+                IssueKind::BboxMismatch {
+                    header: header.bbox,
+                    computed: computed_bbox,
+                },
+            );
+        }
+    }
+
+    let shx_path = path.with_extension("shx");
+    if shx_path.exists() {
+        let reader = ShapeReader::with_shx(
+            BufReader::new(File::open(path)?),
+            BufReader::new(File::open(shx_path)?),
+        )?;
+        let shx_count = reader.shape_count()?;
+        if all_read && shx_count != shp_count {
+            report.push(
+                None,
+                IssueKind::ShxCountMismatch {
+                    shp: shp_count,
+                    shx: shx_count,
+                },
+            );
+        }
+    } else {
+        report.push(None, IssueKind::MissingShx);
+    }
+
+    let dbf_path = path.with_extension("dbf");
+    if dbf_path.exists() {
+        let dbf_count = dbase::Reader::from_path(dbf_path)?.header().num_records as usize;
+        if all_read && dbf_count != shp_count {
+            report.push(
+                None,
+                IssueKind::DbfCountMismatch {
+                    shp: shp_count,
+                    dbf: dbf_count,
+                },
+            );
+        }
+    } else {
+        report.push(None, IssueKind::MissingDbf);
+    }
+
+    Ok(report)
+}
@@ -0,0 +1,279 @@
+//! Writing of shapes as EWKB (Extended Well-Known Binary),
+//! the binary format used by PostGIS
+use std::io::Write;
+
+use byteorder::{LittleEndian, WriteBytesExt};
+
+use crate::record::polygon::GenericPolygon;
+use crate::record::traits::HasXY;
+use crate::record::PolygonRing;
+use crate::{Error, Point, PointM, PointZ, Shape};
+
+/// Byte order flag meaning the geometry is in little endian
+const WKB_NDR: u8 = 1;
+
+const WKB_POINT: u32 = 1;
+const WKB_LINESTRING: u32 = 2;
+const WKB_POLYGON: u32 = 3;
+const WKB_MULTIPOINT: u32 = 4;
+const WKB_MULTILINESTRING: u32 = 5;
+const WKB_MULTIPOLYGON: u32 = 6;
+
+const EWKB_Z_FLAG: u32 = 0x8000_0000;
+const EWKB_M_FLAG: u32 = 0x4000_0000;
+const EWKB_SRID_FLAG: u32 = 0x2000_0000;
+
+/// Trait for the points that can be written in EWKB
+trait EwkbPoint: HasXY {
+    /// The Z and M flags of geometries made of this point type
+    const FLAGS: u32;
+
+    /// Writes the coordinates of the point
+    fn write_coords<W: Write>(&self, dest: &mut W) -> std::io::Result<()>;
+}
+
+impl EwkbPoint for Point {
+    const FLAGS: u32 = 0;
+
+    fn write_coords<W: Write>(&self, dest: &mut W) -> std::io::Result<()> {
+        dest.write_f64::<LittleEndian>(self.x)?;
+        dest.write_f64::<LittleEndian>(self.y)
+    }
+}
+
+impl EwkbPoint for PointM {
+    const FLAGS: u32 = EWKB_M_FLAG;
+
+    fn write_coords<W: Write>(&self, dest: &mut W) -> std::io::Result<()> {
+        dest.write_f64::<LittleEndian>(self.x)?;
+        dest.write_f64::<LittleEndian>(self.y)?;
+        dest.write_f64::<LittleEndian>(self.m)
+    }
+}
+
+impl EwkbPoint for PointZ {
+    const FLAGS: u32 = EWKB_Z_FLAG | EWKB_M_FLAG;
+
+    fn write_coords<W: Write>(&self, dest: &mut W) -> std::io::Result<()> {
+        dest.write_f64::<LittleEndian>(self.x)?;
+        dest.write_f64::<LittleEndian>(self.y)?;
+        dest.write_f64::<LittleEndian>(self.z)?;
+        dest.write_f64::<LittleEndian>(self.m)
+    }
+}
+
+/// Writes the byte order and the geometry type,
+/// followed by the SRID if there is one
+fn write_geometry_header<P: EwkbPoint, W: Write>(
+    dest: &mut W,
+    geometry_type: u32,
+    srid: Option<u32>,
+) -> std::io::Result<()> {
+    dest.write_u8(WKB_NDR)?;
+    match srid {
+        Some(srid) => {
+            dest.write_u32::<LittleEndian>(geometry_type | P::FLAGS | EWKB_SRID_FLAG)?;
+            dest.write_u32::<LittleEndian>(srid)
+        }
+        None => dest.write_u32::<LittleEndian>(geometry_type | P::FLAGS),
+    }
+}
+
+fn write_points<P: EwkbPoint, W: Write>(dest: &mut W, points: &[P]) -> std::io::Result<()> {
+    dest.write_u32::<LittleEndian>(points.len() as u32)?;
+    for point in points {
+        point.write_coords(dest)?;
+    }
+    Ok(())
+}
+
+fn write_point<P: EwkbPoint, W: Write>(
+    dest: &mut W,
+    point: &P,
+    srid: Option<u32>,
+) -> std::io::Result<()> {
+    write_geometry_header::<P, W>(dest, WKB_POINT, srid)?;
+    point.write_coords(dest)
+}
+
+fn write_multipoint<P: EwkbPoint, W: Write>(
+    dest: &mut W,
+    points: &[P],
+    srid: u32,
+) -> std::io::Result<()> {
+    write_geometry_header::<P, W>(dest, WKB_MULTIPOINT, Some(srid))?;
+    dest.write_u32::<LittleEndian>(points.len() as u32)?;
+    for point in points {
+        write_point(dest, point, None)?;
+    }
+    Ok(())
+}
+
+fn write_multilinestring<P: EwkbPoint, W: Write>(
+    dest: &mut W,
+    parts: &[Vec<P>],
+    srid: u32,
+) -> std::io::Result<()> {
+    write_geometry_header::<P, W>(dest, WKB_MULTILINESTRING, Some(srid))?;
+    dest.write_u32::<LittleEndian>(parts.len() as u32)?;
+    for part in parts {
+        write_geometry_header::<P, W>(dest, WKB_LINESTRING, None)?;
+        write_points(dest, part)?;
+    }
+    Ok(())
+}
+
+/// Writes the polygon as a multipolygon, each outer ring
+/// starts a polygon that the inner rings following it belong to
+fn write_multipolygon<P: EwkbPoint, W: Write>(
+    dest: &mut W,
+    polygon: &GenericPolygon<P>,
+    srid: u32,
+) -> std::io::Result<()> {
+    let mut groups = Vec::<Vec<&PolygonRing<P>>>::new();
+    for ring in polygon.file_rings() {
+        match (ring, groups.last_mut()) {
+            (PolygonRing::Inner(_), Some(group)) => group.push(ring),
+            (ring, _) => groups.push(vec![ring]),
+        }
+    }
+
+    write_geometry_header::<P, W>(dest, WKB_MULTIPOLYGON, Some(srid))?;
+    dest.write_u32::<LittleEndian>(groups.len() as u32)?;
+    for rings in groups {
+        write_geometry_header::<P, W>(dest, WKB_POLYGON, None)?;
+        dest.write_u32::<LittleEndian>(rings.len() as u32)?;
+        for ring in rings {
+            write_points(dest, ring.points())?;
+        }
+    }
+    Ok(())
+}
+
+impl Shape {
+    /// Writes the shape as EWKB (the Extended Well-Known Binary used by PostGIS),
+    /// with `srid` embedded
+    ///
+    /// - Points are written as `POINT`
+    /// - Multipoints as `MULTIPOINT`
+    /// - Polylines as `MULTILINESTRING`
+    /// - Polygons as `MULTIPOLYGON`, each outer ring starting a polygon
+    ///
+    /// `M` shapes are written with the M flag and `Z` shapes with both the Z and M flags.
+    /// The geometry is written in little endian.
+    ///
+    /// # Errors
+    ///
+    /// Null shapes and multipatches cannot be written in EWKB,
+    /// [Error::GeometryConversion] is returned for them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "wkb")]
+    /// # fn main() -> Result<(), shapefile::Error> {
+    /// use shapefile::{Point, Shape};
+    /// let mut ewkb = vec![];
+    /// Shape::Point(Point::new(1.0, 2.0)).to_ewkb(4326, &mut ewkb)?;
+    /// // byte order + type + srid + x + y
+    /// assert_eq!(ewkb.len(), 1 + 4 + 4 + 16);
+    /// assert_eq!(&ewkb[5..9], &4326u32.to_le_bytes());
+    /// # Ok(())
+    /// # }
+    /// # #[cfg(not(feature = "wkb"))]
+    /// # fn main() {}
+    /// ```
+    pub fn to_ewkb<W: Write>(&self, srid: u32, dest: &mut W) -> Result<(), Error> {
+        match self {
+            Shape::NullShape => {
+                return Err(Error::GeometryConversion(
+                    "Null shapes cannot be written as EWKB",
+                ))
+            }
+            Shape::Multipatch(_) => {
+                return Err(Error::GeometryConversion(
+                    "Multipatch shapes cannot be written as EWKB",
+                ))
+            }
+            Shape::Point(shp) => write_point(dest, shp, Some(srid))?,
+            Shape::PointM(shp) => write_point(dest, shp, Some(srid))?,
+            Shape::PointZ(shp) => write_point(dest, shp, Some(srid))?,
+            Shape::Multipoint(shp) => write_multipoint(dest, shp.points(), srid)?,
+            Shape::MultipointM(shp) => write_multipoint(dest, shp.points(), srid)?,
+            Shape::MultipointZ(shp) => write_multipoint(dest, shp.points(), srid)?,
+            Shape::Polyline(shp) => write_multilinestring(dest, shp.parts(), srid)?,
+            Shape::PolylineM(shp) => write_multilinestring(dest, shp.parts(), srid)?,
+            Shape::PolylineZ(shp) => write_multilinestring(dest, shp.parts(), srid)?,
+            Shape::Polygon(shp) => write_multipolygon(dest, shp, srid)?,
+            Shape::PolygonM(shp) => write_multipolygon(dest, shp, srid)?,
+            Shape::PolygonZ(shp) => write_multipolygon(dest, shp, srid)?,
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use byteorder::ReadBytesExt;
+    use std::io::Cursor;
+
+    /// Minimal EWKB reader, returns the type (without flags), the SRID and the source
+    fn read_ewkb_header(ewkb: &[u8]) -> (u32, Option<u32>, Cursor<&[u8]>) {
+        let mut source = Cursor::new(ewkb);
+        assert_eq!(source.read_u8().unwrap(), WKB_NDR);
+        let geometry_type = source.read_u32::<LittleEndian>().unwrap();
+        let srid = if geometry_type & EWKB_SRID_FLAG != 0 {
+            Some(source.read_u32::<LittleEndian>().unwrap())
+        } else {
+            None
+        };
+        (geometry_type & 0xFF, srid, source)
+    }
+
+    #[test]
+    fn test_point_ewkb_srid_round_trip() {
+        let mut ewkb = vec![];
+        Shape::Point(Point::new(1.5, -2.0))
+            .to_ewkb(2154, &mut ewkb)
+            .unwrap();
+
+        let (geometry_type, srid, mut source) = read_ewkb_header(&ewkb);
+        assert_eq!(geometry_type, WKB_POINT);
+        assert_eq!(srid, Some(2154));
+        let x = source.read_f64::<LittleEndian>().unwrap();
+        let y = source.read_f64::<LittleEndian>().unwrap();
+        assert_eq!(Point::new(x, y), Point::new(1.5, -2.0));
+        assert_eq!(source.position() as usize, ewkb.len());
+    }
+
+    #[test]
+    fn test_polygon_z_ewkb() {
+        let polygon = crate::PolygonZ::with_rings(vec![
+            PolygonRing::Outer(vec![
+                PointZ::new(0.0, 0.0, 1.0, 0.0),
+                PointZ::new(0.0, 1.0, 1.0, 0.0),
+                PointZ::new(1.0, 1.0, 1.0, 0.0),
+                PointZ::new(0.0, 0.0, 1.0, 0.0),
+            ]),
+            PolygonRing::Outer(vec![
+                PointZ::new(5.0, 5.0, 1.0, 0.0),
+                PointZ::new(5.0, 6.0, 1.0, 0.0),
+                PointZ::new(6.0, 6.0, 1.0, 0.0),
+                PointZ::new(5.0, 5.0, 1.0, 0.0),
+            ]),
+        ]);
+        let mut ewkb = vec![];
+        Shape::PolygonZ(polygon).to_ewkb(4326, &mut ewkb).unwrap();
+
+        let (geometry_type, srid, mut source) = read_ewkb_header(&ewkb);
+        assert_eq!(geometry_type, WKB_MULTIPOLYGON);
+        assert_eq!(srid, Some(4326));
+        assert_eq!(source.read_u32::<LittleEndian>().unwrap(), 2);
+        // 2 polygons of (header + num rings + num points + 4 points of 4 coords)
+        let polygon_size = 5 + 4 + 4 + 4 * 4 * 8;
+        assert_eq!(ewkb.len(), 9 + 4 + 2 * polygon_size);
+
+        assert!(Shape::NullShape.to_ewkb(4326, &mut vec![]).is_err());
+    }
+}
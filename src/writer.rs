@@ -8,33 +8,63 @@
 //!
 //! The [ShapeWriter] can be used if you only want to write the .shp
 //! and .shx files, however since it does not write the .dbf file, it is not recommended.
-use std::io::{BufWriter, Seek, SeekFrom, Write};
+use std::io::{BufWriter, Cursor, Read, Seek, SeekFrom, Write};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+use byteorder::{LittleEndian, WriteBytesExt};
 
 use super::{header, ShapeType};
-use super::{Error, PointZ};
-use crate::record::{BBoxZ, EsriShape, RecordHeader};
+use super::{Error, Point, Shape};
+use crate::record::{BBoxZ, EsriShape, GenericBBox, HasShapeType, RecordHeader, WritableShape};
 use std::fs::File;
 use std::path::Path;
 
-use crate::reader::ShapeIndex;
+use crate::reader::{Reader, ShapeIndex};
 use dbase::TableWriterBuilder;
 
+/// Returns the min of `a` and `b`, NaN values are ignored
 pub(crate) fn f64_min(a: f64, b: f64) -> f64 {
-    if a < b {
+    if a < b || b.is_nan() {
         a
     } else {
         b
     }
 }
 
+/// Returns the max of `a` and `b`, NaN values are ignored
 pub(crate) fn f64_max(a: f64, b: f64) -> f64 {
-    if a > b {
+    if a > b || b.is_nan() {
         a
     } else {
         b
     }
 }
 
+/// Statistics returned by [ShapeWriter::write_shapes_counting]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct WriteStats {
+    /// Number of shapes written by the call
+    pub shapes_written: usize,
+    /// Length in bytes of the .shp file after the shapes were written
+    pub bytes_written: u64,
+}
+
+/// The kinds of spatial index file that a [ShapeWriter] can write,
+/// see [ShapeWriter::with_spatial_index]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SpatialIndexKind {
+    /// Quadtree index (`.qix`), as written by MapServer's `shptree`,
+    /// and read by GDAL/OGR, QGIS and MapServer.
+    Qix,
+}
+
+struct SpatialIndexWriter<T> {
+    kind: SpatialIndexKind,
+    dest: T,
+    // Index in the file and 2D bbox of each non-null shape written
+    bboxes: Vec<(i32, GenericBBox<Point>)>,
+}
+
 /// struct that handles the writing of the .shp
 /// and (optionally) the .idx
 ///
@@ -49,18 +79,67 @@ pub struct ShapeWriter<T: Write + Seek> {
     shx_dest: Option<T>,
     header: header::Header,
     rec_num: u32,
+    header_bbox: Option<BBoxZ>,
+    expected_type: Option<ShapeType>,
+    spatial_index: Option<SpatialIndexWriter<T>>,
+    // Set once the header is written, so that dropping
+    // the writer does not write it again
+    closed: bool,
+}
+
+/// Wraps a shape so that it is written as its 2D shape type,
+/// see [ShapeWriter::write_shape_as_2d]
+struct FlatShape<'a, S>(&'a S);
+
+impl<S: EsriShape> HasShapeType for FlatShape<'_, S> {
+    fn shapetype() -> ShapeType {
+        S::shapetype().to_2d()
+    }
+}
+
+impl<S: EsriShape> WritableShape for FlatShape<'_, S> {
+    fn size_in_bytes(&self) -> usize {
+        self.0.size_in_bytes_2d()
+    }
+
+    fn write_to<W: Write>(&self, dest: &mut W) -> Result<(), Error> {
+        self.0.write_2d_to(dest)
+    }
+}
+
+impl<S: EsriShape> EsriShape for FlatShape<'_, S> {
+    fn x_range(&self) -> [f64; 2] {
+        self.0.x_range()
+    }
+
+    fn y_range(&self) -> [f64; 2] {
+        self.0.y_range()
+    }
+
+    fn has_finite_coords(&self) -> bool {
+        self.0.has_finite_coords()
+    }
 }
 
 impl<T: Write + Seek> ShapeWriter<T> {
     /// Creates a writer that can be used to write a new shapefile.
     ///
     /// The `dest` argument is only for the .shp
+    ///
+    /// No _.shx_ index is written, the resulting file is still valid
+    /// (its header has the correct file length) and can be read sequentially,
+    /// but readers will not be able to access a shape by its index.
+    /// Use [ShapeWriter::with_shx] to also write the index.
     pub fn new(shp_dest: T) -> Self {
         Self {
             shp_dest,
             shx_dest: None,
             header: header::Header::default(),
             rec_num: 1,
+            header_bbox: None,
+            expected_type: None,
+            spatial_index: None,
+            closed: false,
         }
     }
 
@@ -70,11 +149,108 @@ impl<T: Write + Seek> ShapeWriter<T> {
             shx_dest: Some(shx_dest),
             header: Default::default(),
             rec_num: 1,
+            header_bbox: None,
+            expected_type: None,
+            spatial_index: None,
+            closed: false,
         }
     }
 
+    /// Makes the writer also write a spatial index to `index_dest`
+    ///
+    /// The index is built from the bounding boxes of the shapes written,
+    /// and written when the writer is closed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> Result<(), shapefile::Error> {
+    /// use shapefile::{Point, SpatialIndexKind};
+    /// use std::fs::File;
+    /// use std::io::BufWriter;
+    ///
+    /// let qix_dest = BufWriter::new(File::create("indexed_points.qix")?);
+    /// let mut writer = shapefile::ShapeWriter::from_path("indexed_points.shp")?
+    ///     .with_spatial_index(SpatialIndexKind::Qix, qix_dest);
+    /// writer.write_shape(&Point::new(1.0, 2.0))?;
+    /// writer.write_shape(&Point::new(3.0, 4.0))?;
+    /// drop(writer);
+    /// # std::fs::remove_file("indexed_points.shp")?;
+    /// # std::fs::remove_file("indexed_points.shx")?;
+    /// # std::fs::remove_file("indexed_points.qix")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_spatial_index(mut self, kind: SpatialIndexKind, index_dest: T) -> Self {
+        self.spatial_index = Some(SpatialIndexWriter {
+            kind,
+            dest: index_dest,
+            bboxes: vec![],
+        });
+        self
+    }
+
+    /// Sets the type of shapes that the file will contain
+    ///
+    /// Normally, the shape type of the file is given by the first shape written,
+    /// so a file where no shapes are written has the `NullShape` type.
+    /// With an expected type, the header of such a file has the expected type
+    /// (and a zeroed bounding box).
+    ///
+    /// Null shapes do not give their type to the file, so the header gets the
+    /// expected type even if the file starts with (or only has) null shapes.
+    ///
+    /// Writing a shape of another type returns `Error::MismatchShapeType`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), shapefile::Error> {
+    /// use shapefile::ShapeType;
+    /// let mut shp_dest = std::io::Cursor::new(Vec::<u8>::new());
+    /// let mut writer = shapefile::ShapeWriter::new(&mut shp_dest);
+    /// writer.set_expected_type(ShapeType::Polyline);
+    /// assert!(writer.write_shape(&shapefile::Point::new(1.0, 1.0)).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_expected_type(&mut self, shape_type: ShapeType) {
+        self.expected_type = Some(shape_type);
+    }
+
+    /// Sets the bounding box that will be written in the header of the file(s),
+    /// instead of the bounding box computed from the shapes written.
+    ///
+    /// The bbox is used as is when the writer is closed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> Result<(), shapefile::Error> {
+    /// use shapefile::{Point, PointZ};
+    /// use shapefile::record::BBoxZ;
+    /// let mut writer = shapefile::ShapeWriter::from_path("tile.shp")?;
+    /// writer.set_header_bbox(BBoxZ {
+    ///     min: PointZ::new(0.0, 0.0, 0.0, 0.0),
+    ///     max: PointZ::new(256.0, 256.0, 0.0, 0.0),
+    /// });
+    /// writer.write_shape(&Point::new(12.0, 14.0))?;
+    /// # std::fs::remove_file("tile.shp")?;
+    /// # std::fs::remove_file("tile.shx")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_header_bbox(&mut self, bbox: BBoxZ) {
+        self.header_bbox = Some(bbox);
+    }
+
     /// Write the shape to the file
     ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidShape` if the shape has NaN or infinite
+    /// `x` or `y` coordinates, as they would corrupt the bounding boxes of the file.
+    ///
     /// # Examples
     ///
     /// ```
@@ -92,20 +268,52 @@ impl<T: Write + Seek> ShapeWriter<T> {
     /// # }
     /// ```
     pub fn write_shape<S: EsriShape>(&mut self, shape: &S) -> Result<(), Error> {
+        self.write_shape_with_number(shape, self.rec_num as i32)
+    }
+
+    /// Write the shape to the file, using the given `record_number`
+    /// in the record header instead of the writer's own counter.
+    ///
+    /// This is useful to keep the record numbers of the file the shapes were read from.
+    ///
+    /// # Important
+    ///
+    /// It is the caller's responsibility to give record numbers that are unique
+    /// and increasing, otherwise the written file won't be valid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> Result<(), shapefile::Error> {
+    /// use shapefile::Point;
+    /// let mut writer = shapefile::ShapeWriter::from_path("numbered_points.shp")?;
+    ///
+    /// writer.write_shape_with_number(&Point::new(0.0, 0.0), 5)?;
+    /// writer.write_shape_with_number(&Point::new(1.0, 0.0), 9)?;
+    ///
+    /// # std::fs::remove_file("numbered_points.shp")?;
+    /// # std::fs::remove_file("numbered_points.shx")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_shape_with_number<S: EsriShape>(
+        &mut self,
+        shape: &S,
+        record_number: i32,
+    ) -> Result<(), Error> {
+        if !shape.has_finite_coords() {
+            return Err(Error::InvalidShape(
+                "Shapes with NaN or infinite coordinates cannot be written",
+            ));
+        }
+
         match (self.header.shape_type, S::shapetype()) {
-            // This is the first call to write shape, we shall write the header
-            // to reserve it space in the file.
+            // This is the first non-null shape written, it gives its type to the file
             (ShapeType::NullShape, t) => {
-                use std::f64::{MAX, MIN};
+                self.check_expected_type(t)?;
+                self.reserve_header_if_needed()?;
                 self.header.shape_type = t;
-                self.header.bbox = BBoxZ {
-                    max: PointZ::new(MIN, MIN, MIN, MIN),
-                    min: PointZ::new(MAX, MAX, MAX, MAX),
-                };
-                self.header.write_to(&mut self.shp_dest)?;
-                if let Some(shx_dest) = &mut self.shx_dest {
-                    self.header.write_to(shx_dest)?;
-                }
+                self.header.bbox = BBoxZ::inverted();
             }
             (t1, t2) if t1 != t2 => {
                 return Err(Error::MismatchShapeType {
@@ -119,7 +327,7 @@ impl<T: Write + Seek> ShapeWriter<T> {
         let record_size = (shape.size_in_bytes() + std::mem::size_of::<i32>()) / 2;
 
         RecordHeader {
-            record_number: self.rec_num as i32,
+            record_number,
             record_size: record_size as i32,
         }
         .write_to(&mut self.shp_dest)?;
@@ -134,6 +342,13 @@ impl<T: Write + Seek> ShapeWriter<T> {
             .write_to(shx_dest)?;
         }
 
+        if let Some(spatial_index) = &mut self.spatial_index {
+            spatial_index.bboxes.push((
+                self.rec_num as i32 - 1,
+                GenericBBox::<Point>::from_ranges(shape.x_range(), shape.y_range()),
+            ));
+        }
+
         self.header.file_length += record_size as i32 + RecordHeader::SIZE as i32 / 2;
         self.header.bbox.grow_from_shape(shape);
         self.rec_num += 1;
@@ -141,6 +356,106 @@ impl<T: Write + Seek> ShapeWriter<T> {
         Ok(())
     }
 
+    /// Writes a NullShape record to the file
+    ///
+    /// Null records are allowed anywhere in a shapefile, they do not
+    /// change the shape type of the file nor its bounding box.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> Result<(), shapefile::Error> {
+    /// use shapefile::Point;
+    /// let mut shp_dest = std::io::Cursor::new(Vec::<u8>::new());
+    /// let mut writer = shapefile::ShapeWriter::new(&mut shp_dest);
+    ///
+    /// writer.write_shape(&Point::new(0.0, 0.0))?;
+    /// writer.write_null_shape()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_null_shape(&mut self) -> Result<(), Error> {
+        self.reserve_header_if_needed()?;
+
+        let record_size = std::mem::size_of::<i32>() / 2;
+
+        RecordHeader {
+            record_number: self.rec_num as i32,
+            record_size: record_size as i32,
+        }
+        .write_to(&mut self.shp_dest)?;
+        ShapeType::NullShape.write_to(&mut self.shp_dest)?;
+
+        if let Some(shx_dest) = &mut self.shx_dest {
+            ShapeIndex {
+                offset: self.header.file_length,
+                record_size: record_size as i32,
+            }
+            .write_to(shx_dest)?;
+        }
+
+        self.header.file_length += record_size as i32 + RecordHeader::SIZE as i32 / 2;
+        self.rec_num += 1;
+
+        Ok(())
+    }
+
+    /// Writes the shape wrapped in the [Shape] enum by dispatching
+    /// to the write function of the concrete shape.
+    pub(crate) fn write_any_shape(&mut self, shape: &Shape) -> Result<(), Error> {
+        match shape {
+            Shape::NullShape => self.write_null_shape(),
+            Shape::Point(shp) => self.write_shape(shp),
+            Shape::PointM(shp) => self.write_shape(shp),
+            Shape::PointZ(shp) => self.write_shape(shp),
+            Shape::Polyline(shp) => self.write_shape(shp),
+            Shape::PolylineM(shp) => self.write_shape(shp),
+            Shape::PolylineZ(shp) => self.write_shape(shp),
+            Shape::Polygon(shp) => self.write_shape(shp),
+            Shape::PolygonM(shp) => self.write_shape(shp),
+            Shape::PolygonZ(shp) => self.write_shape(shp),
+            Shape::Multipoint(shp) => self.write_shape(shp),
+            Shape::MultipointM(shp) => self.write_shape(shp),
+            Shape::MultipointZ(shp) => self.write_shape(shp),
+            Shape::Multipatch(shp) => self.write_shape(shp),
+        }
+    }
+
+    /// Writes the shape as its 2D equivalent, dropping its z and m
+    ///
+    /// The file gets the 2D shape type (e.g. `Polyline` for a `PolylineZ`),
+    /// so that 2D and 3D/measured shapes can be written to the same file.
+    /// Multipatch shapes have no 2D equivalent and return an [Error::GeometryConversion].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), shapefile::Error> {
+    /// use shapefile::{PointZ, PolylineZ, ShapeType};
+    /// let mut writer = shapefile::ShapeWriter::from_path("flattened_lines.shp")?;
+    /// let polyline = PolylineZ::new(vec![
+    ///     PointZ::new(1.0, 1.0, 5.0, 0.0),
+    ///     PointZ::new(2.0, 2.0, 6.0, 1.0),
+    /// ]);
+    /// writer.write_shape_as_2d(&polyline)?;
+    /// drop(writer);
+    ///
+    /// let reader = shapefile::ShapeReader::from_path("flattened_lines.shp")?;
+    /// assert_eq!(reader.header().shape_type, ShapeType::Polyline);
+    /// # std::fs::remove_file("flattened_lines.shp")?;
+    /// # std::fs::remove_file("flattened_lines.shx")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_shape_as_2d<S: EsriShape>(&mut self, shape: &S) -> Result<(), Error> {
+        if S::shapetype() == ShapeType::Multipatch {
+            return Err(Error::GeometryConversion(
+                "Multipatch shapes have no 2D equivalent",
+            ));
+        }
+        self.write_shape(&FlatShape(shape))
+    }
+
     /// Writes a collection of shapes to the file
     ///
     /// # Examples
@@ -181,15 +496,122 @@ impl<T: Write + Seek> ShapeWriter<T> {
         Ok(())
     }
 
+    /// Writes a collection of shapes, without consuming the writer,
+    /// and returns some statistics about the writing
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), shapefile::Error> {
+    /// use shapefile::Point;
+    /// let mut shp_dest = std::io::Cursor::new(Vec::<u8>::new());
+    /// let mut writer = shapefile::ShapeWriter::new(&mut shp_dest);
+    /// let points = vec![Point::new(0.0, 0.0), Point::new(1.0, 0.0)];
+    ///
+    /// let stats = writer.write_shapes_counting(&points)?;
+    /// assert_eq!(stats.shapes_written, 2);
+    /// // 100 bytes of header + 2 * (8 bytes of record header + 20 bytes of point record)
+    /// assert_eq!(stats.bytes_written, 156);
+    ///
+    /// let stats = writer.write_shapes_counting(&points)?;
+    /// assert_eq!(stats.shapes_written, 2);
+    /// assert_eq!(stats.bytes_written, 212);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_shapes_counting<'a, S: EsriShape + 'a, C: IntoIterator<Item = &'a S>>(
+        &mut self,
+        container: C,
+    ) -> Result<WriteStats, Error> {
+        let mut shapes_written = 0;
+        for shape in container {
+            self.write_shape(shape)?;
+            shapes_written += 1;
+        }
+        Ok(WriteStats {
+            shapes_written,
+            bytes_written: self.header.file_length as u64 * 2,
+        })
+    }
+
+    /// Returns a writer specialized to write points, given as `x`, `y` coordinates
+    ///
+    /// See [PointStreamWriter]
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::MismatchShapeType` if shapes that are not `Point`
+    /// were already written.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), shapefile::Error> {
+    /// let mut shp_dest = std::io::Cursor::new(Vec::<u8>::new());
+    /// let mut writer = shapefile::ShapeWriter::new(&mut shp_dest);
+    /// let mut point_writer = writer.point_stream()?;
+    /// for i in 0..10 {
+    ///     point_writer.push(i as f64, 2.0 * i as f64)?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn point_stream(&mut self) -> Result<PointStreamWriter<'_, T>, Error> {
+        match self.header.shape_type {
+            ShapeType::NullShape => {
+                self.check_expected_type(ShapeType::Point)?;
+                self.reserve_header_if_needed()?;
+                self.header.shape_type = ShapeType::Point;
+                self.header.bbox = BBoxZ::inverted();
+            }
+            ShapeType::Point => {}
+            t => {
+                return Err(Error::MismatchShapeType {
+                    requested: t,
+                    actual: ShapeType::Point,
+                })
+            }
+        }
+        Ok(PointStreamWriter { writer: self })
+    }
+
+    fn check_expected_type(&self, shape_type: ShapeType) -> Result<(), Error> {
+        match self.expected_type {
+            Some(expected_type) if expected_type != shape_type => Err(Error::MismatchShapeType {
+                requested: expected_type,
+                actual: shape_type,
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Writes the header at the start of the file(s) to reserve its space,
+    /// the real header is written when the writer is closed.
+    fn reserve_header_if_needed(&mut self) -> Result<(), Error> {
+        if self.rec_num == 1 && self.header.shape_type == ShapeType::NullShape {
+            self.header.write_to(&mut self.shp_dest)?;
+            if let Some(shx_dest) = &mut self.shx_dest {
+                self.header.write_to(shx_dest)?;
+            }
+        }
+        Ok(())
+    }
+
     fn close(&mut self) -> Result<(), Error> {
-        if self.header.bbox.max.m == std::f64::MIN && self.header.bbox.min.m == std::f64::MAX {
-            self.header.bbox.max.m = 0.0;
-            self.header.bbox.min.m = 0.0;
+        if self.closed {
+            return Ok(());
         }
+        self.closed = true;
 
-        if self.header.bbox.max.z == std::f64::MIN && self.header.bbox.min.z == std::f64::MAX {
-            self.header.bbox.max.z = 0.0;
-            self.header.bbox.min.z = 0.0;
+        if let (ShapeType::NullShape, Some(expected_type)) =
+            (self.header.shape_type, self.expected_type)
+        {
+            self.header.shape_type = expected_type;
+        }
+        self.header.bbox.zero_ungrown_z_and_m();
+
+        if let Some(bbox) = self.header_bbox {
+            self.header.bbox = bbox;
         }
 
         self.shp_dest.seek(SeekFrom::Start(0))?;
@@ -203,6 +625,84 @@ impl<T: Write + Seek> ShapeWriter<T> {
             shx_header.write_to(shx_dest)?;
             shx_dest.seek(SeekFrom::End(0))?;
         }
+        if let Some(spatial_index) = &mut self.spatial_index {
+            let bounds = GenericBBox::<Point>::from_ranges(
+                self.header.bbox.x_range(),
+                self.header.bbox.y_range(),
+            );
+            match spatial_index.kind {
+                SpatialIndexKind::Qix => crate::spatial_index::write_qix(
+                    &mut spatial_index.dest,
+                    &spatial_index.bboxes,
+                    (self.rec_num - 1) as usize,
+                    &bounds,
+                )?,
+            }
+            spatial_index.dest.flush()?;
+        }
+        Ok(())
+    }
+}
+
+/// Writer specialized in writing `Point` shapes, created with [ShapeWriter::point_stream]
+///
+/// Points are given as `x`, `y` coordinates and directly written
+/// to the destination, without going through the [EsriShape] machinery,
+/// which makes writing large amounts of points faster.
+pub struct PointStreamWriter<'a, T: Write + Seek> {
+    writer: &'a mut ShapeWriter<T>,
+}
+
+impl<T: Write + Seek> PointStreamWriter<'_, T> {
+    // Size of a point record (shape type, x, y) in 16-bit words
+    const RECORD_SIZE: i32 =
+        (std::mem::size_of::<i32>() + 2 * std::mem::size_of::<f64>()) as i32 / 2;
+
+    /// Writes a point
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidShape` if the coordinates are NaN or infinite.
+    pub fn push(&mut self, x: f64, y: f64) -> Result<(), Error> {
+        if !x.is_finite() || !y.is_finite() {
+            return Err(Error::InvalidShape(
+                "Shapes with NaN or infinite coordinates cannot be written",
+            ));
+        }
+        let writer = &mut *self.writer;
+
+        RecordHeader {
+            record_number: writer.rec_num as i32,
+            record_size: Self::RECORD_SIZE,
+        }
+        .write_to(&mut writer.shp_dest)?;
+        ShapeType::Point.write_to(&mut writer.shp_dest)?;
+        writer.shp_dest.write_f64::<LittleEndian>(x)?;
+        writer.shp_dest.write_f64::<LittleEndian>(y)?;
+
+        if let Some(shx_dest) = &mut writer.shx_dest {
+            ShapeIndex {
+                offset: writer.header.file_length,
+                record_size: Self::RECORD_SIZE,
+            }
+            .write_to(shx_dest)?;
+        }
+
+        if let Some(spatial_index) = &mut writer.spatial_index {
+            spatial_index.bboxes.push((
+                writer.rec_num as i32 - 1,
+                GenericBBox::<Point>::from_ranges([x, x], [y, y]),
+            ));
+        }
+
+        writer.header.file_length += Self::RECORD_SIZE + RecordHeader::SIZE as i32 / 2;
+        let bbox = &mut writer.header.bbox;
+        bbox.min.x = f64_min(bbox.min.x, x);
+        bbox.max.x = f64_max(bbox.max.x, x);
+        bbox.min.y = f64_min(bbox.min.y, y);
+        bbox.max.y = f64_max(bbox.max.y, y);
+        writer.rec_num += 1;
+
         Ok(())
     }
 }
@@ -232,6 +732,31 @@ impl ShapeWriter<BufWriter<File>> {
 
         Ok(Self::with_shx(shp_file, shx_file))
     }
+
+    /// Creates a new writer from a path that only creates the .shp file
+    ///
+    /// # Important
+    ///
+    /// As no .shx (index file) is written, readers of the resulting file
+    /// won't be able to `seek` to a shape or to know the shape count without
+    /// reading the whole .shp.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> Result<(), shapefile::Error> {
+    /// let mut writer = shapefile::ShapeWriter::from_path_shp_only("only_shp.shp")?;
+    /// writer.write_shape(&shapefile::Point::new(1.0, 2.0))?;
+    /// drop(writer);
+    /// assert!(!std::path::Path::new("only_shp.shx").exists());
+    /// # std::fs::remove_file("only_shp.shp")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_path_shp_only<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let shp_file = BufWriter::new(File::create(path)?);
+        Ok(Self::new(shp_file))
+    }
 }
 
 /// The Writer writes a complete shapefile that is, it
@@ -318,6 +843,53 @@ impl<T: Write + Seek> Writer<T> {
         }
         Ok(())
     }
+
+    /// Writes all the shapes and records yielded by the iterator,
+    /// consuming the writer.
+    ///
+    /// Each shape is written right before its record, so the number of
+    /// shapes and records written always match.
+    ///
+    /// The first non-null shape gives its type to the file,
+    /// any subsequent shape of another type will return an [Error::MismatchShapeType].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), shapefile::Error> {
+    /// use std::convert::TryInto;
+    /// use shapefile::{Point, Shape};
+    /// let table_builder = dbase::TableWriterBuilder::new()
+    ///     .add_character_field("name".try_into().unwrap(), 50);
+    /// let writer = shapefile::Writer::from_path("all_points.shp", table_builder)?;
+    ///
+    /// let features = (0..3).map(|i| {
+    ///     let mut record = dbase::Record::default();
+    ///     record.insert(
+    ///         "name".to_string(),
+    ///         dbase::FieldValue::Character(Some(format!("point {}", i))),
+    ///     );
+    ///     Ok::<_, shapefile::Error>((Shape::Point(Point::new(i as f64, 0.0)), record))
+    /// });
+    /// writer.write_all(features)?;
+    /// # std::fs::remove_file("all_points.shp")?;
+    /// # std::fs::remove_file("all_points.shx")?;
+    /// # std::fs::remove_file("all_points.dbf")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_all<I, E>(mut self, iter: I) -> Result<(), Error>
+    where
+        I: IntoIterator<Item = Result<(Shape, dbase::Record), E>>,
+        Error: From<E>,
+    {
+        for shape_record in iter {
+            let (shape, record) = shape_record?;
+            self.shape_writer.write_any_shape(&shape)?;
+            self.dbase_writer.write_record(&record)?;
+        }
+        Ok(())
+    }
 }
 
 impl Writer<BufWriter<File>> {
@@ -357,3 +929,255 @@ impl Writer<BufWriter<File>> {
         })
     }
 }
+
+/// In memory destination of a [Writer] created with [Writer::in_memory]
+///
+/// The _.shp_, _.shx_ and _.dbf_ buffers are shared between the
+/// destinations so that [Writer::finish_to_bytes] can give them back
+/// once the writers are closed.
+///
+/// Its content is only accessible through [Writer::finish_to_bytes].
+#[derive(Debug)]
+pub struct InMemoryBuffer {
+    buffers: Arc<Mutex<[Cursor<Vec<u8>>; 3]>>,
+    index: usize,
+}
+
+impl InMemoryBuffer {
+    const SHP: usize = 0;
+    const SHX: usize = 1;
+    const DBF: usize = 2;
+
+    fn with_same_buffers(&self, index: usize) -> Self {
+        Self {
+            buffers: Arc::clone(&self.buffers),
+            index,
+        }
+    }
+
+    fn buffers(&self) -> MutexGuard<'_, [Cursor<Vec<u8>>; 3]> {
+        // The buffers stay usable even if a panic happened while writing
+        self.buffers.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn take(&self, index: usize) -> Vec<u8> {
+        std::mem::take(self.buffers()[index].get_mut())
+    }
+}
+
+impl Write for InMemoryBuffer {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.buffers()[self.index].write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Seek for InMemoryBuffer {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        self.buffers()[self.index].seek(pos)
+    }
+}
+
+/// The _.shp_, _.shx_ and _.dbf_ content
+type ShapefileBytes = (Vec<u8>, Vec<u8>, Vec<u8>);
+
+impl Writer<InMemoryBuffer> {
+    /// Creates a writer that writes the _.shp_, _.shx_ and _.dbf_ to in memory buffers,
+    /// use [Writer::finish_to_bytes] to get them.
+    ///
+    /// The writer is a `Writer<InMemoryBuffer>` rather than a `Writer<Cursor<Vec<u8>>>`
+    /// because the dbase `TableWriter` cannot give its destination back
+    /// (it has no `into_inner`), so the buffers are shared with the
+    /// [InMemoryBuffer] destinations instead of being owned by them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), shapefile::Error> {
+    /// use std::convert::TryInto;
+    /// use shapefile::dbase::{FieldValue, Record};
+    /// let table_builder = dbase::TableWriterBuilder::new()
+    ///     .add_character_field("name".try_into().unwrap(), 50);
+    /// let mut writer = shapefile::Writer::in_memory(table_builder);
+    ///
+    /// let mut record = Record::default();
+    /// record.insert("name".to_string(), FieldValue::Character(Some("Paris".to_string())));
+    /// writer.write_shape_and_record(&shapefile::Point::new(2.35, 48.85), &record)?;
+    ///
+    /// let (shp, shx, dbf) = writer.finish_to_bytes()?;
+    /// assert_eq!(shp.len(), 100 + 8 + 20);
+    /// assert_eq!(shx.len(), 100 + 8);
+    /// assert!(!dbf.is_empty());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn in_memory(table_builder: TableWriterBuilder) -> Self {
+        let shp_dest = InMemoryBuffer {
+            buffers: Arc::new(Mutex::new(Default::default())),
+            index: InMemoryBuffer::SHP,
+        };
+        let shx_dest = shp_dest.with_same_buffers(InMemoryBuffer::SHX);
+        let dbf_dest = shp_dest.with_same_buffers(InMemoryBuffer::DBF);
+        Self {
+            shape_writer: ShapeWriter::with_shx(shp_dest, shx_dest),
+            dbase_writer: table_builder.build_with_dest(dbf_dest),
+        }
+    }
+
+    /// Closes the writer and returns the content of the _.shp_, _.shx_ and _.dbf_
+    pub fn finish_to_bytes(self) -> Result<ShapefileBytes, Error> {
+        let Writer {
+            mut shape_writer,
+            mut dbase_writer,
+        } = self;
+        shape_writer.close()?;
+        dbase_writer.close()?;
+        let buffers = shape_writer.shp_dest.with_same_buffers(InMemoryBuffer::SHP);
+        drop(shape_writer);
+        drop(dbase_writer);
+        Ok((
+            buffers.take(InMemoryBuffer::SHP),
+            buffers.take(InMemoryBuffer::SHX),
+            buffers.take(InMemoryBuffer::DBF),
+        ))
+    }
+}
+
+/// Builder to create a [Writer] and the fields of its _.dbf_
+///
+/// The fields are added to a [dbase::TableWriterBuilder], the names given
+/// are only checked when calling [WriterBuilder::build].
+///
+/// # Example
+///
+/// ```
+/// # fn main() -> Result<(), shapefile::Error> {
+/// use shapefile::dbase::{FieldValue, Record};
+/// let mut writer = shapefile::WriterBuilder::new("builder_cities.shp")
+///     .add_field_character("name", 50)
+///     .add_field_numeric("pop", 10, 0)
+///     .build()?;
+///
+/// let mut record = Record::default();
+/// record.insert("name".to_string(), FieldValue::Character(Some("Paris".to_string())));
+/// record.insert("pop".to_string(), FieldValue::Numeric(Some(2_161_000.0)));
+/// writer.write_shape_and_record(&shapefile::Point::new(2.35, 48.85), &record)?;
+/// drop(writer);
+///
+/// let shape_records = shapefile::read("builder_cities.shp")?;
+/// assert_eq!(shape_records.len(), 1);
+/// assert_eq!(shape_records[0].1, record);
+/// # std::fs::remove_file("builder_cities.shp")?;
+/// # std::fs::remove_file("builder_cities.shx")?;
+/// # std::fs::remove_file("builder_cities.dbf")?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct WriterBuilder<P: AsRef<Path>> {
+    path: P,
+    table_builder: TableWriterBuilder,
+    invalid_field_name: Option<String>,
+}
+
+impl<P: AsRef<Path>> WriterBuilder<P> {
+    /// Creates a builder for a shapefile at `path` with no fields
+    pub fn new(path: P) -> Self {
+        Self {
+            path,
+            table_builder: TableWriterBuilder::new(),
+            invalid_field_name: None,
+        }
+    }
+
+    /// Adds a character field
+    pub fn add_field_character(self, name: &str, length: u8) -> Self {
+        self.add_field(name, |builder, name| {
+            builder.add_character_field(name, length)
+        })
+    }
+
+    /// Adds a numeric field
+    pub fn add_field_numeric(self, name: &str, length: u8, num_decimals: u8) -> Self {
+        self.add_field(name, |builder, name| {
+            builder.add_numeric_field(name, length, num_decimals)
+        })
+    }
+
+    fn add_field<F>(mut self, name: &str, add: F) -> Self
+    where
+        F: FnOnce(TableWriterBuilder, dbase::FieldName) -> TableWriterBuilder,
+    {
+        match dbase::FieldName::try_from(name) {
+            Ok(field_name) => self.table_builder = add(self.table_builder, field_name),
+            Err(_) => {
+                if self.invalid_field_name.is_none() {
+                    self.invalid_field_name = Some(name.to_string());
+                }
+            }
+        }
+        self
+    }
+
+    /// Creates the _.shp_, _.shx_ and _.dbf_ files and returns the [Writer]
+    ///
+    /// # Errors
+    ///
+    /// Returns [Error::InvalidFieldName] if one of the field names is not valid.
+    pub fn build(self) -> Result<Writer<BufWriter<File>>, Error> {
+        if let Some(name) = self.invalid_field_name {
+            return Err(Error::InvalidFieldName(name));
+        }
+        Writer::from_path(self.path, self.table_builder)
+    }
+}
+
+/// Copies the shapes and records of the reader to the writer,
+/// passing each of them through `f` on the way
+///
+/// When `f` returns `None`, the shape and its record are not written,
+/// as both are always written together, the _.shp_ and _.dbf_ stay aligned.
+/// The type of the output is given by the first shape written.
+///
+/// # Example
+///
+/// ```
+/// # fn main() -> Result<(), shapefile::Error> {
+/// let table_info = shapefile::Reader::from_path("tests/data/multipatch.shp")?.into_table_info();
+/// let mut reader = shapefile::Reader::from_path("tests/data/multipatch.shp")?;
+/// let mut writer = shapefile::Writer::from_path_with_info("copied_multipatch.shp", table_info)?;
+/// shapefile::copy_transform(&mut reader, &mut writer, |shape, record| Some((shape, record)))?;
+/// drop(writer);
+///
+/// assert_eq!(
+///     shapefile::read("copied_multipatch.shp")?,
+///     shapefile::read("tests/data/multipatch.shp")?
+/// );
+/// # std::fs::remove_file("copied_multipatch.shp")?;
+/// # std::fs::remove_file("copied_multipatch.shx")?;
+/// # std::fs::remove_file("copied_multipatch.dbf")?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn copy_transform<T, D, W, F>(
+    reader: &mut Reader<T, D>,
+    writer: &mut Writer<W>,
+    mut f: F,
+) -> Result<(), Error>
+where
+    T: Read + Seek,
+    D: Read + Seek,
+    W: Write + Seek,
+    F: FnMut(Shape, dbase::Record) -> Option<(Shape, dbase::Record)>,
+{
+    for shape_record in reader.iter_shapes_and_records() {
+        let (shape, record) = shape_record?;
+        if let Some((shape, record)) = f(shape, record) {
+            writer.shape_writer.write_any_shape(&shape)?;
+            writer.dbase_writer.write_record(&record)?;
+        }
+    }
+    Ok(())
+}
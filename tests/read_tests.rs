@@ -337,10 +337,12 @@ fn check_polygon<T: Read + Seek>(reader: shapefile::ShapeReader<T>) {
             Point { x: 117.0, y: 36.0 },
             Point { x: 115.0, y: 32.0 },
         ]);
-        assert_eq!(shp.ring(0), Some(&first_part));
-        assert_eq!(shp.ring(1), Some(&second_part));
-        assert_eq!(shp.ring(2), Some(&third_part));
-        assert_eq!(shp.rings().len(), 3);
+        // The first part is an inner ring, so an empty outer ring is added before it
+        assert_eq!(shp.ring(0), Some(&PolygonRing::Outer(vec![])));
+        assert_eq!(shp.ring(1), Some(&first_part));
+        assert_eq!(shp.ring(2), Some(&second_part));
+        assert_eq!(shp.ring(3), Some(&third_part));
+        assert_eq!(shp.rings().len(), 4);
     } else {
         assert!(false, "The second shape is not a Polygon");
     }
@@ -817,3 +819,1106 @@ fn read_point_as_wrong_type() {
         assert!(false);
     }
 }
+
+#[test]
+fn read_into_reuses_buffer() {
+    let mut points = Vec::<shapefile::Point>::new();
+
+    let mut reader = shapefile::ShapeReader::from_path(testfiles::POINT_PATH).unwrap();
+    reader.read_into(&mut points).unwrap();
+    assert_eq!(points.len(), 1);
+    check_first_point(&points[0]);
+    let capacity = points.capacity();
+
+    // Reading again with the same reader starts over from the first shape
+    reader.read_into(&mut points).unwrap();
+    assert_eq!(points.len(), 1);
+    check_first_point(&points[0]);
+
+    let mut reader = shapefile::ShapeReader::from_path(testfiles::POINT_PATH).unwrap();
+    reader.read_into(&mut points).unwrap();
+    assert_eq!(points.len(), 1);
+    assert_eq!(points.capacity(), capacity);
+    check_first_point(&points[0]);
+}
+
+#[test]
+fn read_line_from_bytes() {
+    let shp_bytes = std::fs::read(testfiles::LINE_PATH).unwrap();
+    let reader = shapefile::ShapeReader::from_bytes(&shp_bytes).unwrap();
+    check_line(reader);
+
+    let shx_bytes = std::fs::read(testfiles::LINE_SHX_PATH).unwrap();
+    let reader = shapefile::ShapeReader::with_shx_bytes(&shp_bytes, &shx_bytes).unwrap();
+    assert_eq!(reader.shape_count().unwrap(), 1);
+    check_line(reader);
+}
+
+#[test]
+fn with_shx_validated_detects_inconsistent_headers() {
+    use shapefile::{Error, ShapeType};
+    let point_shp = std::fs::read(testfiles::POINT_PATH).unwrap();
+    let point_shx = std::fs::read(testfiles::POINT_SHX_PATH).unwrap();
+    let line_shx = std::fs::read(testfiles::LINE_SHX_PATH).unwrap();
+
+    assert!(
+        shapefile::ShapeReader::with_shx_validated(Cursor::new(&point_shp), &point_shx[..]).is_ok()
+    );
+
+    match shapefile::ShapeReader::with_shx_validated(Cursor::new(&point_shp), &line_shx[..]) {
+        Err(Error::InconsistentIndex {
+            shp_type: ShapeType::Point,
+            shx_type: ShapeType::Polyline,
+        }) => {}
+        _ => panic!("Expected an InconsistentIndex error"),
+    }
+
+    // Same shape type, but a different x min in the bbox
+    let mut wrong_bbox_shx = point_shx.clone();
+    wrong_bbox_shx[36..44].copy_from_slice(&(-1.0f64).to_le_bytes());
+    match shapefile::ShapeReader::with_shx_validated(Cursor::new(&point_shp), &wrong_bbox_shx[..]) {
+        Err(Error::InconsistentIndex {
+            shp_type: ShapeType::Point,
+            shx_type: ShapeType::Point,
+        }) => {}
+        _ => panic!("Expected an InconsistentIndex error"),
+    }
+
+    // The non validating constructor still accepts it
+    assert!(shapefile::ShapeReader::with_shx(Cursor::new(&point_shp), &line_shx[..]).is_ok());
+}
+
+#[test]
+fn read_records_only() {
+    let records = shapefile::Reader::read_records_only(testfiles::MULTIPATCH_PATH).unwrap();
+    let shape_records = shapefile::read(testfiles::MULTIPATCH_PATH).unwrap();
+    assert_eq!(records.len(), shape_records.len());
+    assert_eq!(records[0], shape_records[0].1);
+
+    // The path to the .dbf also works
+    let dbf_path = std::path::Path::new(testfiles::MULTIPATCH_PATH).with_extension("dbf");
+    assert_eq!(
+        shapefile::Reader::read_records_only(dbf_path).unwrap(),
+        records
+    );
+
+    match shapefile::Reader::read_records_only(testfiles::LINEM_PATH) {
+        Err(shapefile::Error::MissingDbf) => {}
+        _ => panic!("Expected a MissingDbf error"),
+    }
+}
+
+#[test]
+fn verify_bbox_detects_wrong_header() {
+    let mut reader = shapefile::ShapeReader::from_path(testfiles::POLYGON_PATH).unwrap();
+    let verification = reader.verify_bbox::<Polygon>().unwrap();
+    assert!(verification.matches);
+    assert_eq!(verification.header_bbox, verification.computed_bbox);
+
+    // Write a file whose header lies about the extent of the data
+    let points = vec![Point::new(1.0, 2.0), Point::new(3.0, 4.0)];
+    let wrong_bbox = shapefile::record::BBoxZ {
+        max: PointZ::new(10.0, 10.0, 0.0, 0.0),
+        min: PointZ::new(0.0, 0.0, 0.0, 0.0),
+    };
+    let mut cursor = Cursor::new(Vec::<u8>::new());
+    {
+        let mut writer = shapefile::ShapeWriter::new(&mut cursor);
+        writer.set_header_bbox(wrong_bbox);
+        writer.write_shapes(&points).unwrap();
+    }
+    cursor.seek(SeekFrom::Start(0)).unwrap();
+
+    let mut reader = shapefile::ShapeReader::new(cursor).unwrap();
+    let verification = reader.verify_bbox::<Point>().unwrap();
+    assert!(!verification.matches);
+    assert_eq!(verification.header_bbox, wrong_bbox);
+    assert_eq!(
+        verification.computed_bbox.min,
+        PointZ::new(1.0, 2.0, 0.0, 0.0)
+    );
+    assert_eq!(
+        verification.computed_bbox.max,
+        PointZ::new(3.0, 4.0, 0.0, 0.0)
+    );
+}
+
+#[test]
+fn verify_bbox_with_null_shapes() {
+    let mut cursor = Cursor::new(Vec::<u8>::new());
+    {
+        let mut writer = shapefile::ShapeWriter::new(&mut cursor);
+        writer.write_null_shape().unwrap();
+        writer.write_shape(&Point::new(1.0, 2.0)).unwrap();
+        writer.write_null_shape().unwrap();
+        writer.write_shape(&Point::new(3.0, 4.0)).unwrap();
+    }
+    cursor.seek(SeekFrom::Start(0)).unwrap();
+
+    let mut reader = shapefile::ShapeReader::new(cursor).unwrap();
+    let verification = reader.verify_bbox::<shapefile::Shape>().unwrap();
+    assert!(verification.matches);
+    assert_eq!(
+        verification.computed_bbox.min,
+        PointZ::new(1.0, 2.0, 0.0, 0.0)
+    );
+    assert_eq!(
+        verification.computed_bbox.max,
+        PointZ::new(3.0, 4.0, 0.0, 0.0)
+    );
+}
+
+#[test]
+fn shape_type_histogram_with_null_shapes() {
+    use shapefile::ShapeType;
+    let mut cursor = Cursor::new(Vec::<u8>::new());
+    {
+        let mut writer = shapefile::ShapeWriter::new(&mut cursor);
+        writer.write_shape(&Point::new(1.0, 1.0)).unwrap();
+        writer.write_null_shape().unwrap();
+    }
+    cursor.seek(SeekFrom::Start(0)).unwrap();
+
+    let mut reader = shapefile::ShapeReader::new(cursor).unwrap();
+    let histogram = reader.shape_type_histogram().unwrap();
+    assert_eq!(histogram.len(), 2);
+    assert_eq!(histogram[&ShapeType::Point], 1);
+    assert_eq!(histogram[&ShapeType::NullShape], 1);
+}
+
+#[test]
+fn read_polygonz_with_capacity() {
+    let reader =
+        shapefile::ShapeReader::from_path_with_capacity(testfiles::POLYGONZ_PATH, 1024 * 1024)
+            .unwrap();
+    let shapes = reader.read().unwrap();
+    let expected = shapefile::read_shapes(testfiles::POLYGONZ_PATH).unwrap();
+    assert_eq!(shapes, expected);
+
+    let reader =
+        shapefile::ShapeReader::from_path_with_capacity(testfiles::POLYGONZ_PATH, 1024 * 1024)
+            .unwrap();
+    check_polygonz(reader);
+}
+
+#[test]
+fn read_polyline_with_null_shape_as_optional() {
+    let first = Polyline::new(vec![Point::new(1.0, 1.0), Point::new(2.0, 2.0)]);
+    let last = Polyline::new(vec![Point::new(3.0, 3.0), Point::new(4.0, 5.0)]);
+    let mut cursor = Cursor::new(Vec::<u8>::new());
+    {
+        let mut writer = shapefile::ShapeWriter::new(&mut cursor);
+        writer.write_shape(&first).unwrap();
+        writer.write_null_shape().unwrap();
+        writer.write_shape(&last).unwrap();
+    }
+
+    cursor.seek(SeekFrom::Start(0)).unwrap();
+    let mut reader = shapefile::ShapeReader::new(&mut cursor).unwrap();
+    assert!(reader
+        .iter_shapes_as::<Polyline>()
+        .collect::<Result<Vec<_>, _>>()
+        .is_err());
+
+    cursor.seek(SeekFrom::Start(0)).unwrap();
+    let mut reader = shapefile::ShapeReader::new(&mut cursor).unwrap();
+    let polylines = reader
+        .iter_shapes_as_optional::<Polyline>()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(polylines, vec![Some(first), None, Some(last)]);
+}
+
+#[test]
+fn read_range_of_shapes() {
+    let points = vec![
+        Point::new(1.0, 1.0),
+        Point::new(2.0, 2.0),
+        Point::new(3.0, 3.0),
+        Point::new(4.0, 4.0),
+    ];
+    let mut shp = Cursor::new(Vec::<u8>::new());
+    let mut shx = Cursor::new(Vec::<u8>::new());
+    {
+        let writer = shapefile::ShapeWriter::with_shx(&mut shp, &mut shx);
+        writer.write_shapes(&points).unwrap();
+    }
+    shp.set_position(0);
+    shx.set_position(0);
+
+    let mut reader = shapefile::ShapeReader::with_shx(&mut shp, &mut shx).unwrap();
+    let range = reader.read_range_as::<Point>(1..3).unwrap();
+    assert_eq!(range, &points[1..3]);
+
+    let range = reader.read_range_as::<Point>(2..10).unwrap();
+    assert_eq!(range, &points[2..]);
+    assert!(reader.read_range_as::<Point>(5..10).unwrap().is_empty());
+
+    shp.set_position(0);
+    let mut reader = shapefile::ShapeReader::new(&mut shp).unwrap();
+    match reader.read_range_as::<Point>(1..3) {
+        Err(shapefile::Error::MissingIndexFile) => {}
+        _ => panic!("Expected a MissingIndexFile error"),
+    }
+}
+
+#[test]
+fn read_point_as_f32() {
+    let points = shapefile::read_shapes_as::<_, Point>(testfiles::POINT_PATH).unwrap();
+    let points_f32 =
+        shapefile::read_shapes_as::<_, shapefile::PointF32>(testfiles::POINT_PATH).unwrap();
+
+    assert_eq!(points.len(), points_f32.len());
+    for (point, point_f32) in points.iter().zip(&points_f32) {
+        assert!(point.approx_eq(&Point::from(*point_f32), 1e-5));
+    }
+
+    let reader = shapefile::ShapeReader::from_path(testfiles::LINE_PATH).unwrap();
+    assert!(reader.read_as::<shapefile::PointF32>().is_err());
+}
+
+#[test]
+fn read_with_owning_iterator() {
+    let reader = shapefile::ShapeReader::from_path(testfiles::POLYGON_HOLE_PATH).unwrap();
+    let shapes = reader.into_iter().collect::<Result<Vec<_>, _>>().unwrap();
+    let expected = shapefile::read_shapes(testfiles::POLYGON_HOLE_PATH).unwrap();
+    assert_eq!(shapes, expected);
+}
+
+#[test]
+fn polygon_num_parts() {
+    let shapes = shapefile::read_shapes(testfiles::POLYGON_PATH).unwrap();
+    assert_eq!(shapes[0].num_parts(), 3);
+}
+
+#[test]
+fn read_polygon_with_orphan_inner_ring() {
+    let polygons = shapefile::read_shapes_as::<_, Polygon>(testfiles::POLYGON_PATH).unwrap();
+    let polygon = &polygons[0];
+    // The first ring of the file is an inner ring,
+    // it follows the empty outer ring added when reading
+    assert_eq!(polygon.rings()[0], PolygonRing::Outer(vec![]));
+    assert!(matches!(polygon.rings()[1], PolygonRing::Inner(_)));
+
+    // num_points is after the record header (8), shape type (4), bbox (32) and num_parts (4)
+    let bytes = std::fs::read(testfiles::POLYGON_PATH).unwrap();
+    let num_points_pos = 100 + 8 + 4 + 32 + 4;
+    let num_points = i32::from_le_bytes([
+        bytes[num_points_pos],
+        bytes[num_points_pos + 1],
+        bytes[num_points_pos + 2],
+        bytes[num_points_pos + 3],
+    ]);
+    assert_eq!(polygon.total_point_count(), num_points as usize);
+}
+
+#[test]
+fn polygon_with_orphan_inner_ring_outer_ring_count() {
+    let polygons = shapefile::read_shapes_as::<_, Polygon>(testfiles::POLYGON_PATH).unwrap();
+    // The empty outer ring added when reading is not counted
+    assert_eq!(polygons[0].outer_ring_count(), 1);
+    assert!(!polygons[0].is_multi());
+}
+
+#[test]
+fn polygon_with_orphan_inner_ring_into_polygons() {
+    let mut polygons = shapefile::read_shapes_as::<_, Polygon>(testfiles::POLYGON_PATH).unwrap();
+    let polygon = polygons.pop().unwrap();
+    let rings = polygon.rings()[1..].to_vec();
+
+    let polygons = polygon.into_polygons();
+    assert_eq!(polygons.len(), 2);
+    assert_eq!(polygons[0].rings(), &rings[..1]);
+    assert_eq!(polygons[1].rings(), &rings[1..]);
+}
+
+#[test]
+fn polygon_with_orphan_inner_ring_is_valid() {
+    let inner_ring = PolygonRing::Inner(vec![
+        Point::new(0.0, 0.0),
+        Point::new(1.0, 0.0),
+        Point::new(1.0, 1.0),
+        Point::new(0.0, 1.0),
+        Point::new(0.0, 0.0),
+    ]);
+    let mut cursor = Cursor::new(Vec::<u8>::new());
+    {
+        let mut writer = shapefile::ShapeWriter::new(&mut cursor);
+        writer
+            .write_shape(&Polygon::with_rings(vec![inner_ring]))
+            .unwrap();
+    }
+    cursor.seek(SeekFrom::Start(0)).unwrap();
+
+    let reader = shapefile::ShapeReader::new(cursor).unwrap();
+    let polygons = reader.read_as::<Polygon>().unwrap();
+    assert_eq!(polygons[0].rings()[0], PolygonRing::Outer(vec![]));
+    assert!(polygons[0].is_valid());
+}
+
+#[test]
+fn polygon_with_orphan_inner_ring_to_svg_path() {
+    let shapes = shapefile::read_shapes(testfiles::POLYGON_PATH).unwrap();
+    let path = shapes[0].to_svg_path();
+    assert!(path.starts_with("M 122 37 L 117 36"), "{}", path);
+    assert_eq!(path.matches('M').count(), 3);
+}
+
+#[test]
+fn polygon_with_orphan_inner_ring_display() {
+    let shapes = shapefile::read_shapes(testfiles::POLYGON_PATH).unwrap();
+    assert_eq!(shapes[0].to_string(), "Shape::Polygon(3 rings)");
+}
+
+#[cfg(feature = "wkb")]
+#[test]
+fn polygon_with_orphan_inner_ring_to_ewkb() {
+    let shapes = shapefile::read_shapes(testfiles::POLYGON_PATH).unwrap();
+    let mut ewkb = vec![];
+    shapes[0].to_ewkb(4326, &mut ewkb).unwrap();
+
+    let read_u32 =
+        |pos: usize| u32::from_le_bytes([ewkb[pos], ewkb[pos + 1], ewkb[pos + 2], ewkb[pos + 3]]);
+    // byte order + type + srid, then the number of polygons
+    assert_eq!(read_u32(9), 2);
+    // The first polygon is made of the orphan inner ring only,
+    // its number of rings and points follow its byte order and type
+    assert_eq!(read_u32(18), 1);
+    assert_eq!(read_u32(22), 5);
+}
+
+#[cfg(feature = "geo-types")]
+#[test]
+fn polygon_with_orphan_inner_ring_geo_types_round_trip() {
+    let mut polygons = shapefile::read_shapes_as::<_, Polygon>(testfiles::POLYGON_PATH).unwrap();
+    let polygon = polygons.pop().unwrap();
+    let ring_kinds = |polygon: &Polygon| {
+        polygon
+            .rings()
+            .iter()
+            .map(|ring| matches!(ring, PolygonRing::Outer(_)))
+            .collect::<Vec<_>>()
+    };
+    let expected_kinds = ring_kinds(&polygon);
+
+    let multi_polygon = geo_types::MultiPolygon::<f64>::from(polygon);
+    // The empty outer ring is the exterior of the orphan inner ring
+    assert!(multi_polygon.0[0].exterior().0.is_empty());
+    assert_eq!(multi_polygon.0[0].interiors().len(), 1);
+
+    let polygon = Polygon::from(multi_polygon);
+    assert_eq!(ring_kinds(&polygon), expected_kinds);
+}
+
+#[cfg(feature = "geo-types")]
+#[test]
+fn read_polygon_as_geometry_collection() {
+    let collection = shapefile::read_as_geometry_collection(testfiles::POLYGON_PATH).unwrap();
+    assert_eq!(collection.len(), 1);
+    match &collection[0] {
+        geo_types::Geometry::MultiPolygon(multi_polygon) => {
+            assert_eq!(multi_polygon.0.len(), 2);
+        }
+        _ => panic!("Expected a MultiPolygon"),
+    }
+}
+
+#[cfg(feature = "geo-types")]
+#[test]
+fn iter_geometries_of_line() {
+    let mut reader = shapefile::ShapeReader::from_path(testfiles::LINE_PATH).unwrap();
+    let geometries = reader
+        .iter_geometries()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(geometries.len(), 1);
+    assert!(matches!(
+        geometries[0],
+        geo_types::Geometry::MultiLineString(_)
+    ));
+}
+
+#[test]
+fn read_pointm_with_no_data_threshold() {
+    use shapefile::reader::ReadOptions;
+    use shapefile::{Shape, NO_DATA};
+
+    let points = vec![
+        PointM::new(1.0, 1.0, 0.0),
+        PointM::new(2.0, 2.0, 5.0),
+        PointM::new(3.0, 3.0, -2.0),
+    ];
+    let mut shp = Cursor::new(Vec::<u8>::new());
+    {
+        let writer = shapefile::ShapeWriter::new(&mut shp);
+        writer.write_shapes(&points).unwrap();
+    }
+
+    shp.set_position(0);
+    let reader = shapefile::ShapeReader::new(&mut shp).unwrap();
+    let shapes = reader.read_with_options(ReadOptions::default()).unwrap();
+    assert_eq!(shapes[0], Shape::PointM(points[0]));
+    assert_eq!(shapes[2], Shape::PointM(points[2]));
+
+    shp.set_position(0);
+    let reader = shapefile::ShapeReader::new(&mut shp).unwrap();
+    let shapes = reader
+        .read_with_options(ReadOptions {
+            no_data_threshold: 0.0,
+        })
+        .unwrap();
+    assert_eq!(shapes[0], Shape::PointM(PointM::new(1.0, 1.0, NO_DATA)));
+    assert_eq!(shapes[1], Shape::PointM(points[1]));
+    assert_eq!(shapes[2], Shape::PointM(PointM::new(3.0, 3.0, NO_DATA)));
+}
+
+#[test]
+fn iter_polylinem_with_no_data_threshold() {
+    use shapefile::reader::ReadOptions;
+    use shapefile::{PolylineM, NO_DATA};
+
+    let polyline = PolylineM::new(vec![
+        PointM::new(1.0, 1.0, 0.0),
+        PointM::new(2.0, 2.0, 5.0),
+        PointM::new(3.0, 3.0, -2.0),
+    ]);
+    let mut shp = Cursor::new(Vec::<u8>::new());
+    {
+        let writer = shapefile::ShapeWriter::new(&mut shp);
+        writer.write_shapes(&[polyline]).unwrap();
+    }
+
+    shp.set_position(0);
+    let mut reader = shapefile::ShapeReader::new(&mut shp).unwrap();
+    reader.set_read_options(ReadOptions {
+        no_data_threshold: 0.0,
+    });
+    let polylines = reader
+        .iter_shapes_as::<PolylineM>()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    let ms = polylines[0]
+        .parts()
+        .iter()
+        .flatten()
+        .map(|point| point.m)
+        .collect::<Vec<_>>();
+    assert_eq!(ms, vec![NO_DATA, 5.0, NO_DATA]);
+}
+
+#[test]
+fn read_empty_shapefile() {
+    let mut shp = Cursor::new(Vec::<u8>::new());
+    {
+        let _writer = shapefile::ShapeWriter::new(&mut shp);
+    }
+
+    shp.set_position(0);
+    let reader = shapefile::ShapeReader::new(&mut shp).unwrap();
+    assert!(reader.is_empty());
+    assert_eq!(reader.shape_count().unwrap(), 0);
+    assert!(reader.read().unwrap().is_empty());
+
+    let reader = shapefile::ShapeReader::from_path(testfiles::LINE_PATH).unwrap();
+    assert!(!reader.is_empty());
+}
+
+#[test]
+fn multipatch_ring_patches_into_polygon_z() {
+    let mut multipatches =
+        shapefile::read_shapes_as::<_, Multipatch>(testfiles::MULTIPATCH_PATH).unwrap();
+    let patches = multipatches.pop().unwrap().patches().clone();
+    let is_ring = |patch: &Patch| {
+        matches!(
+            patch,
+            Patch::OuterRing(_) | Patch::InnerRing(_) | Patch::FirstRing(_) | Patch::Ring(_)
+        )
+    };
+    // The file only has triangle patches
+    let ring_patches: Vec<Patch> = patches.iter().filter(|p| is_ring(p)).cloned().collect();
+    assert!(ring_patches.is_empty());
+
+    // Use the points of the file as rings of each type
+    let points = patches[0].points().to_vec();
+    let mut cursor = Cursor::new(Vec::<u8>::new());
+    {
+        let writer = shapefile::ShapeWriter::new(&mut cursor);
+        writer
+            .write_shapes(&[Multipatch::with_parts(vec![
+                patches[0].clone(),
+                Patch::OuterRing(points.clone()),
+                Patch::InnerRing(patches[1].points().to_vec()),
+                Patch::FirstRing(points.clone()),
+                Patch::Ring(points),
+            ])])
+            .unwrap();
+    }
+    cursor.seek(SeekFrom::Start(0)).unwrap();
+    let mut multipatches = shapefile::ShapeReader::new(cursor)
+        .unwrap()
+        .read_as::<Multipatch>()
+        .unwrap();
+    let ring_patches: Vec<Patch> = multipatches
+        .pop()
+        .unwrap()
+        .patches()
+        .iter()
+        .filter(|p| is_ring(p))
+        .cloned()
+        .collect();
+    assert_eq!(ring_patches.len(), 4);
+
+    let polygon = Multipatch::with_parts(ring_patches)
+        .into_polygon_z()
+        .unwrap();
+    let is_outer = polygon
+        .rings()
+        .iter()
+        .map(|ring| matches!(ring, PolygonRing::Outer(_)))
+        .collect::<Vec<_>>();
+    // FirstRing and Ring patches become outer rings
+    assert_eq!(is_outer, vec![true, false, true, true]);
+}
+
+#[test]
+fn read_multipatch_unzipped() {
+    let mut reader = shapefile::Reader::from_path(testfiles::MULTIPATCH_PATH).unwrap();
+    let (shapes, records) = reader.read_unzipped().unwrap();
+    assert_eq!(shapes.len(), records.len());
+    assert_eq!(shapes.len(), 1);
+
+    let mut reader = shapefile::Reader::from_path(testfiles::MULTIPATCH_PATH).unwrap();
+    let expected = reader.read().unwrap();
+    for ((shape, record), (expected_shape, expected_record)) in
+        shapes.iter().zip(&records).zip(&expected)
+    {
+        assert_eq!(shape, expected_shape);
+        assert_eq!(record, expected_record);
+    }
+
+    let mut reader = shapefile::Reader::from_path(testfiles::MULTIPATCH_PATH).unwrap();
+    let (multipatches, records) = reader
+        .read_unzipped_as::<Multipatch, dbase::Record>()
+        .unwrap();
+    assert_eq!(multipatches.len(), records.len());
+}
+
+#[test]
+fn iter_typed_records_only() {
+    use dbase::{FieldIOError, FieldIterator, ReadableRecord};
+
+    struct Building {
+        name: String,
+    }
+
+    impl ReadableRecord for Building {
+        fn read_using<Source, MemoSource>(
+            field_iterator: &mut FieldIterator<Source, MemoSource>,
+        ) -> Result<Self, FieldIOError>
+        where
+            Source: Read + Seek,
+            MemoSource: Read + Seek,
+        {
+            let name = field_iterator.read_next_field_as::<String>()?.value;
+            Ok(Self { name })
+        }
+    }
+
+    let mut reader = shapefile::Reader::from_path(testfiles::MULTIPATCH_PATH).unwrap();
+    let buildings = reader
+        .iter_records_as::<Building>()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(buildings.len(), 1);
+    assert_eq!(buildings[0].name, "house1");
+}
+
+#[test]
+fn read_with_dbase_options() {
+    use dbase::{FieldValue, ReadingOptions, TrimOption, UnicodeLossy};
+
+    let name_of = |options: ReadingOptions| {
+        let mut reader = shapefile::Reader::from_path_with_dbase_options(
+            testfiles::MULTIPATCH_PATH,
+            options,
+            UnicodeLossy,
+        )
+        .unwrap();
+        let (_, record) = reader.read().unwrap().pop().unwrap();
+        match record.get("name") {
+            Some(FieldValue::Character(Some(name))) => name.clone(),
+            value => panic!("unexpected name {:?}", value),
+        }
+    };
+
+    assert_eq!(name_of(ReadingOptions::default()), "house1");
+    // The value is padded with spaces up to the field length of 50
+    let untrimmed = name_of(ReadingOptions::default().character_trim(TrimOption::Begin));
+    assert_eq!(untrimmed.len(), 50);
+    assert_eq!(untrimmed.trim_end(), "house1");
+}
+
+#[test]
+fn read_with_dbase_encoding() {
+    use dbase::{FieldValue, ReadingOptions, Unicode};
+
+    let mut writer = shapefile::Writer::in_memory(testfiles::name_table_builder());
+    writer
+        .write_shape_and_record(
+            &Point::new(1.0, 2.0),
+            &testfiles::name_record("Parés".to_string()),
+        )
+        .unwrap();
+    let (shp, shx, mut dbf) = writer.finish_to_bytes().unwrap();
+
+    let dir = std::env::temp_dir().join("shapefile_read_with_dbase_encoding");
+    std::fs::create_dir_all(&dir).unwrap();
+    let shp_path = dir.join("points.shp");
+    std::fs::write(&shp_path, shp).unwrap();
+    std::fs::write(shp_path.with_extension("shx"), shx).unwrap();
+    std::fs::write(shp_path.with_extension("dbf"), &dbf).unwrap();
+
+    let read_name = |reader: &mut shapefile::Reader<_, _>| {
+        let (_, record) = reader.read()?.pop().unwrap();
+        Ok::<_, shapefile::Error>(record.get("name").cloned())
+    };
+    let strict_reader = || {
+        shapefile::Reader::from_path_with_dbase_options(
+            &shp_path,
+            ReadingOptions::default(),
+            Unicode,
+        )
+        .unwrap()
+    };
+
+    assert_eq!(
+        read_name(&mut strict_reader()).unwrap(),
+        Some(FieldValue::Character(Some("Parés".to_string())))
+    );
+
+    // Make the name invalid UTF-8, the default decoding replaces
+    // the invalid bytes while the strict one fails
+    let name_pos = dbf.windows(3).position(|bytes| bytes == b"Par").unwrap();
+    dbf[name_pos + 3] = 0xFF;
+    std::fs::write(shp_path.with_extension("dbf"), &dbf).unwrap();
+
+    assert_eq!(
+        read_name(&mut shapefile::Reader::from_path(&shp_path).unwrap()).unwrap(),
+        Some(FieldValue::Character(Some(
+            "Par\u{FFFD}\u{FFFD}s".to_string()
+        )))
+    );
+    assert!(read_name(&mut strict_reader()).is_err());
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[cfg(feature = "zip")]
+#[test]
+fn open_shapefile_inside_zip() {
+    let mut reader = shapefile::Reader::open("tests/data/bundle.zip/point.shp").unwrap();
+    assert_eq!(reader.shape_count().unwrap(), 1);
+    let shapes_and_records = reader.read().unwrap();
+    assert_eq!(shapes_and_records.len(), 1);
+
+    let expected = shapefile::ShapeReader::from_path(testfiles::POINT_PATH)
+        .unwrap()
+        .read()
+        .unwrap();
+    assert_eq!(shapes_and_records[0].0, expected[0]);
+
+    let mut reader = shapefile::Reader::open("/vsizip/tests/data/bundle.zip/point.shp").unwrap();
+    assert_eq!(reader.read().unwrap().len(), 1);
+
+    assert!(shapefile::Reader::open("tests/data/bundle.zip/line.shp").is_err());
+}
+
+#[test]
+fn iter_until_eof_ignores_short_file_length() {
+    let points = vec![
+        Point::new(1.0, 1.0),
+        Point::new(2.0, 2.0),
+        Point::new(3.0, 3.0),
+    ];
+    let mut shp = Cursor::new(Vec::<u8>::new());
+    shapefile::ShapeWriter::new(&mut shp)
+        .write_shapes(&points)
+        .unwrap();
+
+    // The file length (in 16-bit words, big endian) is at byte 24,
+    // a point record is 28 bytes: 8 of record header, 4 of shape type, 16 of coordinates
+    let mut bytes = shp.into_inner();
+    let file_length = i32::from_be_bytes([bytes[24], bytes[25], bytes[26], bytes[27]]);
+    bytes[24..28].copy_from_slice(&(file_length - 14).to_be_bytes());
+
+    let reader = shapefile::ShapeReader::new(Cursor::new(bytes.clone())).unwrap();
+    assert_eq!(reader.read_as::<Point>().unwrap().len(), 2);
+
+    let mut reader = shapefile::ShapeReader::new(Cursor::new(bytes.clone())).unwrap();
+    let read_points = reader
+        .iter_until_eof::<Point>()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(read_points, points);
+
+    // A truncated record is an error, not the end of the iteration
+    bytes.truncate(bytes.len() - 4);
+    let mut reader = shapefile::ShapeReader::new(Cursor::new(bytes)).unwrap();
+    let results = reader.iter_until_eof::<Point>().collect::<Vec<_>>();
+    assert_eq!(results.len(), 3);
+    assert!(results[2].is_err());
+}
+
+#[test]
+fn read_nth_record_bytes_of_polygon_hole() {
+    let mut reader = shapefile::ShapeReader::from_path(testfiles::POLYGON_HOLE_PATH).unwrap();
+    let index_entries = reader.index_entries().unwrap().to_vec();
+    for (i, entry) in index_entries.iter().enumerate() {
+        let bytes = reader.read_nth_record_bytes(i).unwrap().unwrap();
+        assert_eq!(bytes.len(), entry.record_size as usize * 2);
+        // Polygon shape type
+        assert_eq!(&bytes[..4], &5i32.to_le_bytes());
+    }
+    assert!(reader.read_nth_record_bytes(index_entries.len()).is_none());
+
+    let mut reader = shapefile::ShapeReader::from_path(testfiles::POLYGONZ_PATH).unwrap();
+    assert!(matches!(
+        reader.read_nth_record_bytes(0),
+        Some(Err(shapefile::Error::MissingIndexFile))
+    ));
+}
+
+#[test]
+fn read_nth_record_bytes_with_negative_record_size() {
+    let mut shp = Cursor::new(Vec::<u8>::new());
+    let mut shx = Cursor::new(Vec::<u8>::new());
+    {
+        let writer = shapefile::ShapeWriter::with_shx(&mut shp, &mut shx);
+        writer.write_shapes(&[Point::new(1.0, 1.0)]).unwrap();
+    }
+    // The record size of the first entry is after the header (100) and the offset (4)
+    shx.get_mut()[104..108].copy_from_slice(&(-1i32).to_be_bytes());
+    shp.set_position(0);
+    shx.set_position(0);
+
+    let mut reader = shapefile::ShapeReader::with_shx(&mut shp, &mut shx).unwrap();
+    assert!(matches!(
+        reader.read_nth_record_bytes(0),
+        Some(Err(shapefile::Error::InvalidShapeRecordSize))
+    ));
+}
+
+#[test]
+fn iter_record_types_of_polygon() {
+    use shapefile::ShapeType;
+
+    // Without .shx
+    let mut reader = shapefile::ShapeReader::from_path(testfiles::POLYGON_PATH).unwrap();
+    let shape_types = reader
+        .iter_record_types()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert!(!shape_types.is_empty());
+    assert!(shape_types.iter().all(|t| *t == ShapeType::Polygon));
+    // The reader can still be used after
+    assert_eq!(reader.read().unwrap().len(), shape_types.len());
+
+    // With .shx
+    let mut reader = shapefile::ShapeReader::from_path(testfiles::POLYGON_HOLE_PATH).unwrap();
+    let shape_types = reader
+        .iter_record_types()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(shape_types.len(), reader.shape_count().unwrap());
+    assert!(shape_types.iter().all(|t| *t == ShapeType::Polygon));
+}
+
+#[test]
+fn iter_record_types_with_negative_record_size() {
+    let mut bytes = std::fs::read(testfiles::LINE_PATH).unwrap();
+    // The record size of the first record is after the header (100) and the record number (4)
+    bytes[104..108].copy_from_slice(&(-1i32).to_be_bytes());
+
+    let mut reader = shapefile::ShapeReader::new(Cursor::new(bytes)).unwrap();
+    let mut shape_types = reader.iter_record_types();
+    assert!(matches!(
+        shape_types.next(),
+        Some(Err(shapefile::Error::InvalidShapeRecordSize))
+    ));
+    assert!(shape_types.next().is_none());
+}
+
+#[test]
+fn read_point_grouped() {
+    let mut reader = shapefile::ShapeReader::from_path(testfiles::POINT_PATH).unwrap();
+    let grouped = reader.read_grouped().unwrap();
+    assert_eq!(grouped.points.len(), 1);
+    check_first_point(&grouped.points[0]);
+    assert_eq!(grouped.null_shapes, 0);
+    assert!(grouped.points_m.is_empty());
+    assert!(grouped.polylines.is_empty());
+}
+
+#[test]
+fn polygon_m_coords() {
+    use shapefile::record::traits::HasCoords;
+    let polygons =
+        shapefile::read_shapes_as::<_, shapefile::PolygonM>(testfiles::POLYGONM_PATH).unwrap();
+    let polygon = &polygons[0];
+    let coords = polygon.coords().collect::<Vec<_>>();
+    assert_eq!(coords.len(), polygon.total_point_count());
+    let first_point = polygon.ring(0).unwrap().points()[0];
+    assert_eq!(coords[0].m, first_point.m);
+    assert_eq!(coords[0].z, 0.0);
+}
+
+#[test]
+fn read_polygon_with_bbox() {
+    let mut reader = shapefile::ShapeReader::from_path(testfiles::POLYGON_PATH).unwrap();
+    let shapes_bboxes = reader
+        .iter_shapes_with_bbox::<Polygon>()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(shapes_bboxes.len(), 1);
+    let (_, bbox) = &shapes_bboxes[0];
+    // The file has a single polygon, so its bbox is the one of the file
+    assert_eq!(bbox.min, PointZ::new(15.0, 2.0, 0.0, 0.0));
+    assert_eq!(bbox.max, PointZ::new(122.0, 37.0, 0.0, 0.0));
+}
+
+#[test]
+fn read_record_with_huge_size() {
+    let mut bytes = std::fs::read(testfiles::POINT_PATH).unwrap();
+    // The record size of the first record is right after its record number
+    bytes[104..108].copy_from_slice(&i32::MAX.to_be_bytes());
+
+    let mut reader = shapefile::ShapeReader::new(Cursor::new(bytes)).unwrap();
+    let result = reader.iter_shapes().next().unwrap();
+    match result {
+        Err(shapefile::Error::RecordTooLarge { size, max }) => {
+            assert_eq!(size, i32::MAX as usize * 2);
+            assert!(max < size);
+        }
+        other => panic!("Expected RecordTooLarge, got {:?}", other),
+    }
+}
+
+#[test]
+fn read_records_with_impossible_num_points() {
+    // header + record header + shape type + bbox
+    let counts_start = 100 + 8 + 4 + 32;
+
+    let mut bytes = std::fs::read(testfiles::LINE_PATH).unwrap();
+    // num_parts comes before num_points
+    bytes[counts_start + 4..counts_start + 8].copy_from_slice(&i32::MAX.to_le_bytes());
+    let mut reader = shapefile::ShapeReader::new(Cursor::new(bytes)).unwrap();
+    let result = reader.iter_shapes_as::<Polyline>().next().unwrap();
+    assert!(matches!(
+        result,
+        Err(shapefile::Error::InvalidShapeRecordSize)
+    ));
+
+    let mut bytes = std::fs::read(testfiles::MULTIPOINT_PATH).unwrap();
+    bytes[counts_start..counts_start + 4].copy_from_slice(&i32::MAX.to_le_bytes());
+    let mut reader = shapefile::ShapeReader::new(Cursor::new(bytes)).unwrap();
+    let result = reader.iter_shapes().next().unwrap();
+    assert!(matches!(
+        result,
+        Err(shapefile::Error::InvalidShapeRecordSize)
+    ));
+}
+
+#[test]
+fn read_pointz_into_soa() {
+    let mut reader = shapefile::ShapeReader::from_path(testfiles::POINTZ_PATH).unwrap();
+    let soa = reader.read_all_into_soa::<PointZ>().unwrap();
+    let points = shapefile::read_shapes_as::<_, PointZ>(testfiles::POINTZ_PATH).unwrap();
+
+    assert_eq!(soa.x.len(), points.len());
+    for (i, point) in points.iter().enumerate() {
+        assert_eq!(PointZ::new(soa.x[i], soa.y[i], soa.z[i], soa.m[i]), *point);
+    }
+}
+
+#[test]
+fn estimate_polygonz_memory() {
+    let reader = shapefile::ShapeReader::from_path(testfiles::POLYGONZ_PATH).unwrap();
+    let estimate = reader.estimate_memory().unwrap();
+
+    let shapes = reader.read().unwrap();
+    let num_points: usize = shapes
+        .iter()
+        .map(|shape| match shape {
+            shapefile::Shape::PolygonZ(polygon) => polygon.total_point_count(),
+            _ => 0,
+        })
+        .sum();
+    let points_memory = num_points * std::mem::size_of::<shapefile::PointZ>();
+    assert!(estimate >= points_memory);
+    // There is no polygonz.shx, so the estimate is an upper bound,
+    // but it stays within an order of magnitude of the file size
+    let file_size = std::fs::metadata(testfiles::POLYGONZ_PATH).unwrap().len() as usize;
+    assert!(estimate < 16 * file_size);
+}
+
+#[test]
+fn read_lenient_polygons_with_open_ring() {
+    use shapefile::reader::{PolygonFixup, PolygonFixupKind};
+
+    let open_ring = vec![
+        Point::new(0.0, 0.0),
+        Point::new(0.0, 4.0),
+        Point::new(4.0, 4.0),
+        Point::new(4.0, 0.0),
+    ];
+    let degenerated_ring = vec![
+        Point::new(1.0, 1.0),
+        Point::new(2.0, 2.0),
+        Point::new(1.0, 1.0),
+    ];
+    let polygon = Polygon::with_rings_unchecked(vec![
+        PolygonRing::Outer(open_ring.clone()),
+        PolygonRing::Inner(degenerated_ring.clone()),
+    ]);
+    let only_degenerated =
+        Polygon::with_rings_unchecked(vec![PolygonRing::Outer(degenerated_ring)]);
+
+    let mut cursor = Cursor::new(Vec::<u8>::new());
+    {
+        let writer = shapefile::ShapeWriter::new(&mut cursor);
+        writer.write_shapes(&[polygon, only_degenerated]).unwrap();
+    }
+    cursor.seek(SeekFrom::Start(0)).unwrap();
+
+    let mut reader = shapefile::ShapeReader::new(cursor).unwrap();
+    let (polygons, fixups) = reader.read_as_lenient_polygons::<Point>().unwrap();
+    assert_eq!(
+        fixups,
+        vec![
+            PolygonFixup {
+                record_number: 1,
+                ring: 0,
+                kind: PolygonFixupKind::ClosedRing,
+            },
+            PolygonFixup {
+                record_number: 1,
+                ring: 1,
+                kind: PolygonFixupKind::DroppedRing,
+            },
+            PolygonFixup {
+                record_number: 2,
+                ring: 0,
+                kind: PolygonFixupKind::DroppedRing,
+            },
+        ]
+    );
+
+    assert_eq!(polygons.len(), 2);
+    let polygon = polygons[0].as_ref().unwrap();
+    assert_eq!(polygon.rings().len(), 1);
+    let mut closed_ring = open_ring;
+    closed_ring.push(Point::new(0.0, 0.0));
+    assert_eq!(polygon.rings()[0], PolygonRing::Outer(closed_ring));
+    assert!(polygon.is_valid());
+    assert!(polygons[1].is_none());
+}
+
+#[test]
+fn filter_shapes_and_records_on_points() {
+    let points = [50.0, 150.0, 99.0, 250.0].map(|x| Point::new(x, 1.0));
+    let mut reader = testfiles::points_reader(&points);
+    reader.verify_record_counts().unwrap();
+    let kept = reader
+        .filter_shapes_and_records::<Point, dbase::Record, _>(|point| point.x > 100.0)
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+
+    assert_eq!(kept.len(), 2);
+    assert_eq!(kept[0].0, Point::new(150.0, 1.0));
+    assert_eq!(
+        kept[0].1.get("name"),
+        Some(&dbase::FieldValue::Character(Some("point 1".to_string())))
+    );
+    assert_eq!(kept[1].0, Point::new(250.0, 1.0));
+    assert_eq!(
+        kept[1].1.get("name"),
+        Some(&dbase::FieldValue::Character(Some("point 3".to_string())))
+    );
+}
+
+#[test]
+fn verify_record_counts_on_mismatched_files() {
+    let mut shp: Cursor<Vec<u8>> = Cursor::new(vec![]);
+    let mut shx: Cursor<Vec<u8>> = Cursor::new(vec![]);
+    let mut dbf: Cursor<Vec<u8>> = Cursor::new(vec![]);
+    {
+        let mut shape_writer = shapefile::ShapeWriter::with_shx(&mut shp, &mut shx);
+        for x in [1.0, 2.0, 3.0] {
+            shape_writer.write_shape(&Point::new(x, 1.0)).unwrap();
+        }
+
+        // One record less than there are shapes
+        let mut dbase_writer = testfiles::name_table_builder().build_with_dest(&mut dbf);
+        for i in 0..2 {
+            let record = testfiles::name_record(format!("point {}", i));
+            dbase_writer.write_record(&record).unwrap();
+        }
+    }
+
+    shp.set_position(0);
+    shx.set_position(0);
+    dbf.set_position(0);
+    let shape_reader = shapefile::ShapeReader::with_shx(&mut shp, &mut shx).unwrap();
+    let dbase_reader = dbase::Reader::new(&mut dbf).unwrap();
+    let reader = shapefile::Reader::new(shape_reader, dbase_reader);
+    match reader.verify_record_counts() {
+        Err(shapefile::Error::RecordCountMismatch { shp, dbf }) => {
+            assert_eq!(shp, 3);
+            assert_eq!(dbf, 2);
+        }
+        other => panic!("Expected RecordCountMismatch, got {:?}", other),
+    }
+}
+
+#[test]
+fn read_back_record_numbers() {
+    let points = [0.0, 1.0].map(|x| Point::new(x, 0.0));
+    let mut reader = testfiles::points_reader(&points);
+    let numbers = reader
+        .iter_shapes_and_records_with_number::<Point, dbase::Record>()
+        .map(|result| result.map(|(number, point, _)| (number, point)))
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(
+        numbers,
+        vec![(1, Point::new(0.0, 0.0)), (2, Point::new(1.0, 0.0))]
+    );
+}
+
+#[test]
+fn read_back_in_chunks() {
+    let points = [0.0, 1.0, 2.0, 3.0].map(|x| Point::new(x, 0.0));
+    let mut reader = testfiles::points_reader(&points);
+    let chunks = reader
+        .iter_shapes_and_records_chunked(2)
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(chunks.len(), 2);
+    for (i, chunk) in chunks.iter().enumerate() {
+        assert_eq!(chunk.len(), 2);
+        for (j, (shape, record)) in chunk.iter().enumerate() {
+            let index = 2 * i + j;
+            assert_eq!(
+                *shape,
+                shapefile::Shape::Point(Point::new(index as f64, 0.0))
+            );
+            assert_eq!(
+                record.get("name"),
+                Some(&dbase::FieldValue::Character(Some(format!(
+                    "point {}",
+                    index
+                ))))
+            );
+        }
+    }
+}
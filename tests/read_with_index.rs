@@ -15,3 +15,30 @@ fn test_line_read_nth() {
 
     assert_eq!(reader.read_nth_shape(1).is_none(), true);
 }
+
+#[test]
+fn test_read_shape_number_is_one_based() {
+    let mut reader = shapefile::ShapeReader::from_path(testfiles::LINE_PATH).unwrap();
+
+    let by_number = reader.read_shape_number(1).unwrap().unwrap();
+    let by_index = reader.read_nth_shape(0).unwrap().unwrap();
+    assert_eq!(by_number, by_index);
+
+    assert!(reader.read_shape_number(0).is_none());
+    assert!(reader.read_shape_number(2).is_none());
+}
+
+#[test]
+fn test_index_entries_offsets_increase() {
+    let reader = shapefile::ShapeReader::from_path(testfiles::POLYGON_HOLE_PATH).unwrap();
+    let entries = reader.index_entries().unwrap();
+    assert_eq!(entries.len(), reader.shape_count().unwrap());
+    assert_eq!(entries[0].offset * 2, 100);
+    for pair in entries.windows(2) {
+        assert!(pair[0].offset < pair[1].offset);
+        assert_eq!(pair[1].offset, pair[0].offset + 4 + pair[0].record_size);
+    }
+
+    let reader = shapefile::ShapeReader::from_path(testfiles::POLYGON_PATH).unwrap();
+    assert!(reader.index_entries().is_none());
+}
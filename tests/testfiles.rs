@@ -1,6 +1,8 @@
 #![allow(dead_code)]
 extern crate shapefile;
 
+use std::io::Cursor;
+
 use shapefile::Point;
 
 pub const LINE_PATH: &str = "./tests/data/line.shp";
@@ -44,3 +46,32 @@ pub fn check_line_first_shape(shape: &shapefile::Shape) {
         assert!(false, "The shape is not a Polyline");
     }
 }
+
+/// Returns the builder of the dbase table used by the tests, with a single "name" field
+pub fn name_table_builder() -> dbase::TableWriterBuilder {
+    dbase::TableWriterBuilder::new().add_character_field("name".try_into().unwrap(), 50)
+}
+
+/// Returns a record of the table of [name_table_builder]
+pub fn name_record(name: String) -> dbase::Record {
+    let mut record = dbase::Record::default();
+    record.insert("name".to_string(), dbase::FieldValue::Character(Some(name)));
+    record
+}
+
+/// Writes the points in memory, the record of the `i`th point being named `point {i}`,
+/// and returns a reader of the written files
+pub fn points_reader(points: &[Point]) -> shapefile::Reader<Cursor<Vec<u8>>, Cursor<Vec<u8>>> {
+    let mut writer = shapefile::Writer::in_memory(name_table_builder());
+    for (i, point) in points.iter().enumerate() {
+        writer
+            .write_shape_and_record(point, &name_record(format!("point {}", i)))
+            .unwrap();
+    }
+    let (shp, shx, dbf) = writer.finish_to_bytes().unwrap();
+
+    let shape_reader =
+        shapefile::ShapeReader::with_shx(Cursor::new(shp), Cursor::new(shx)).unwrap();
+    let dbase_reader = dbase::Reader::new(Cursor::new(dbf)).unwrap();
+    shapefile::Reader::new(shape_reader, dbase_reader)
+}
@@ -115,3 +115,613 @@ fn polygon_inner_is_correctly_reordered() {
     let expected = read_a_file(testfiles::POLYGON_HOLE_SHX_PATH).unwrap();
     assert_eq!(shx.get_ref(), &expected);
 }
+
+#[test]
+fn write_all_from_iterator() {
+    use shapefile::{Shape, ShapeType};
+    use std::convert::TryInto;
+
+    let mut shp: Cursor<Vec<u8>> = Cursor::new(vec![]);
+    let mut shx: Cursor<Vec<u8>> = Cursor::new(vec![]);
+    let mut dbf: Cursor<Vec<u8>> = Cursor::new(vec![]);
+
+    let shape_writer = ShapeWriter::with_shx(&mut shp, &mut shx);
+    let dbase_writer = dbase::TableWriterBuilder::new()
+        .add_character_field("name".try_into().unwrap(), 50)
+        .build_with_dest(&mut dbf);
+    let writer = shapefile::Writer::new(shape_writer, dbase_writer);
+
+    let features = vec![
+        Point::new(1.0, 1.0),
+        Point::new(2.0, 5.0),
+        Point::new(3.0, 2.0),
+    ]
+    .into_iter()
+    .enumerate()
+    .map(|(i, point)| {
+        let mut record = dbase::Record::default();
+        record.insert(
+            "name".to_string(),
+            dbase::FieldValue::Character(Some(format!("point {}", i))),
+        );
+        Ok::<_, shapefile::Error>((Shape::Point(point), record))
+    });
+    writer.write_all(features).unwrap();
+
+    shp.set_position(0);
+    shx.set_position(0);
+    dbf.set_position(0);
+    let shape_reader = shapefile::ShapeReader::with_shx(&mut shp, &mut shx).unwrap();
+    assert_eq!(shape_reader.header().shape_type, ShapeType::Point);
+    assert_eq!(shape_reader.header().bbox.max.y, 5.0);
+    let dbase_reader = dbase::Reader::new(&mut dbf).unwrap();
+    let mut reader = shapefile::Reader::new(shape_reader, dbase_reader);
+    assert_eq!(reader.shape_count().unwrap(), 3);
+    let points = reader.read_as::<Point, dbase::Record>().unwrap();
+    assert_eq!(points.len(), 3);
+    assert_eq!(points[2].0, Point::new(3.0, 2.0));
+    assert_eq!(
+        points[2].1.get("name"),
+        Some(&dbase::FieldValue::Character(Some("point 2".to_string())))
+    );
+}
+
+#[test]
+fn write_all_mismatch_type() {
+    use shapefile::Shape;
+    use std::convert::TryInto;
+
+    let mut shp: Cursor<Vec<u8>> = Cursor::new(vec![]);
+    let mut shx: Cursor<Vec<u8>> = Cursor::new(vec![]);
+    let mut dbf: Cursor<Vec<u8>> = Cursor::new(vec![]);
+
+    let shape_writer = ShapeWriter::with_shx(&mut shp, &mut shx);
+    let dbase_writer = dbase::TableWriterBuilder::new()
+        .add_character_field("name".try_into().unwrap(), 50)
+        .build_with_dest(&mut dbf);
+    let writer = shapefile::Writer::new(shape_writer, dbase_writer);
+
+    let shapes = vec![
+        Shape::NullShape,
+        Shape::Point(Point::new(1.0, 1.0)),
+        Shape::Polyline(Polyline::new(vec![
+            Point::new(1.0, 1.0),
+            Point::new(2.0, 2.0),
+        ])),
+    ];
+    let features = shapes.into_iter().map(|shape| {
+        let mut record = dbase::Record::default();
+        record.insert("name".to_string(), dbase::FieldValue::Character(None));
+        Ok::<_, shapefile::Error>((shape, record))
+    });
+    assert!(matches!(
+        writer.write_all(features),
+        Err(shapefile::Error::MismatchShapeType { .. })
+    ));
+}
+
+#[test]
+fn write_shape_with_record_number() {
+    let mut shp: Cursor<Vec<u8>> = Cursor::new(vec![]);
+    {
+        let mut writer = ShapeWriter::new(&mut shp);
+        writer
+            .write_shape_with_number(&Point::new(1.0, 1.0), 5)
+            .unwrap();
+        writer
+            .write_shape_with_number(&Point::new(2.0, 2.0), 9)
+            .unwrap();
+    }
+
+    let read_record_number = |offset: usize| {
+        let bytes = &shp.get_ref()[offset..offset + 4];
+        i32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+    };
+    // 100 bytes of header, then each point record takes 8 + 4 + 16 bytes
+    assert_eq!(read_record_number(100), 5);
+    assert_eq!(read_record_number(128), 9);
+
+    shp.set_position(0);
+    let points = shapefile::ShapeReader::new(&mut shp)
+        .unwrap()
+        .read_as::<Point>()
+        .unwrap();
+    assert_eq!(points, vec![Point::new(1.0, 1.0), Point::new(2.0, 2.0)]);
+}
+
+#[test]
+fn write_with_custom_header_bbox() {
+    use shapefile::record::BBoxZ;
+    use shapefile::PointZ;
+
+    let bbox = BBoxZ {
+        min: PointZ::new(-100.0, -100.0, 0.0, 0.0),
+        max: PointZ::new(100.0, 100.0, 0.0, 0.0),
+    };
+
+    let mut shp: Cursor<Vec<u8>> = Cursor::new(vec![]);
+    {
+        let mut writer = ShapeWriter::new(&mut shp);
+        writer.set_header_bbox(bbox);
+        writer.write_shape(&Point::new(1.0, 1.0)).unwrap();
+        writer.write_shape(&Point::new(2.0, 2.0)).unwrap();
+    }
+
+    shp.set_position(0);
+    let reader = shapefile::ShapeReader::new(&mut shp).unwrap();
+    assert_eq!(reader.header().bbox, bbox);
+    assert_eq!(reader.read_as::<Point>().unwrap().len(), 2);
+}
+
+#[test]
+fn write_shp_only_from_path() {
+    let dir = std::env::temp_dir().join("shapefile_write_shp_only");
+    std::fs::create_dir_all(&dir).unwrap();
+    let shp_path = dir.join("points.shp");
+    let _ = std::fs::remove_file(shp_path.with_extension("shx"));
+
+    let points = vec![Point::new(1.0, 2.0), Point::new(3.0, 4.0)];
+    {
+        let writer = ShapeWriter::from_path_shp_only(&shp_path).unwrap();
+        writer.write_shapes(&points).unwrap();
+    }
+
+    assert!(shp_path.exists());
+    assert!(!shp_path.with_extension("shx").exists());
+
+    let reader = shapefile::ShapeReader::from_path(&shp_path).unwrap();
+    assert!(reader.shape_count().is_err());
+    let read_points = reader.read_as::<Point>().unwrap();
+    assert_eq!(read_points, points);
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn write_shp_only_file_length() {
+    let mut shp = Cursor::new(Vec::<u8>::new());
+    {
+        let writer = ShapeWriter::new(&mut shp);
+        writer
+            .write_shapes(&[Point::new(1.0, 2.0), Point::new(3.0, 4.0)])
+            .unwrap();
+    }
+    let bytes = shp.into_inner();
+    // Header + 2 * (record header + shape type + x + y)
+    assert_eq!(bytes.len(), 100 + 2 * (8 + 4 + 16));
+
+    let mut file_length = [0u8; 4];
+    file_length.copy_from_slice(&bytes[24..28]);
+    // The file length is in 16-bit words
+    assert_eq!(i32::from_be_bytes(file_length) as usize * 2, bytes.len());
+
+    let reader = shapefile::ShapeReader::new(Cursor::new(bytes)).unwrap();
+    assert_eq!(reader.header().file_length as usize * 2, 100 + 2 * 28);
+}
+
+#[test]
+fn writer_builder_invalid_field_name() {
+    let result = shapefile::WriterBuilder::new("invalid_field_name.shp")
+        .add_field_character("name", 50)
+        .add_field_numeric("a_too_long_field_name", 10, 0)
+        .build();
+    match result {
+        Err(shapefile::Error::InvalidFieldName(name)) => assert_eq!(name, "a_too_long_field_name"),
+        _ => panic!("Expected an InvalidFieldName error"),
+    }
+    assert!(!std::path::Path::new("invalid_field_name.shp").exists());
+}
+
+#[test]
+fn write_shapes_counting() {
+    let points: Vec<Point> = (0..5).map(|i| Point::new(i as f64, i as f64)).collect();
+    let mut shp: Cursor<Vec<u8>> = Cursor::new(vec![]);
+    {
+        let mut writer = ShapeWriter::new(&mut shp);
+        let stats = writer.write_shapes_counting(&points).unwrap();
+        assert_eq!(stats.shapes_written, 5);
+        assert_eq!(stats.bytes_written, 100 + 5 * 28);
+        writer.write_null_shape().unwrap();
+    }
+    let shapes = shapefile::ShapeReader::new(Cursor::new(shp.into_inner()))
+        .unwrap()
+        .read()
+        .unwrap();
+    assert_eq!(shapes.len(), 6);
+}
+
+#[test]
+fn write_polyline_m_without_measures() {
+    use shapefile::{PointM, PolylineM, NO_DATA};
+    let polyline = PolylineM::new(vec![
+        PointM::new(1.0, 1.0, 1.0),
+        PointM::new(2.0, 2.0, 2.0),
+        PointM::new(3.0, 3.0, NO_DATA),
+    ]);
+    assert!(polyline.has_measures());
+
+    fn write_and_read_back(polyline: &PolylineM) -> (i32, PolylineM) {
+        let mut shp: Cursor<Vec<u8>> = Cursor::new(vec![]);
+        {
+            let mut writer = ShapeWriter::new(&mut shp);
+            writer.write_shape(polyline).unwrap();
+        }
+        shp.set_position(0);
+        let reader = shapefile::ShapeReader::new(shp).unwrap();
+        let file_length = reader.header().file_length;
+        let mut polylines = reader.read_as::<PolylineM>().unwrap();
+        (file_length, polylines.pop().unwrap())
+    }
+
+    let (length_with_m, read_polyline) = write_and_read_back(&polyline);
+    assert_eq!(read_polyline, polyline);
+
+    let polyline = polyline.without_measures();
+    assert!(!polyline.has_measures());
+    let (length_without_m, read_polyline) = write_and_read_back(&polyline);
+    // The M range and the 3 m values are not written
+    assert_eq!((length_with_m - length_without_m) * 2, 5 * 8);
+    assert!(!read_polyline.has_measures());
+    assert_eq!(read_polyline.parts(), polyline.parts());
+}
+
+#[test]
+fn write_qix_spatial_index() {
+    use shapefile::SpatialIndexKind;
+
+    let mut shp: Cursor<Vec<u8>> = Cursor::new(vec![]);
+    let mut shx: Cursor<Vec<u8>> = Cursor::new(vec![]);
+    let mut qix: Cursor<Vec<u8>> = Cursor::new(vec![]);
+    {
+        let writer = ShapeWriter::with_shx(&mut shp, &mut shx)
+            .with_spatial_index(SpatialIndexKind::Qix, &mut qix);
+        let points = (0..50)
+            .map(|i| Point::new(i as f64, (i * 2) as f64))
+            .collect::<Vec<_>>();
+        writer.write_shapes(&points).unwrap();
+    }
+
+    let qix = qix.into_inner();
+    assert!(!qix.is_empty());
+    assert_eq!(&qix[..3], b"SQT");
+    // number of shapes
+    assert_eq!(&qix[8..12], &50i32.to_le_bytes());
+}
+
+#[test]
+fn write_shape_with_nan_is_rejected() {
+    let mut shp: Cursor<Vec<u8>> = Cursor::new(vec![]);
+    {
+        let mut writer = ShapeWriter::new(&mut shp);
+        writer.write_shape(&Point::new(1.0, 1.0)).unwrap();
+        match writer.write_shape(&Point::new(f64::NAN, 3.0)) {
+            Err(shapefile::Error::InvalidShape(_)) => {}
+            _ => panic!("Expected an InvalidShape error"),
+        }
+    }
+
+    shp.set_position(0);
+    let reader = shapefile::ShapeReader::new(&mut shp).unwrap();
+    assert_eq!(reader.header().bbox.max.x, 1.0);
+    assert_eq!(reader.read_as::<Point>().unwrap().len(), 1);
+}
+
+#[test]
+fn write_points_with_point_stream() {
+    let mut shp: Cursor<Vec<u8>> = Cursor::new(vec![]);
+    let mut shx: Cursor<Vec<u8>> = Cursor::new(vec![]);
+    {
+        let mut writer = ShapeWriter::with_shx(&mut shp, &mut shx);
+        let mut point_writer = writer.point_stream().unwrap();
+        for i in 0..1000 {
+            point_writer.push(i as f64, -(i as f64) / 2.0).unwrap();
+        }
+    }
+
+    shp.set_position(0);
+    shx.set_position(0);
+    let reader = shapefile::ShapeReader::with_shx(&mut shp, &mut shx).unwrap();
+    assert_eq!(reader.shape_count().unwrap(), 1000);
+    assert_eq!(reader.header().bbox.min.x, 0.0);
+    assert_eq!(reader.header().bbox.max.x, 999.0);
+    assert_eq!(reader.header().bbox.min.y, -499.5);
+    assert_eq!(reader.header().bbox.max.y, 0.0);
+    let points = reader.read_as::<Point>().unwrap();
+    assert_eq!(points.len(), 1000);
+    for (i, point) in points.iter().enumerate() {
+        assert_eq!(*point, Point::new(i as f64, -(i as f64) / 2.0));
+    }
+}
+
+#[test]
+fn write_empty_file_with_expected_type() {
+    use shapefile::ShapeType;
+
+    let mut shp: Cursor<Vec<u8>> = Cursor::new(vec![]);
+    let mut shx: Cursor<Vec<u8>> = Cursor::new(vec![]);
+    {
+        let mut writer = ShapeWriter::with_shx(&mut shp, &mut shx);
+        writer.set_expected_type(ShapeType::PolylineZ);
+    }
+
+    shp.set_position(0);
+    shx.set_position(0);
+    let reader = shapefile::ShapeReader::with_shx(&mut shp, &mut shx).unwrap();
+    assert_eq!(reader.header().shape_type, ShapeType::PolylineZ);
+    assert_eq!(reader.header().bbox, shapefile::record::BBoxZ::default());
+    assert_eq!(reader.shape_count().unwrap(), 0);
+    assert!(reader.read_as::<shapefile::PolylineZ>().unwrap().is_empty());
+}
+
+#[test]
+fn predicted_record_sizes_match_written_file() {
+    use shapefile::record::EsriShape;
+
+    let polylines = vec![
+        Polyline::new(vec![Point::new(1.0, 1.0), Point::new(2.0, 2.0)]),
+        Polyline::with_parts(vec![
+            vec![
+                Point::new(0.0, 0.0),
+                Point::new(5.0, 5.0),
+                Point::new(6.0, 2.0),
+            ],
+            vec![Point::new(10.0, 10.0), Point::new(15.0, 15.0)],
+        ]),
+    ];
+    let predicted = polylines
+        .iter()
+        .map(|polyline| polyline.record_size_in_file())
+        .sum::<usize>();
+
+    let mut shp: Cursor<Vec<u8>> = Cursor::new(vec![]);
+    ShapeWriter::new(&mut shp).write_shapes(&polylines).unwrap();
+    // The file header is 100 bytes
+    assert_eq!(shp.get_ref().len(), 100 + predicted);
+}
+
+#[test]
+fn copy_transform_drops_features() {
+    let points = [0.0, 1.0, 2.0].map(|x| Point::new(x, 0.0));
+    let mut reader = testfiles::points_reader(&points);
+
+    let mut copied_shp: Cursor<Vec<u8>> = Cursor::new(vec![]);
+    let mut copied_shx: Cursor<Vec<u8>> = Cursor::new(vec![]);
+    let mut copied_dbf: Cursor<Vec<u8>> = Cursor::new(vec![]);
+    {
+        let shape_writer = ShapeWriter::with_shx(&mut copied_shp, &mut copied_shx);
+        let dbase_writer = testfiles::name_table_builder().build_with_dest(&mut copied_dbf);
+        let mut writer = shapefile::Writer::new(shape_writer, dbase_writer);
+        shapefile::copy_transform(&mut reader, &mut writer, |shape, record| {
+            match record.get("name") {
+                Some(dbase::FieldValue::Character(Some(name))) if name == "point 1" => None,
+                _ => Some((shape, record)),
+            }
+        })
+        .unwrap();
+    }
+
+    copied_shp.set_position(0);
+    copied_shx.set_position(0);
+    copied_dbf.set_position(0);
+    let shape_reader = shapefile::ShapeReader::with_shx(&mut copied_shp, &mut copied_shx).unwrap();
+    let dbase_reader = dbase::Reader::new(&mut copied_dbf).unwrap();
+    let mut reader = shapefile::Reader::new(shape_reader, dbase_reader);
+    reader.verify_record_counts().unwrap();
+    let points = reader.read_as::<Point, dbase::Record>().unwrap();
+    assert_eq!(points.len(), 2);
+    assert_eq!(points[0].0, Point::new(0.0, 0.0));
+    assert_eq!(points[1].0, Point::new(2.0, 0.0));
+    assert_eq!(
+        points[1].1.get("name"),
+        Some(&dbase::FieldValue::Character(Some("point 2".to_string())))
+    );
+}
+
+#[test]
+fn write_in_memory_and_read_back() {
+    let mut writer = shapefile::Writer::in_memory(testfiles::name_table_builder());
+    let record = testfiles::name_record("Paris".to_string());
+    writer
+        .write_shape_and_record(&Point::new(2.35, 48.85), &record)
+        .unwrap();
+    let (shp, shx, dbf) = writer.finish_to_bytes().unwrap();
+
+    let shape_reader =
+        shapefile::ShapeReader::with_shx(Cursor::new(shp), Cursor::new(shx)).unwrap();
+    let dbase_reader = dbase::Reader::new(Cursor::new(dbf)).unwrap();
+    let mut reader = shapefile::Reader::new(shape_reader, dbase_reader);
+    let points = reader.read_as::<Point, dbase::Record>().unwrap();
+    assert_eq!(points, vec![(Point::new(2.35, 48.85), record)]);
+}
+
+#[test]
+fn write_in_memory_from_another_thread() {
+    let mut writer = shapefile::Writer::in_memory(testfiles::name_table_builder());
+    let record = testfiles::name_record("Paris".to_string());
+    let (shp, shx, _) = std::thread::spawn(move || {
+        writer
+            .write_shape_and_record(&Point::new(2.35, 48.85), &record)
+            .unwrap();
+        writer.finish_to_bytes().unwrap()
+    })
+    .join()
+    .unwrap();
+
+    assert_eq!(shp.len(), 100 + 8 + 20);
+    assert_eq!(shx.len(), 100 + 8);
+    let reader = shapefile::ShapeReader::new(Cursor::new(shp)).unwrap();
+    assert_eq!(reader.header().file_length, (100 + 8 + 20) / 2);
+}
+
+#[test]
+fn write_polyline_z_as_2d() {
+    use shapefile::{PointZ, PolylineZ, ShapeType};
+    let polyline = PolylineZ::with_parts(vec![
+        vec![
+            PointZ::new(1.0, 1.0, 5.0, 0.0),
+            PointZ::new(2.0, 2.0, 6.0, 1.0),
+        ],
+        vec![
+            PointZ::new(3.0, 3.0, 7.0, 2.0),
+            PointZ::new(4.0, 1.0, 8.0, 3.0),
+        ],
+    ]);
+    let mut shp = Cursor::new(Vec::<u8>::new());
+    {
+        let mut writer = ShapeWriter::new(&mut shp);
+        writer.write_shape_as_2d(&polyline).unwrap();
+        writer
+            .write_shape(&Polyline::new(vec![
+                Point::new(0.0, 0.0),
+                Point::new(1.0, 0.0),
+            ]))
+            .unwrap();
+    }
+    shp.set_position(0);
+
+    let reader = shapefile::ShapeReader::new(shp).unwrap();
+    assert_eq!(reader.header().shape_type, ShapeType::Polyline);
+    let polylines = reader.read_as::<Polyline>().unwrap();
+    assert_eq!(
+        polylines[0],
+        Polyline::with_parts(vec![
+            vec![Point::new(1.0, 1.0), Point::new(2.0, 2.0)],
+            vec![Point::new(3.0, 3.0), Point::new(4.0, 1.0)],
+        ])
+    );
+    assert_eq!(polylines.len(), 2);
+}
+
+#[test]
+fn write_point_multipoint_and_polygon_as_2d() {
+    use shapefile::record::EsriShape;
+    use shapefile::{Multipoint, MultipointZ, PointM, PointZ, PolygonM};
+
+    fn write_as_2d<S: EsriShape>(shape: &S) -> shapefile::ShapeReader<Cursor<Vec<u8>>> {
+        let mut shp = Cursor::new(Vec::<u8>::new());
+        {
+            let mut writer = ShapeWriter::new(&mut shp);
+            writer.write_shape_as_2d(shape).unwrap();
+        }
+        shp.set_position(0);
+        shapefile::ShapeReader::new(shp).unwrap()
+    }
+
+    let points = write_as_2d(&PointM::new(1.0, 2.0, 3.0))
+        .read_as::<Point>()
+        .unwrap();
+    assert_eq!(points, vec![Point::new(1.0, 2.0)]);
+
+    let multipoints = write_as_2d(&MultipointZ::new(vec![
+        PointZ::new(1.0, 2.0, 3.0, 4.0),
+        PointZ::new(5.0, 6.0, 7.0, 8.0),
+    ]))
+    .read_as::<Multipoint>()
+    .unwrap();
+    assert_eq!(
+        multipoints,
+        vec![Multipoint::new(vec![
+            Point::new(1.0, 2.0),
+            Point::new(5.0, 6.0)
+        ])]
+    );
+
+    let polygons = write_as_2d(&PolygonM::new(PolygonRing::Outer(vec![
+        PointM::new(0.0, 0.0, 1.0),
+        PointM::new(0.0, 1.0, 2.0),
+        PointM::new(1.0, 1.0, 3.0),
+        PointM::new(0.0, 0.0, 1.0),
+    ])))
+    .read_as::<Polygon>()
+    .unwrap();
+    assert_eq!(
+        polygons,
+        vec![Polygon::new(PolygonRing::Outer(vec![
+            Point::new(0.0, 0.0),
+            Point::new(0.0, 1.0),
+            Point::new(1.0, 1.0),
+            Point::new(0.0, 0.0),
+        ]))]
+    );
+}
+
+#[test]
+fn write_polyline_m_header_m_range() {
+    use shapefile::{PointM, PolylineM};
+    let polyline = PolylineM::new(
+        (0..4)
+            .map(|i| PointM::new(i as f64, 0.0, i as f64))
+            .collect(),
+    );
+    let mut shp = Cursor::new(Vec::<u8>::new());
+    {
+        let mut writer = ShapeWriter::new(&mut shp);
+        writer.write_shape(&polyline).unwrap();
+    }
+    shp.set_position(0);
+
+    let reader = shapefile::ShapeReader::new(shp).unwrap();
+    assert_eq!(reader.header().m_range(), [0.0, 3.0]);
+}
+
+#[test]
+fn write_leading_null_shape_with_expected_type() {
+    use shapefile::{Shape, ShapeType};
+    for expected_type in [None, Some(ShapeType::Point)] {
+        let mut shp = Cursor::new(Vec::<u8>::new());
+        let mut shx = Cursor::new(Vec::<u8>::new());
+        {
+            let mut writer = ShapeWriter::with_shx(&mut shp, &mut shx);
+            if let Some(shape_type) = expected_type {
+                writer.set_expected_type(shape_type);
+            }
+            writer.write_null_shape().unwrap();
+            writer.write_shape(&Point::new(1.0, 2.0)).unwrap();
+        }
+        shp.set_position(0);
+        shx.set_position(0);
+
+        let reader = shapefile::ShapeReader::with_shx(shp, shx).unwrap();
+        assert_eq!(reader.header().shape_type, ShapeType::Point);
+        assert_eq!(reader.header().bbox.x_range(), [1.0, 1.0]);
+        assert_eq!(
+            reader.read().unwrap(),
+            vec![Shape::NullShape, Shape::Point(Point::new(1.0, 2.0))]
+        );
+    }
+}
+
+#[test]
+fn validate_written_file() {
+    let dir = std::env::temp_dir().join("shapefile_validate_written_file");
+    std::fs::create_dir_all(&dir).unwrap();
+    let shp_path = dir.join("polygons.shp");
+
+    {
+        let table_builder = testfiles::name_table_builder();
+        let mut writer = shapefile::Writer::from_path(&shp_path, table_builder).unwrap();
+        let polygon = Polygon::with_rings(vec![
+            PolygonRing::Outer(vec![
+                Point::new(0.0, 0.0),
+                Point::new(0.0, 4.0),
+                Point::new(4.0, 4.0),
+                Point::new(4.0, 0.0),
+                Point::new(0.0, 0.0),
+            ]),
+            PolygonRing::Inner(vec![
+                Point::new(1.0, 1.0),
+                Point::new(2.0, 1.0),
+                Point::new(2.0, 2.0),
+                Point::new(1.0, 2.0),
+                Point::new(1.0, 1.0),
+            ]),
+        ]);
+        let record = testfiles::name_record("square".to_string());
+        writer.write_shape_and_record(&polygon, &record).unwrap();
+    }
+
+    let report = shapefile::validate_file(&shp_path).unwrap();
+    assert!(report.is_valid(), "{:?}", report.issues);
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}